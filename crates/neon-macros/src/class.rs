@@ -0,0 +1,421 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{
+    parse::{Parse, ParseStream},
+    spanned::Spanned,
+};
+
+use crate::{
+    export::is_context_type,
+    meta::{self, MetaItem},
+    typescript,
+};
+
+/// Options accepted by `#[neon::class(...)]`
+#[derive(Default)]
+pub(crate) struct Meta {
+    /// Name of the class
+    name: Option<syn::LitStr>,
+}
+
+impl Parse for Meta {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut meta = Meta::default();
+
+        for item in meta::parse_list(input)? {
+            match item {
+                MetaItem::Value(ident, lit) if ident == "name" => meta.name = Some(lit),
+                item => return Err(item.unsupported("#[neon::class]")),
+            }
+        }
+
+        Ok(meta)
+    }
+}
+
+/// Options accepted by `#[neon(...)]` on methods of a class
+#[derive(Default)]
+struct MethodMeta {
+    name: Option<syn::LitStr>,
+    constructor: bool,
+    getter: bool,
+    setter: bool,
+    skip: bool,
+    context: bool,
+}
+
+impl MethodMeta {
+    // Removes `#[neon(...)]` attributes from a method and parses them
+    fn take(attrs: &mut Vec<syn::Attribute>) -> syn::Result<Self> {
+        let mut meta = MethodMeta::default();
+        let mut items = Vec::new();
+
+        for attr in std::mem::take(attrs) {
+            if attr.path.is_ident("neon") {
+                items.extend(attr.parse_args_with(meta::parse_list)?);
+            } else {
+                attrs.push(attr);
+            }
+        }
+
+        for item in items {
+            match item {
+                MetaItem::Value(ident, lit) if ident == "name" => meta.name = Some(lit),
+                MetaItem::Flag(ident) if ident == "constructor" => meta.constructor = true,
+                MetaItem::Flag(ident) if ident == "getter" => meta.getter = true,
+                MetaItem::Flag(ident) if ident == "setter" => meta.setter = true,
+                MetaItem::Flag(ident) if ident == "skip" => meta.skip = true,
+                MetaItem::Flag(ident) if ident == "context" => meta.context = true,
+                item => return Err(item.unsupported("#[neon]")),
+            }
+        }
+
+        Ok(meta)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Kind {
+    Constructor,
+    Method,
+    Getter,
+    Setter,
+    Static,
+}
+
+struct Method {
+    kind: Kind,
+    // Name of the property in JavaScript
+    name: String,
+    // Name of the generated native callback
+    callback: syn::Ident,
+    tokens: TokenStream,
+    // Expression rendering the TypeScript declaration of the member
+    declaration: TokenStream,
+}
+
+pub(crate) fn class(meta: Meta, mut item: syn::ItemImpl) -> syn::Result<TokenStream> {
+    if let Some((_, path, _)) = &item.trait_ {
+        return Err(syn::Error::new(
+            path.span(),
+            "`#[neon::class]` cannot be applied to trait implementations",
+        ));
+    }
+
+    if !item.generics.params.is_empty() {
+        return Err(syn::Error::new(
+            item.generics.span(),
+            "classes cannot have generic parameters",
+        ));
+    }
+
+    let ty = item.self_ty.clone();
+    let ident = match &*ty {
+        syn::Type::Path(path) if path.qself.is_none() => match path.path.segments.last() {
+            Some(segment) if segment.arguments.is_empty() => segment.ident.clone(),
+            _ => return Err(syn::Error::new(ty.span(), "expected a struct name")),
+        },
+        _ => return Err(syn::Error::new(ty.span(), "expected a struct name")),
+    };
+
+    let class_name = meta
+        .name
+        .map(|name| name.value())
+        .unwrap_or_else(|| ident.to_string());
+
+    let mut methods = Vec::new();
+
+    for impl_item in item.items.iter_mut() {
+        if let syn::ImplItem::Method(method) = impl_item {
+            let meta = MethodMeta::take(&mut method.attrs)?;
+
+            if !meta.skip {
+                methods.push(class_method(&ty, meta, &method.sig)?);
+            }
+        }
+    }
+
+    let mut constructors = methods.iter().filter(|m| m.kind == Kind::Constructor);
+    let constructor = match (constructors.next(), constructors.next()) {
+        (Some(constructor), None) => &constructor.callback,
+        (_, Some(_)) => {
+            return Err(syn::Error::new(
+                item.span(),
+                "classes must have exactly one constructor",
+            ))
+        }
+        (None, None) => {
+            return Err(syn::Error::new(
+                item.span(),
+                "classes require a `#[neon(constructor)]` or `fn new`",
+            ))
+        }
+    };
+
+    let callbacks = methods.iter().map(|m| &m.tokens);
+    let mut properties = Vec::new();
+    let mut accessors: Vec<(&str, Option<&syn::Ident>, Option<&syn::Ident>)> = Vec::new();
+
+    for method in &methods {
+        let name = &method.name;
+        let callback = &method.callback;
+
+        match method.kind {
+            Kind::Constructor => {}
+            Kind::Method => properties.push(quote!(.method(#name, #callback))),
+            Kind::Static => properties.push(quote!(.static_method(#name, #callback))),
+            Kind::Getter | Kind::Setter => {
+                let i = match accessors.iter().position(|(n, _, _)| *n == name) {
+                    Some(i) => i,
+                    None => {
+                        accessors.push((name, None, None));
+                        accessors.len() - 1
+                    }
+                };
+
+                let accessor = &mut accessors[i];
+                let slot = if method.kind == Kind::Getter {
+                    &mut accessor.1
+                } else {
+                    &mut accessor.2
+                };
+
+                if slot.replace(callback).is_some() {
+                    return Err(syn::Error::new(
+                        callback.span(),
+                        format!("duplicate accessor for property `{}`", name),
+                    ));
+                }
+            }
+        }
+    }
+
+    for (name, getter, setter) in accessors {
+        let getter = getter
+            .map(|f| quote!(Some(#f as neon::macro_internal::class::Method)))
+            .unwrap_or_else(|| quote!(None));
+
+        let setter = setter
+            .map(|f| quote!(Some(#f as neon::macro_internal::class::Method)))
+            .unwrap_or_else(|| quote!(None));
+
+        properties.push(quote!(.accessor(#name, #getter, #setter)));
+    }
+
+    let register = format_ident!("__NEON_CLASS_{}", ident);
+    let declaration = format_ident!("__NEON_TYPESCRIPT_{}", ident);
+    // The constructor is declared first
+    let members = methods
+        .iter()
+        .filter(|m| m.kind == Kind::Constructor)
+        .chain(methods.iter().filter(|m| m.kind != Kind::Constructor))
+        .map(|m| &m.declaration);
+
+    Ok(quote!(
+        #item
+
+        #[doc(hidden)]
+        #[allow(non_upper_case_globals)]
+        #[neon::macro_internal::linkme::distributed_slice(neon::macro_internal::EXPORTS)]
+        #[linkme(crate = neon::macro_internal::linkme)]
+        static #register: neon::macro_internal::Export = {
+            fn export(cx: &mut neon::context::ModuleContext) -> neon::result::NeonResult<()> {
+                #(#callbacks)*
+
+                let class = neon::macro_internal::class::ClassBuilder::new(#class_name, #constructor)
+                    #(#properties)*
+                    .build(cx)?;
+
+                cx.export_value(#class_name, class)
+            }
+
+            export
+        };
+
+        #[doc(hidden)]
+        #[allow(non_upper_case_globals)]
+        #[neon::macro_internal::linkme::distributed_slice(neon::macro_internal::typescript::DECLARATIONS)]
+        #[linkme(crate = neon::macro_internal::linkme)]
+        static #declaration: neon::macro_internal::typescript::Declaration = {
+            fn declaration() -> ::std::string::String {
+                neon::macro_internal::typescript::class(#class_name, ::std::vec![#(#members),*])
+            }
+
+            declaration
+        };
+    ))
+}
+
+fn class_method(ty: &syn::Type, meta: MethodMeta, sig: &syn::Signature) -> syn::Result<Method> {
+    if let Some(param) = sig.generics.type_params().next() {
+        return Err(syn::Error::new(
+            param.span(),
+            "class methods cannot have type parameters",
+        ));
+    }
+
+    if let Some(asyncness) = &sig.asyncness {
+        return Err(syn::Error::new(
+            asyncness.span(),
+            "class methods cannot be `async`",
+        ));
+    }
+
+    let ident = &sig.ident;
+    let mut inputs = sig.inputs.iter().peekable();
+    let receiver = match inputs.peek() {
+        Some(syn::FnArg::Receiver(receiver)) => {
+            if receiver.reference.is_none() {
+                return Err(syn::Error::new(
+                    receiver.span(),
+                    "class methods must take `&self` or `&mut self`",
+                ));
+            }
+
+            let mutability = receiver.mutability.is_some();
+
+            inputs.next();
+            Some(mutability)
+        }
+        _ => None,
+    };
+
+    let kind = match (receiver, &meta) {
+        (None, MethodMeta { getter: true, .. }) | (None, MethodMeta { setter: true, .. }) => {
+            return Err(syn::Error::new(
+                sig.span(),
+                "accessors must take `&self` or `&mut self`",
+            ))
+        }
+        (
+            Some(_),
+            MethodMeta {
+                constructor: true, ..
+            },
+        ) => {
+            return Err(syn::Error::new(
+                sig.span(),
+                "constructors cannot take `self`",
+            ))
+        }
+        (_, MethodMeta { getter: true, .. }) => Kind::Getter,
+        (_, MethodMeta { setter: true, .. }) => Kind::Setter,
+        (
+            None,
+            MethodMeta {
+                constructor: true, ..
+            },
+        ) => Kind::Constructor,
+        (None, _) if ident == "new" => Kind::Constructor,
+        (None, _) => Kind::Static,
+        (Some(_), _) => Kind::Method,
+    };
+
+    let has_context = match inputs.peek() {
+        Some(syn::FnArg::Typed(arg)) => meta.context || is_context_type(&arg.ty),
+        _ => false,
+    };
+
+    if has_context {
+        inputs.next();
+    }
+
+    let inputs = inputs.collect::<Vec<_>>();
+    let args = (0..inputs.len())
+        .map(|i| format_ident!("arg{}", i))
+        .collect::<Vec<_>>();
+
+    let indices = (0..args.len()).collect::<Vec<_>>();
+
+    if kind == Kind::Getter && !args.is_empty() {
+        return Err(syn::Error::new(sig.span(), "getters cannot take arguments"));
+    }
+
+    if kind == Kind::Setter && args.len() != 1 {
+        return Err(syn::Error::new(
+            sig.span(),
+            "setters must take exactly one argument",
+        ));
+    }
+
+    let name = match meta.name {
+        Some(name) => name.value(),
+        None if kind == Kind::Setter => {
+            let name = ident.to_string();
+
+            name.strip_prefix("set_").unwrap_or(&name).to_string()
+        }
+        None => ident.to_string(),
+    };
+
+    let callback = format_ident!("__neon_{}", ident);
+    let context_arg = if has_context {
+        quote!(&mut cx,)
+    } else {
+        quote!()
+    };
+
+    let call = match receiver {
+        Some(true) => quote!(
+            let this = neon::macro_internal::class::this::<#ty>(&mut cx)?;
+            #(let #args = neon::macro_internal::argument(&mut cx, #indices)?;)*
+            let mut this = neon::macro_internal::class::borrow_mut(&mut cx, this)?;
+            let res = <#ty>::#ident(&mut *this, #context_arg #(#args),*);
+        ),
+        Some(false) => quote!(
+            let this = neon::macro_internal::class::this::<#ty>(&mut cx)?;
+            #(let #args = neon::macro_internal::argument(&mut cx, #indices)?;)*
+            let this = neon::macro_internal::class::borrow(&mut cx, this)?;
+            let res = <#ty>::#ident(&*this, #context_arg #(#args),*);
+        ),
+        None => quote!(
+            #(let #args = neon::macro_internal::argument(&mut cx, #indices)?;)*
+            let res = <#ty>::#ident(#context_arg #(#args),*);
+        ),
+    };
+
+    let ret = if kind == Kind::Constructor {
+        quote!(neon::macro_internal::class::construct::<#ty, _>(&mut cx, res))
+    } else {
+        quote!(neon::macro_internal::into_js(&mut cx, res))
+    };
+
+    let tokens = quote!(
+        fn #callback(
+            mut cx: neon::context::FunctionContext,
+        ) -> neon::result::JsResult<neon::types::JsValue> {
+            #call
+
+            #ret
+        }
+    );
+
+    let params = typescript::params(inputs.iter().copied(), Some(ty));
+    let output = typescript::output(&sig.output, Some(ty));
+    let declaration = match kind {
+        Kind::Constructor => quote!(neon::macro_internal::typescript::constructor(#params)),
+        Kind::Method => {
+            quote!(neon::macro_internal::typescript::method(#name, false, #params, #output))
+        }
+        Kind::Static => {
+            quote!(neon::macro_internal::typescript::method(#name, true, #params, #output))
+        }
+        Kind::Getter => quote!(neon::macro_internal::typescript::getter(#name, #output)),
+        Kind::Setter => {
+            let value = match inputs[0] {
+                syn::FnArg::Typed(arg) => typescript::input(&arg.ty, Some(ty)),
+                syn::FnArg::Receiver(_) => unreachable!("receiver was skipped"),
+            };
+
+            quote!(neon::macro_internal::typescript::setter(#name, #value))
+        }
+    };
+
+    Ok(Method {
+        kind,
+        name,
+        callback,
+        tokens,
+        declaration,
+    })
+}
@@ -0,0 +1,264 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::spanned::Spanned;
+
+use crate::{
+    extract::{field_name, parse_attrs},
+    meta::MetaItem,
+};
+
+/// Class of the JavaScript error
+#[derive(Clone, Copy)]
+enum Class {
+    Error,
+    TypeError,
+    RangeError,
+}
+
+/// Options accepted by `#[neon(...)]` on an error type or variant
+#[derive(Default)]
+struct ErrorMeta {
+    class: Option<Class>,
+    code: Option<syn::LitStr>,
+}
+
+impl ErrorMeta {
+    fn parse(attrs: &[syn::Attribute]) -> syn::Result<Self> {
+        let mut meta = ErrorMeta::default();
+
+        for item in parse_attrs(attrs)? {
+            let class = match &item {
+                MetaItem::Value(ident, lit) if ident == "code" => {
+                    meta.code = Some(lit.clone());
+                    continue;
+                }
+                MetaItem::Flag(ident) if ident == "type_error" => Class::TypeError,
+                MetaItem::Flag(ident) if ident == "range_error" => Class::RangeError,
+                item => return Err(item.unsupported("#[neon]")),
+            };
+
+            if meta.class.replace(class).is_some() {
+                return Err(syn::Error::new(
+                    item.ident().span(),
+                    "only one of `type_error` and `range_error` may be set",
+                ));
+            }
+        }
+
+        Ok(meta)
+    }
+}
+
+/// Options accepted by `#[neon(...)]` on fields of an error
+#[derive(Default)]
+struct FieldMeta {
+    rename: Option<syn::LitStr>,
+    skip: bool,
+}
+
+impl FieldMeta {
+    fn parse(attrs: &[syn::Attribute]) -> syn::Result<Self> {
+        let mut meta = FieldMeta::default();
+
+        for item in parse_attrs(attrs)? {
+            match item {
+                MetaItem::Value(ident, lit) if ident == "rename" => meta.rename = Some(lit),
+                MetaItem::Flag(ident) if ident == "skip" => meta.skip = true,
+                item => return Err(item.unsupported("#[neon]")),
+            }
+        }
+
+        Ok(meta)
+    }
+}
+
+// A struct or a variant of an enum
+struct Variant {
+    // `Self` or `Self::Variant`
+    path: TokenStream,
+    class: Class,
+    code: String,
+    // Named fields converted to properties, with the name of the property
+    props: Vec<(syn::Ident, String)>,
+}
+
+impl Variant {
+    fn new(
+        path: TokenStream,
+        ident: &syn::Ident,
+        attrs: &[syn::Attribute],
+        fields: &syn::Fields,
+        default: &ErrorMeta,
+    ) -> syn::Result<Self> {
+        let meta = ErrorMeta::parse(attrs)?;
+        let class = meta.class.or(default.class).unwrap_or(Class::Error);
+        let code = match meta.code {
+            Some(code) => code.value(),
+            None => default_code(&ident.to_string()),
+        };
+
+        let mut props = Vec::new();
+
+        // Only named fields are converted; tuple fields are usually a message
+        // or the source of the error
+        if let syn::Fields::Named(fields) = fields {
+            for field in &fields.named {
+                let meta = FieldMeta::parse(&field.attrs)?;
+                let ident = field.ident.clone().expect("named field");
+
+                if meta.skip {
+                    continue;
+                }
+
+                let name = match meta.rename {
+                    Some(name) => name.value(),
+                    None => field_name(ident.to_string().trim_start_matches("r#")),
+                };
+
+                props.push((ident, name));
+            }
+        }
+
+        Ok(Self {
+            path,
+            class,
+            code,
+            props,
+        })
+    }
+}
+
+// `ERR_` followed by the name in `SCREAMING_SNAKE_CASE`, e.g., `ERR_NOT_FOUND`
+fn default_code(name: &str) -> String {
+    let mut code = String::from("ERR");
+
+    for (i, c) in name.trim_start_matches("r#").chars().enumerate() {
+        if i == 0 || c.is_uppercase() {
+            code.push('_');
+        }
+
+        code.extend(c.to_uppercase());
+    }
+
+    code
+}
+
+pub(crate) fn js_error(input: syn::DeriveInput) -> syn::Result<TokenStream> {
+    let meta = ErrorMeta::parse(&input.attrs)?;
+    let variants = match &input.data {
+        syn::Data::Struct(data) => vec![Variant::new(
+            quote!(Self),
+            &input.ident,
+            &input.attrs,
+            &data.fields,
+            &ErrorMeta::default(),
+        )?],
+        syn::Data::Enum(data) => {
+            if let Some(code) = &meta.code {
+                return Err(syn::Error::new(
+                    code.span(),
+                    "`code` must be set on each variant of an enum",
+                ));
+            }
+
+            data.variants
+                .iter()
+                .map(|variant| {
+                    let ident = &variant.ident;
+
+                    Variant::new(
+                        quote!(Self::#ident),
+                        ident,
+                        &variant.attrs,
+                        &variant.fields,
+                        &meta,
+                    )
+                })
+                .collect::<syn::Result<Vec<_>>>()?
+        }
+        syn::Data::Union(data) => {
+            return Err(syn::Error::new(
+                data.union_token.span(),
+                "unions are not supported",
+            ))
+        }
+    };
+
+    let ident = &input.ident;
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+    let mut generics = input.generics.clone();
+
+    generics.params.insert(0, syn::parse_quote!('cx));
+
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+
+    let options = variants.iter().map(|variant| {
+        let path = &variant.path;
+        let code = &variant.code;
+        let new = match variant.class {
+            Class::Error => quote!(error_with),
+            Class::TypeError => quote!(type_error_with),
+            Class::RangeError => quote!(range_error_with),
+        };
+
+        quote!(
+            #path { .. } => {
+                let mut options = neon::types::JsError::#new(&*cx, msg);
+
+                options.code(#code);
+                options
+            }
+        )
+    });
+
+    let props = variants.iter().map(|variant| {
+        let path = &variant.path;
+        let bindings = (0..variant.props.len())
+            .map(|i| format_ident!("field{}", i))
+            .collect::<Vec<_>>();
+
+        let fields = variant.props.iter().map(|(ident, _)| ident);
+        let names = variant.props.iter().map(|(_, name)| name);
+
+        quote!(
+            #path { #(#fields: #bindings,)* .. } => {
+                #(
+                    let v = neon::types::extract::TryIntoJs::try_into_js(#bindings, cx)?;
+                    options.prop(#names, v);
+                )*
+            }
+        )
+    });
+
+    Ok(quote!(
+        #[automatically_derived]
+        impl #impl_generics neon::types::extract::TryIntoJs<'cx> for #ident #ty_generics #where_clause {
+            type Value = neon::types::JsError;
+
+            fn try_into_js<C>(self, cx: &mut C) -> neon::result::JsResult<'cx, neon::types::JsError>
+            where
+                C: neon::context::Context<'cx>,
+            {
+                let msg = ::std::string::ToString::to_string(&self);
+                let mut options = match &self {
+                    #(#options)*
+                };
+
+                if let ::std::option::Option::Some(source) = ::std::error::Error::source(&self) {
+                    options.cause_error(source);
+                }
+
+                #[allow(unused_variables)]
+                match self {
+                    #(#props)*
+                }
+
+                options.apply(cx)
+            }
+
+            fn ts_output() -> ::std::string::String {
+                ::std::string::String::from("Error")
+            }
+        }
+    ))
+}
@@ -0,0 +1,185 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{
+    parse::{Parse, ParseStream},
+    spanned::Spanned,
+};
+
+use crate::{
+    meta::{self, MetaItem},
+    typescript,
+};
+
+/// Options accepted by `#[neon::export(...)]`
+#[derive(Default)]
+pub(crate) struct Meta {
+    /// Name of the export on the module object
+    name: Option<syn::LitStr>,
+    /// The first argument is a `&mut FunctionContext`
+    context: bool,
+    /// The function returns a future that settles a promise
+    asyncness: bool,
+}
+
+impl Parse for Meta {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut meta = Meta::default();
+
+        for item in meta::parse_list(input)? {
+            match item {
+                MetaItem::Value(ident, lit) if ident == "name" => meta.name = Some(lit),
+                MetaItem::Flag(ident) if ident == "context" => meta.context = true,
+                MetaItem::Flag(ident) if ident == "async" => meta.asyncness = true,
+                item => return Err(item.unsupported("#[neon::export]")),
+            }
+        }
+
+        Ok(meta)
+    }
+}
+
+pub(crate) fn export(meta: Meta, item: syn::Item) -> syn::Result<TokenStream> {
+    match item {
+        syn::Item::Fn(item) => export_fn(meta, item),
+        item => Err(syn::Error::new(
+            item.span(),
+            "`#[neon::export]` can only be applied to functions",
+        )),
+    }
+}
+
+fn export_fn(meta: Meta, item: syn::ItemFn) -> syn::Result<TokenStream> {
+    let sig = &item.sig;
+
+    if let Some(param) = sig.generics.type_params().next() {
+        return Err(syn::Error::new(
+            param.span(),
+            "exported functions cannot have type parameters",
+        ));
+    }
+
+    let mut inputs = sig.inputs.iter().peekable();
+    let has_context = match inputs.peek() {
+        Some(syn::FnArg::Receiver(receiver)) => {
+            return Err(syn::Error::new(
+                receiver.span(),
+                "exported functions cannot take `self`",
+            ));
+        }
+        Some(syn::FnArg::Typed(arg)) => meta.context || is_context_type(&arg.ty),
+        None if meta.context => {
+            return Err(syn::Error::new(
+                sig.span(),
+                "expected a `&mut FunctionContext` argument",
+            ));
+        }
+        None => false,
+    };
+
+    if has_context {
+        // The future of an `async fn` borrows its arguments and cannot
+        // outlive the call
+        if let Some(asyncness) = &sig.asyncness {
+            return Err(syn::Error::new(
+                asyncness.span(),
+                "`async fn` exports cannot take a context; use `#[neon::export(async)]` \
+                 on a function that returns a future",
+            ));
+        }
+
+        inputs.next();
+    }
+
+    let name = &sig.ident;
+    let export_name = meta
+        .name
+        .unwrap_or_else(|| syn::LitStr::new(&name.to_string(), name.span()));
+
+    let inputs = inputs.collect::<Vec<_>>();
+    let args = (0..inputs.len())
+        .map(|i| format_ident!("arg{}", i))
+        .collect::<Vec<_>>();
+
+    let indices = (0..args.len()).collect::<Vec<_>>();
+    let context_arg = if has_context {
+        quote!(&mut cx,)
+    } else {
+        quote!()
+    };
+
+    let register = format_ident!("__NEON_EXPORT_{}", name);
+    let output = if meta.asyncness || sig.asyncness.is_some() {
+        quote!(neon::macro_internal::spawn(&mut cx, res))
+    } else {
+        quote!(neon::macro_internal::into_js(&mut cx, res))
+    };
+
+    let declaration = format_ident!("__NEON_TYPESCRIPT_{}", name);
+    let params = typescript::params(inputs.into_iter(), None);
+    let ret = if meta.asyncness {
+        let output = typescript::future_output(&sig.output);
+
+        quote!(neon::macro_internal::typescript::promise(#output))
+    } else if sig.asyncness.is_some() {
+        let output = typescript::output(&sig.output, None);
+
+        quote!(neon::macro_internal::typescript::promise(#output))
+    } else {
+        typescript::output(&sig.output, None)
+    };
+
+    Ok(quote!(
+        #item
+
+        #[doc(hidden)]
+        #[allow(non_upper_case_globals)]
+        #[neon::macro_internal::linkme::distributed_slice(neon::macro_internal::EXPORTS)]
+        #[linkme(crate = neon::macro_internal::linkme)]
+        static #register: neon::macro_internal::Export = {
+            fn export(cx: &mut neon::context::ModuleContext) -> neon::result::NeonResult<()> {
+                fn wrapper(
+                    mut cx: neon::context::FunctionContext,
+                ) -> neon::result::JsResult<neon::types::JsValue> {
+                    #(let #args = neon::macro_internal::argument(&mut cx, #indices)?;)*
+                    let res = #name(#context_arg #(#args),*);
+
+                    #output
+                }
+
+                cx.export_function(#export_name, wrapper)
+            }
+
+            export
+        };
+
+        #[doc(hidden)]
+        #[allow(non_upper_case_globals)]
+        #[neon::macro_internal::linkme::distributed_slice(neon::macro_internal::typescript::DECLARATIONS)]
+        #[linkme(crate = neon::macro_internal::linkme)]
+        static #declaration: neon::macro_internal::typescript::Declaration = {
+            fn declaration() -> ::std::string::String {
+                neon::macro_internal::typescript::function(#export_name, #params, #ret)
+            }
+
+            declaration
+        };
+    ))
+}
+
+// Checks if a type is `&mut FunctionContext`
+pub(crate) fn is_context_type(ty: &syn::Type) -> bool {
+    let ty = match ty {
+        syn::Type::Reference(ty) if ty.mutability.is_some() => &*ty.elem,
+        _ => return false,
+    };
+
+    match ty {
+        syn::Type::Path(ty) => ty
+            .path
+            .segments
+            .last()
+            .map(|segment| segment.ident == "FunctionContext")
+            .unwrap_or(false),
+        _ => false,
+    }
+}
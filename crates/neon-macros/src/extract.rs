@@ -0,0 +1,579 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{parse_quote, spanned::Spanned};
+
+use crate::meta::{self, MetaItem};
+
+/// Options accepted by `#[neon(...)]` on a struct or enum
+#[derive(Default)]
+struct ContainerMeta {
+    /// Name of the property holding the variant of a tagged enum
+    tag: Option<syn::LitStr>,
+}
+
+impl ContainerMeta {
+    fn parse(attrs: &[syn::Attribute]) -> syn::Result<Self> {
+        let mut meta = ContainerMeta::default();
+
+        for item in parse_attrs(attrs)? {
+            match item {
+                MetaItem::Value(ident, lit) if ident == "tag" => meta.tag = Some(lit),
+                item => return Err(item.unsupported("#[neon]")),
+            }
+        }
+
+        Ok(meta)
+    }
+}
+
+/// Options accepted by `#[neon(...)]` on fields and variants
+#[derive(Default)]
+struct FieldMeta {
+    rename: Option<syn::LitStr>,
+    default: Option<Option<syn::ExprPath>>,
+    skip: bool,
+}
+
+impl FieldMeta {
+    fn parse(attrs: &[syn::Attribute]) -> syn::Result<Self> {
+        let mut meta = FieldMeta::default();
+
+        for item in parse_attrs(attrs)? {
+            match item {
+                MetaItem::Value(ident, lit) if ident == "rename" => meta.rename = Some(lit),
+                MetaItem::Value(ident, lit) if ident == "default" => {
+                    meta.default = Some(Some(lit.parse()?))
+                }
+                MetaItem::Flag(ident) if ident == "default" => meta.default = Some(None),
+                MetaItem::Flag(ident) if ident == "skip" => meta.skip = true,
+                item => return Err(item.unsupported("#[neon]")),
+            }
+        }
+
+        Ok(meta)
+    }
+}
+
+pub(crate) fn parse_attrs(attrs: &[syn::Attribute]) -> syn::Result<Vec<MetaItem>> {
+    let mut items = Vec::new();
+
+    for attr in attrs.iter().filter(|attr| attr.path.is_ident("neon")) {
+        items.extend(attr.parse_args_with(meta::parse_list)?);
+    }
+
+    Ok(items)
+}
+
+struct Field {
+    ident: syn::Ident,
+    ty: syn::Type,
+    // Name of the property in JavaScript
+    name: String,
+    meta: FieldMeta,
+}
+
+struct Variant {
+    ident: syn::Ident,
+    // Value of the tag (or string) in JavaScript
+    name: String,
+    // `None` for unit variants
+    fields: Option<Vec<Field>>,
+}
+
+enum Shape {
+    Struct(Vec<Field>),
+    // Enum of unit variants converted from and to strings
+    Strings(Vec<Variant>),
+    // Enum converted from and to objects with a tag property
+    Tagged(String, Vec<Variant>),
+}
+
+impl Shape {
+    fn new(input: &syn::DeriveInput) -> syn::Result<Self> {
+        let meta = ContainerMeta::parse(&input.attrs)?;
+
+        match &input.data {
+            syn::Data::Struct(data) => {
+                if let Some(tag) = meta.tag {
+                    return Err(syn::Error::new(
+                        tag.span(),
+                        "`tag` is only supported on enums",
+                    ));
+                }
+
+                Ok(Shape::Struct(fields(&data.fields)?))
+            }
+            syn::Data::Enum(data) => {
+                let variants = data
+                    .variants
+                    .iter()
+                    .map(|variant| {
+                        let meta = FieldMeta::parse(&variant.attrs)?;
+
+                        if meta.skip || meta.default.is_some() {
+                            return Err(syn::Error::new(
+                                variant.span(),
+                                "variants only support the `rename` option",
+                            ));
+                        }
+
+                        let fields = match &variant.fields {
+                            syn::Fields::Unit => None,
+                            fields => Some(self::fields(fields)?),
+                        };
+
+                        Ok(Variant {
+                            name: rename(&meta, &variant.ident, variant_name),
+                            ident: variant.ident.clone(),
+                            fields,
+                        })
+                    })
+                    .collect::<syn::Result<Vec<_>>>()?;
+
+                let is_unit = variants.iter().all(|variant| variant.fields.is_none());
+
+                match meta.tag {
+                    None if is_unit => Ok(Shape::Strings(variants)),
+                    tag => {
+                        let tag = tag
+                            .map(|tag| tag.value())
+                            .unwrap_or_else(|| "type".to_string());
+
+                        Ok(Shape::Tagged(tag, variants))
+                    }
+                }
+            }
+            syn::Data::Union(data) => Err(syn::Error::new(
+                data.union_token.span(),
+                "unions are not supported",
+            )),
+        }
+    }
+}
+
+fn fields(fields: &syn::Fields) -> syn::Result<Vec<Field>> {
+    let fields = match fields {
+        syn::Fields::Named(fields) => fields,
+        fields => {
+            return Err(syn::Error::new(
+                fields.span(),
+                "only named fields are supported",
+            ))
+        }
+    };
+
+    fields
+        .named
+        .iter()
+        .map(|field| {
+            let ident = field.ident.clone().expect("named field");
+            let meta = FieldMeta::parse(&field.attrs)?;
+
+            Ok(Field {
+                name: rename(&meta, &ident, field_name),
+                ident,
+                ty: field.ty.clone(),
+                meta,
+            })
+        })
+        .collect()
+}
+
+fn rename(meta: &FieldMeta, ident: &syn::Ident, f: fn(&str) -> String) -> String {
+    match &meta.rename {
+        Some(name) => name.value(),
+        None => f(ident.to_string().trim_start_matches("r#")),
+    }
+}
+
+// Converts a `snake_case` field name to `camelCase`
+pub(crate) fn field_name(name: &str) -> String {
+    let mut out = String::with_capacity(name.len());
+    let mut upper = false;
+
+    for c in name.chars() {
+        if c == '_' && !out.is_empty() {
+            upper = true;
+        } else if upper {
+            out.extend(c.to_uppercase());
+            upper = false;
+        } else {
+            out.push(c);
+        }
+    }
+
+    out
+}
+
+// Converts a `PascalCase` variant name to `camelCase`
+fn variant_name(name: &str) -> String {
+    let mut chars = name.chars();
+
+    match chars.next() {
+        Some(c) => c.to_lowercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+// Description of the expected strings, e.g., `one of "small", "large"`
+fn expected(variants: &[Variant]) -> String {
+    let names = variants
+        .iter()
+        .map(|variant| format!("{:?}", variant.name))
+        .collect::<Vec<_>>();
+
+    format!("one of {}", names.join(", "))
+}
+
+// Adds the `'cx` lifetime and bounds each type parameter by `bound`
+fn generics(input: &syn::DeriveInput, bound: TokenStream) -> syn::Generics {
+    let mut generics = input.generics.clone();
+
+    generics.params.insert(0, parse_quote!('cx));
+
+    let params = input
+        .generics
+        .type_params()
+        .map(|param| param.ident.clone())
+        .collect::<Vec<_>>();
+
+    let where_clause = generics.make_where_clause();
+
+    for param in params {
+        where_clause
+            .predicates
+            .push(parse_quote!(#param: #bound<'cx>));
+    }
+
+    generics
+}
+
+pub(crate) fn try_from_js(input: syn::DeriveInput) -> syn::Result<TokenStream> {
+    let shape = Shape::new(&input)?;
+    let ident = &input.ident;
+    let generics = generics(&input, quote!(neon::types::extract::TryFromJs));
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+
+    let ts = ts_type(&shape, true);
+    let body = match shape {
+        Shape::Struct(fields) => {
+            let fields = from_fields(&fields);
+
+            quote!(
+                let obj = neon::macro_internal::extract::object(cx, v);
+                let obj = match obj {
+                    ::std::result::Result::Ok(obj) => obj,
+                    ::std::result::Result::Err(err) => {
+                        return ::std::result::Result::Ok(::std::result::Result::Err(err))
+                    }
+                };
+
+                ::std::result::Result::Ok(::std::result::Result::Ok(Self { #(#fields)* }))
+            )
+        }
+        Shape::Strings(variants) => {
+            let expected = expected(&variants);
+            let arms = variants.iter().map(|variant| {
+                let name = &variant.name;
+                let ident = &variant.ident;
+
+                quote!(#name => Self::#ident,)
+            });
+
+            quote!(
+                let err = neon::types::extract::ConversionError::new(#expected);
+                let s = match <::std::string::String as neon::types::extract::TryFromJs>::try_from_js(cx, v)? {
+                    ::std::result::Result::Ok(s) => s,
+                    ::std::result::Result::Err(_) => {
+                        return ::std::result::Result::Ok(::std::result::Result::Err(err))
+                    }
+                };
+
+                ::std::result::Result::Ok(::std::result::Result::Ok(match s.as_str() {
+                    #(#arms)*
+                    _ => return ::std::result::Result::Ok(::std::result::Result::Err(err)),
+                }))
+            )
+        }
+        Shape::Tagged(tag, variants) => {
+            let expected = expected(&variants);
+            let arms = variants.iter().map(|variant| {
+                let name = &variant.name;
+                let ident = &variant.ident;
+
+                match &variant.fields {
+                    Some(fields) => {
+                        let fields = from_fields(fields);
+
+                        quote!(#name => Self::#ident { #(#fields)* },)
+                    }
+                    None => quote!(#name => Self::#ident,),
+                }
+            });
+
+            quote!(
+                let obj = neon::macro_internal::extract::object(cx, v);
+                let obj = match obj {
+                    ::std::result::Result::Ok(obj) => obj,
+                    ::std::result::Result::Err(err) => {
+                        return ::std::result::Result::Ok(::std::result::Result::Err(err))
+                    }
+                };
+
+                let tag: ::std::string::String = match neon::macro_internal::extract::field(cx, obj, #tag)? {
+                    ::std::result::Result::Ok(tag) => tag,
+                    ::std::result::Result::Err(err) => {
+                        return ::std::result::Result::Ok(::std::result::Result::Err(err))
+                    }
+                };
+
+                ::std::result::Result::Ok(::std::result::Result::Ok(match tag.as_str() {
+                    #(#arms)*
+                    _ => {
+                        let err = neon::types::extract::ConversionError::new(#expected).field(#tag);
+
+                        return ::std::result::Result::Ok(::std::result::Result::Err(err));
+                    }
+                }))
+            )
+        }
+    };
+
+    Ok(quote!(
+        #[automatically_derived]
+        impl #impl_generics neon::types::extract::TryFromJs<'cx> for #ident #ty_generics #where_clause {
+            fn try_from_js<C>(
+                cx: &mut C,
+                v: neon::handle::Handle<'cx, neon::types::JsValue>,
+            ) -> neon::result::NeonResult<
+                ::std::result::Result<Self, neon::types::extract::ConversionError>,
+            >
+            where
+                C: neon::context::Context<'cx>,
+            {
+                #body
+            }
+
+            fn ts_input() -> ::std::string::String {
+                #ts
+            }
+        }
+    ))
+}
+
+// Field initializers converting each property of `obj`
+fn from_fields(fields: &[Field]) -> Vec<TokenStream> {
+    fields
+        .iter()
+        .map(|field| {
+            let ident = &field.ident;
+            let name = &field.name;
+
+            if field.meta.skip {
+                return quote!(#ident: ::std::default::Default::default(),);
+            }
+
+            let value = match &field.meta.default {
+                Some(Some(default)) => quote!(
+                    neon::macro_internal::extract::field_or_else(cx, obj, #name, #default)?
+                ),
+                Some(None) => quote!(
+                    neon::macro_internal::extract::field_or_else(
+                        cx,
+                        obj,
+                        #name,
+                        ::std::default::Default::default,
+                    )?
+                ),
+                None => quote!(neon::macro_internal::extract::field(cx, obj, #name)?),
+            };
+
+            quote!(
+                #ident: match #value {
+                    ::std::result::Result::Ok(v) => v,
+                    ::std::result::Result::Err(err) => {
+                        return ::std::result::Result::Ok(::std::result::Result::Err(err))
+                    }
+                },
+            )
+        })
+        .collect()
+}
+
+pub(crate) fn try_into_js(input: syn::DeriveInput) -> syn::Result<TokenStream> {
+    let shape = Shape::new(&input)?;
+    let ident = &input.ident;
+    let generics = generics(&input, quote!(neon::types::extract::TryIntoJs));
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+
+    let ts = ts_type(&shape, false);
+    let (value, body) = match shape {
+        Shape::Struct(fields) => {
+            let (bindings, set) = into_fields(&fields);
+
+            (
+                quote!(neon::types::JsObject),
+                quote!(
+                    let Self { #(#bindings)* .. } = self;
+                    let obj = neon::context::Context::empty_object(cx);
+
+                    #(#set)*
+
+                    ::std::result::Result::Ok(obj)
+                ),
+            )
+        }
+        Shape::Strings(variants) => {
+            let arms = variants.iter().map(|variant| {
+                let name = &variant.name;
+                let ident = &variant.ident;
+
+                quote!(Self::#ident => #name,)
+            });
+
+            (
+                quote!(neon::types::JsString),
+                quote!(
+                    let s = match self {
+                        #(#arms)*
+                    };
+
+                    ::std::result::Result::Ok(neon::context::Context::string(cx, s))
+                ),
+            )
+        }
+        Shape::Tagged(tag, variants) => {
+            let arms = variants.iter().map(|variant| {
+                let name = &variant.name;
+                let ident = &variant.ident;
+                let fields = variant.fields.as_deref().unwrap_or(&[]);
+                let (bindings, set) = into_fields(fields);
+                let pattern = match &variant.fields {
+                    Some(_) => quote!(Self::#ident { #(#bindings)* .. }),
+                    None => quote!(Self::#ident),
+                };
+
+                quote!(
+                    #pattern => {
+                        neon::macro_internal::extract::set_field(cx, obj, #tag, #name)?;
+                        #(#set)*
+                    }
+                )
+            });
+
+            (
+                quote!(neon::types::JsObject),
+                quote!(
+                    let obj = neon::context::Context::empty_object(cx);
+
+                    match self {
+                        #(#arms)*
+                    }
+
+                    ::std::result::Result::Ok(obj)
+                ),
+            )
+        }
+    };
+
+    Ok(quote!(
+        #[automatically_derived]
+        impl #impl_generics neon::types::extract::TryIntoJs<'cx> for #ident #ty_generics #where_clause {
+            type Value = #value;
+
+            fn try_into_js<C>(self, cx: &mut C) -> neon::result::JsResult<'cx, #value>
+            where
+                C: neon::context::Context<'cx>,
+            {
+                #body
+            }
+
+            fn ts_output() -> ::std::string::String {
+                #ts
+            }
+        }
+    ))
+}
+
+// Patterns binding each field to a local and statements setting the locals
+// as properties of `obj`
+fn into_fields(fields: &[Field]) -> (Vec<TokenStream>, Vec<TokenStream>) {
+    fields
+        .iter()
+        .filter(|field| !field.meta.skip)
+        .enumerate()
+        .map(|(i, field)| {
+            let ident = &field.ident;
+            let name = &field.name;
+            let binding = format_ident!("field{}", i);
+
+            (
+                quote!(#ident: #binding,),
+                quote!(neon::macro_internal::extract::set_field(cx, obj, #name, #binding)?;),
+            )
+        })
+        .unzip()
+}
+
+// Expression rendering the TypeScript type of a shape as the input of
+// `TryFromJs` or the output of `TryIntoJs`. Fields with a default are
+// optional in the input.
+fn ts_type(shape: &Shape, is_input: bool) -> TokenStream {
+    let (conversion, method) = if is_input {
+        (quote!(TryFromJs), quote!(ts_input))
+    } else {
+        (quote!(TryIntoJs), quote!(ts_output))
+    };
+
+    let props = |fields: &[Field]| {
+        fields
+            .iter()
+            .filter(|field| !field.meta.skip)
+            .map(|field| {
+                let name = &field.name;
+                let ty = &field.ty;
+                let optional = is_input && field.meta.default.is_some();
+
+                quote!((
+                    #name,
+                    #optional,
+                    <#ty as neon::types::extract::#conversion<'cx>>::#method(),
+                ))
+            })
+            .collect::<Vec<_>>()
+    };
+
+    match shape {
+        Shape::Struct(fields) => {
+            let props = props(fields);
+
+            quote!(neon::macro_internal::typescript::object(
+                ::std::vec![#(#props),*]
+            ))
+        }
+        Shape::Strings(variants) => {
+            let names = variants.iter().map(|variant| &variant.name);
+
+            quote!(neon::macro_internal::typescript::union(::std::vec![
+                #(neon::macro_internal::typescript::string(#names)),*
+            ]))
+        }
+        Shape::Tagged(tag, variants) => {
+            let objects = variants.iter().map(|variant| {
+                let name = &variant.name;
+                let props = props(variant.fields.as_deref().unwrap_or(&[]));
+
+                quote!(neon::macro_internal::typescript::object(::std::vec![
+                    (#tag, false, neon::macro_internal::typescript::string(#name)),
+                    #(#props),*
+                ]))
+            });
+
+            quote!(neon::macro_internal::typescript::union(
+                ::std::vec![#(#objects),*]
+            ))
+        }
+    }
+}
@@ -1,5 +1,12 @@
 //! Procedural macros supporting [Neon](https://docs.rs/neon/latest/neon/)
 
+mod class;
+mod error;
+mod export;
+mod extract;
+mod meta;
+mod typescript;
+
 #[proc_macro_attribute]
 /// Marks a function as the main entry point for initialization in
 /// a Neon module.
@@ -18,37 +25,398 @@
 /// }
 /// ```
 ///
-/// If multiple functions are marked with `#[neon::main]`, there may be a compile error:
+/// A main function is optional. Items registered with [`macro@export`],
+/// [`macro@class`] and [`macro@init`] in any crate linked into the module are
+/// registered first, so `main` may observe or override them.
+///
+/// If multiple functions are marked with `#[neon::main]`, loading the module
+/// throws an `Error`.
+///
+/// ## Panic policy
 ///
-/// ```sh
-/// error: symbol `napi_register_module_v1` is already defined
+/// The `panic_policy` option names a function returning a
+/// [`PanicPolicy`](https://docs.rs/neon/latest/neon/panic/struct.PanicPolicy.html),
+/// which is set before any exports are called.
+///
+/// ```ignore
+/// fn panic_policy() -> PanicPolicy {
+///     PanicPolicy::new().location(true).backtrace(true)
+/// }
+///
+/// #[neon::main(panic_policy = "panic_policy")]
+/// fn main(mut cx: ModuleContext) -> NeonResult<()> {
+///     Ok(())
+/// }
 /// ```
 pub fn main(
-    _attr: proc_macro::TokenStream,
+    attr: proc_macro::TokenStream,
     item: proc_macro::TokenStream,
 ) -> proc_macro::TokenStream {
+    let items = syn::parse_macro_input!(attr with meta::parse_list);
     let input = syn::parse_macro_input!(item as syn_mid::ItemFn);
+    let name = &input.sig.ident;
+    let register = quote::format_ident!("__NEON_MAIN_{}", name);
+    let mut panic_policy = None;
+
+    for item in items {
+        match item {
+            meta::MetaItem::Value(ident, lit) if ident == "panic_policy" => {
+                match lit.parse::<syn::Path>() {
+                    Ok(path) => panic_policy = Some(path),
+                    Err(err) => return err.to_compile_error().into(),
+                }
+            }
+            item => return item.unsupported("#[neon::main]").to_compile_error().into(),
+        }
+    }
+
+    // The policy is set by an init hook, since those are called before `main`
+    let panic_policy = panic_policy.map(|path| {
+        let register = quote::format_ident!("__NEON_PANIC_POLICY_{}", name);
 
-    let attrs = &input.attrs;
-    let vis = &input.vis;
-    let sig = &input.sig;
-    let block = &input.block;
-    let name = &sig.ident;
+        quote::quote!(
+            #[doc(hidden)]
+            #[allow(non_upper_case_globals)]
+            #[neon::macro_internal::linkme::distributed_slice(neon::macro_internal::INIT)]
+            #[linkme(crate = neon::macro_internal::linkme)]
+            static #register: neon::macro_internal::Init = |cx| {
+                cx.set_panic_policy(#path());
+                Ok(())
+            };
+        )
+    });
 
     quote::quote!(
-        #(#attrs) *
-        #vis #sig {
-            #[no_mangle]
-            unsafe extern "C" fn napi_register_module_v1(
-                env: *mut std::ffi::c_void,
-                m: *mut std::ffi::c_void,
-            ) -> *mut std::ffi::c_void {
-                neon::macro_internal::initialize_module(env, m, #name);
-                m
-            }
+        #input
 
-            #block
-        }
+        #[doc(hidden)]
+        #[allow(non_upper_case_globals)]
+        #[neon::macro_internal::linkme::distributed_slice(neon::macro_internal::MAIN)]
+        #[linkme(crate = neon::macro_internal::linkme)]
+        static #register: neon::macro_internal::Main = #name;
+
+        #panic_policy
+    )
+    .into()
+}
+
+#[proc_macro_attribute]
+/// Registers a function that is called each time the module is initialized.
+///
+/// Init hooks may be declared in any crate linked into the module, including
+/// library crates that contribute exports. They are called after items
+/// registered with [`macro@export`] and [`macro@class`], and before the
+/// [`macro@main`] function. The order of init hooks is unspecified.
+///
+/// ```ignore
+/// #[neon::init]
+/// fn init(cx: &mut ModuleContext) -> NeonResult<()> {
+///     let version = cx.string(env!("CARGO_PKG_VERSION"));
+///
+///     cx.export_value("bindingsVersion", version)
+/// }
+/// ```
+pub fn init(
+    _attr: proc_macro::TokenStream,
+    item: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    let input = syn::parse_macro_input!(item as syn_mid::ItemFn);
+    let name = &input.sig.ident;
+    let register = quote::format_ident!("__NEON_INIT_{}", name);
+
+    quote::quote!(
+        #input
+
+        #[doc(hidden)]
+        #[allow(non_upper_case_globals)]
+        #[neon::macro_internal::linkme::distributed_slice(neon::macro_internal::INIT)]
+        #[linkme(crate = neon::macro_internal::linkme)]
+        static #register: neon::macro_internal::Init = #name;
     )
     .into()
 }
+
+#[proc_macro_attribute]
+/// Export a function from a Neon module.
+///
+/// Arguments are converted from JavaScript with
+/// [`TryFromJs`](https://docs.rs/neon/latest/neon/types/extract/trait.TryFromJs.html)
+/// and the return value is converted to JavaScript with
+/// [`TryIntoJs`](https://docs.rs/neon/latest/neon/types/extract/trait.TryIntoJs.html).
+/// An argument that cannot be converted throws a `TypeError` naming its position.
+///
+/// ```ignore
+/// #[neon::export]
+/// fn add(a: f64, b: f64) -> f64 {
+///     a + b
+/// }
+/// ```
+///
+/// Exported functions are registered when the module is initialized, before
+/// the `#[neon::main]` function is called.
+///
+/// ## Renaming
+///
+/// The function is exported with the name of the Rust function. Provide a
+/// `name` to use a different name in JavaScript:
+///
+/// ```ignore
+/// #[neon::export(name = "addNumbers")]
+/// fn add(a: f64, b: f64) -> f64 {
+///     a + b
+/// }
+/// ```
+///
+/// ## Context
+///
+/// If the first argument is a `&mut FunctionContext`, it is passed the context
+/// of the call. The `context` option may be used if the type is an alias.
+/// Lifetimes of handles returned from the function must be named explicitly.
+///
+/// ```ignore
+/// #[neon::export]
+/// fn hello<'cx>(cx: &mut FunctionContext<'cx>, name: String) -> JsResult<'cx, JsString> {
+///     Ok(cx.string(format!("Hello, {}!", name)))
+/// }
+/// ```
+///
+/// ## Async
+///
+/// An `async fn` is exported as a function that returns a `Promise`. Arguments
+/// are converted before the future is spawned on the executor configured in
+/// `neon::executor`, and the promise is settled with the output of the future.
+/// Returning an `Err` rejects the promise.
+///
+/// Requires the `futures` and `napi-5` features. Unless another executor is set,
+/// futures are polled on a current-thread tokio runtime running on a dedicated
+/// thread, or on a multi-threaded tokio runtime with the `tokio-rt-multi-thread`
+/// feature.
+///
+/// ```ignore
+/// #[neon::export]
+/// async fn add(a: f64, b: f64) -> f64 {
+///     a + b
+/// }
+/// ```
+///
+/// The `async` option exports a function that returns a future. The function
+/// is called on the JavaScript thread, so it may take a context and handles:
+///
+/// ```ignore
+/// #[neon::export(async)]
+/// fn sum(cx: &mut FunctionContext, nums: Handle<JsFloat64Array>) -> impl Future<Output = f64> {
+///     let nums = nums.as_slice(cx).to_vec();
+///
+///     async move { nums.into_iter().sum() }
+/// }
+/// ```
+///
+/// ## TypeScript
+///
+/// The signature of each export is recorded for `neon::typescript`, which
+/// generates TypeScript declarations for the module.
+pub fn export(
+    attr: proc_macro::TokenStream,
+    item: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    let meta = syn::parse_macro_input!(attr as export::Meta);
+    let item = syn::parse_macro_input!(item as syn::Item);
+
+    export::export(meta, item)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+#[proc_macro_attribute]
+/// Define a JavaScript class from the methods of a Rust struct.
+///
+/// The attribute is applied to an `impl` block and exports a class with the
+/// name of the struct. Instances own a value of the struct and `&self` and
+/// `&mut self` methods are called with the value wrapped by `this`. Arguments
+/// and return values are converted in the same way as [`macro@export`].
+///
+/// ```ignore
+/// struct Counter {
+///     count: f64,
+/// }
+///
+/// #[neon::class]
+/// impl Counter {
+///     fn new(count: f64) -> Self {
+///         Self { count }
+///     }
+///
+///     fn increment(&mut self) -> f64 {
+///         self.count += 1.0;
+///         self.count
+///     }
+///
+///     #[neon(getter)]
+///     fn count(&self) -> f64 {
+///         self.count
+///     }
+///
+///     #[neon(setter)]
+///     fn set_count(&mut self, count: f64) {
+///         self.count = count;
+///     }
+///
+///     fn zero() -> f64 {
+///         0.0
+///     }
+/// }
+/// ```
+///
+/// ```js
+/// const counter = new Counter(1);
+///
+/// counter.increment(); // 2
+/// counter.count = 10;
+/// Counter.zero(); // 0
+/// ```
+///
+/// Methods without a receiver are defined on the constructor. Each method
+/// accepts options in a `#[neon(...)]` attribute:
+///
+/// * `constructor`: Constructs an instance. Defaults to a method named `new`.
+///   The constructor may return `Self` or `NeonResult<Self>`.
+/// * `getter` / `setter`: Defines an accessor property. Setters are named
+///   after the method with a `set_` prefix removed.
+/// * `name = "..."`: Name of the property in JavaScript.
+/// * `context`: The first argument after the receiver is a `&mut FunctionContext`.
+///   This is detected automatically unless the type is an alias.
+/// * `skip`: The method is not exposed to JavaScript.
+///
+/// Calling a method with a `this` that is not an instance of the class throws
+/// a `TypeError`. Calling a method that borrows the instance while a `&mut self`
+/// method is executing (e.g., re-entrantly from a JavaScript callback) throws
+/// an `Error`.
+///
+/// The class is declared by `neon::typescript` with each of its members.
+pub fn class(
+    attr: proc_macro::TokenStream,
+    item: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    let meta = syn::parse_macro_input!(attr as class::Meta);
+    let item = syn::parse_macro_input!(item as syn::ItemImpl);
+
+    class::class(meta, item)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+#[proc_macro_derive(TryFromJs, attributes(neon))]
+/// Derive [`TryFromJs`](https://docs.rs/neon/latest/neon/types/extract/trait.TryFromJs.html)
+/// for a struct or enum.
+///
+/// Structs with named fields are converted from objects. Each field is
+/// converted from the property of the same name in `camelCase`. An error names
+/// the path of the property that failed to convert (e.g., `retry.maxDelay: expected number`).
+///
+/// ```ignore
+/// #[derive(TryFromJs, TryIntoJs)]
+/// struct Options {
+///     // Converted from `retryCount`
+///     retry_count: u32,
+///     #[neon(rename = "URL")]
+///     url: String,
+///     // Uses `Default::default()` if the property is `undefined`
+///     #[neon(default)]
+///     verbose: bool,
+///     // Uses the result of a function if the property is `undefined`
+///     #[neon(default = "default_timeout")]
+///     timeout: f64,
+///     // Not converted; always `Default::default()`
+///     #[neon(skip)]
+///     cache: Option<Vec<u8>>,
+/// }
+/// ```
+///
+/// Enums of unit variants are converted from strings, with each variant named
+/// in `camelCase` (`"small" | "extraLarge"`). Other enums are converted from
+/// objects with a `type` property naming the variant and the fields of the
+/// variant as properties (`{ type: "circle", radius: 1 }`). The name of the tag
+/// property may be changed with `#[neon(tag = "kind")]` on the enum.
+///
+/// ```ignore
+/// #[derive(TryFromJs, TryIntoJs)]
+/// enum Shape {
+///     Circle { radius: f64 },
+///     Rect { width: f64, height: f64 },
+///     #[neon(rename = "dot")]
+///     Point,
+/// }
+/// ```
+pub fn try_from_js(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = syn::parse_macro_input!(item as syn::DeriveInput);
+
+    extract::try_from_js(input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+#[proc_macro_derive(TryIntoJs, attributes(neon))]
+/// Derive [`TryIntoJs`](https://docs.rs/neon/latest/neon/types/extract/trait.TryIntoJs.html)
+/// for a struct or enum.
+///
+/// Values are converted to the same representation accepted by
+/// [`macro@TryFromJs`] and the same `#[neon(...)]` options are supported.
+/// Fields marked `skip` are omitted.
+pub fn try_into_js(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = syn::parse_macro_input!(item as syn::DeriveInput);
+
+    extract::try_into_js(input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+#[proc_macro_derive(JsError, attributes(neon))]
+/// Derive a conversion of a Rust error into a JavaScript `Error`.
+///
+/// The derive implements [`TryIntoJs`](https://docs.rs/neon/latest/neon/types/extract/trait.TryIntoJs.html)
+/// for a struct or enum that implements `std::error::Error`, so that returning
+/// `Err` from an exported function throws the converted error:
+///
+/// * The `message` is the [`Display`](std::fmt::Display) output of the error
+/// * The `code` is set for each variant; it defaults to `ERR_` followed by the
+///   name of the variant in `SCREAMING_SNAKE_CASE` (`NotFound` is `ERR_NOT_FOUND`)
+/// * Named fields are converted with `TryIntoJs` and set as properties in `camelCase`
+/// * The [`source`](std::error::Error::source) chain is converted to the `cause`
+///
+/// ```ignore
+/// #[derive(Debug, JsError)]
+/// enum ConfigError {
+///     #[neon(code = "ERR_CONFIG_MISSING")]
+///     Missing { path: String },
+///     #[neon(type_error)]
+///     Invalid { key: String, expected: String },
+///     #[neon(range_error)]
+///     TooLarge { size: u64, max_size: u64 },
+///     Io(std::io::Error),
+/// }
+///
+/// # impl std::fmt::Display for ConfigError { ... }
+/// # impl std::error::Error for ConfigError { ... }
+///
+/// #[neon::export]
+/// fn load_config(path: String) -> Result<Config, ConfigError> {
+///     let text = std::fs::read_to_string(&path).map_err(ConfigError::Io)?;
+///     // ...
+/// }
+/// ```
+///
+/// Variants (or a struct) accept the following options in `#[neon(...)]`:
+///
+/// * `type_error` / `range_error`: Creates a `TypeError` or `RangeError`. On an
+///   enum, sets the class of all variants.
+/// * `code = "..."`: The `code` property of the error.
+///
+/// Fields accept `rename = "..."` and `skip`. Fields of tuple variants are not
+/// converted.
+pub fn js_error(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = syn::parse_macro_input!(item as syn::DeriveInput);
+
+    error::js_error(input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
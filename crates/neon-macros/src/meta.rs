@@ -0,0 +1,53 @@
+use syn::{
+    ext::IdentExt,
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+};
+
+/// An option passed to a Neon attribute, either a flag (`context`) or a
+/// string value (`name = "addNumbers"`)
+pub(crate) enum MetaItem {
+    Flag(syn::Ident),
+    Value(syn::Ident, syn::LitStr),
+}
+
+impl MetaItem {
+    pub(crate) fn ident(&self) -> &syn::Ident {
+        match self {
+            MetaItem::Flag(ident) | MetaItem::Value(ident, _) => ident,
+        }
+    }
+
+    /// Error for an option that is not supported by an attribute
+    pub(crate) fn unsupported(&self, attr: &str) -> syn::Error {
+        let ident = self.ident();
+
+        syn::Error::new(
+            ident.span(),
+            format!("unsupported `{}` option `{}`", attr, ident),
+        )
+    }
+}
+
+impl Parse for MetaItem {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        // Options may be keywords (e.g., `async`)
+        let ident = syn::Ident::parse_any(input)?;
+
+        if input.peek(syn::Token![=]) {
+            input.parse::<syn::Token![=]>()?;
+            Ok(MetaItem::Value(ident, input.parse()?))
+        } else {
+            Ok(MetaItem::Flag(ident))
+        }
+    }
+}
+
+/// Parses a comma separated list of options
+pub(crate) fn parse_list(input: ParseStream) -> syn::Result<Vec<MetaItem>> {
+    Ok(
+        Punctuated::<MetaItem, syn::Token![,]>::parse_terminated(input)?
+            .into_iter()
+            .collect(),
+    )
+}
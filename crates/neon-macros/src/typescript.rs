@@ -0,0 +1,132 @@
+//! Expressions rendering the TypeScript types of exported signatures
+
+use proc_macro2::{Ident, Punct, Spacing, Span, TokenStream, TokenTree};
+use quote::{quote, ToTokens};
+
+/// Type accepted by an argument of type `ty`
+pub(crate) fn input(ty: &syn::Type, self_ty: Option<&syn::Type>) -> TokenStream {
+    let ty = erase(ty, self_ty);
+
+    quote!(<#ty as neon::types::extract::TryFromJs>::ts_input())
+}
+
+/// Type returned by a function with the return type `output`
+pub(crate) fn output(output: &syn::ReturnType, self_ty: Option<&syn::Type>) -> TokenStream {
+    match output {
+        syn::ReturnType::Default => quote!(::std::string::String::from("undefined")),
+        syn::ReturnType::Type(_, ty) => {
+            let ty = erase(ty, self_ty);
+
+            quote!(<#ty as neon::types::extract::TryIntoJs>::ts_output())
+        }
+    }
+}
+
+/// Type of the output of a function returning `impl Future<Output = T>`
+pub(crate) fn future_output(output: &syn::ReturnType) -> TokenStream {
+    let bounds = match output {
+        syn::ReturnType::Type(_, ty) => match &**ty {
+            syn::Type::ImplTrait(ty) => &ty.bounds,
+            _ => return quote!(::std::string::String::from("unknown")),
+        },
+        syn::ReturnType::Default => return quote!(::std::string::String::from("unknown")),
+    };
+
+    let ty = bounds.iter().find_map(|bound| match bound {
+        syn::TypeParamBound::Trait(bound) => {
+            let segment = bound.path.segments.last()?;
+
+            if segment.ident != "Future" {
+                return None;
+            }
+
+            match &segment.arguments {
+                syn::PathArguments::AngleBracketed(args) => {
+                    args.args.iter().find_map(|arg| match arg {
+                        syn::GenericArgument::Binding(binding) if binding.ident == "Output" => {
+                            Some(binding.ty.clone())
+                        }
+                        _ => None,
+                    })
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    });
+
+    match ty {
+        Some(ty) => self::output(&syn::parse_quote!(-> #ty), None),
+        None => quote!(::std::string::String::from("unknown")),
+    }
+}
+
+/// Parameters of a declaration from the typed arguments of a function
+pub(crate) fn params<'a>(
+    args: impl Iterator<Item = &'a syn::FnArg>,
+    self_ty: Option<&syn::Type>,
+) -> TokenStream {
+    let params = args.enumerate().filter_map(|(i, arg)| {
+        let arg = match arg {
+            syn::FnArg::Typed(arg) => arg,
+            syn::FnArg::Receiver(_) => return None,
+        };
+
+        let name = match &*arg.pat {
+            syn::Pat::Ident(pat) => pat.ident.to_string().trim_start_matches("r#").to_string(),
+            _ => format!("arg{}", i),
+        };
+
+        let ty = input(&arg.ty, self_ty);
+
+        Some(quote!((#name, #ty)))
+    });
+
+    quote!(::std::vec![#(#params),*])
+}
+
+// Declarations are rendered outside of the scope of the function, so named
+// lifetimes are erased and `Self` is replaced with the type of the class
+fn erase(ty: &syn::Type, self_ty: Option<&syn::Type>) -> syn::Type {
+    let tokens = erase_tokens(ty.to_token_stream(), self_ty);
+
+    syn::parse2(tokens).unwrap_or_else(|_| ty.clone())
+}
+
+fn erase_tokens(tokens: TokenStream, self_ty: Option<&syn::Type>) -> TokenStream {
+    let mut out = TokenStream::new();
+    let mut lifetime = false;
+
+    for token in tokens {
+        match token {
+            TokenTree::Punct(punct) if punct.as_char() == '\'' => {
+                lifetime = true;
+                out.extend(Some(TokenTree::Punct(Punct::new('\'', Spacing::Joint))));
+            }
+            TokenTree::Ident(ident) if lifetime => {
+                lifetime = false;
+
+                if ident == "static" {
+                    out.extend(Some(TokenTree::Ident(ident)));
+                } else {
+                    out.extend(Some(TokenTree::Ident(Ident::new("_", Span::call_site()))));
+                }
+            }
+            TokenTree::Ident(ident) if ident == "Self" && self_ty.is_some() => {
+                let self_ty = self_ty.unwrap();
+
+                out.extend(quote!(#self_ty));
+            }
+            TokenTree::Group(group) => {
+                let stream = erase_tokens(group.stream(), self_ty);
+                let mut erased = proc_macro2::Group::new(group.delimiter(), stream);
+
+                erased.set_span(group.span());
+                out.extend(Some(TokenTree::Group(erased)));
+            }
+            token => out.extend(Some(token)),
+        }
+    }
+
+    out
+}
@@ -1,9 +1,8 @@
 use std::{cell::RefCell, ffi::c_void, mem::MaybeUninit};
 
 use crate::{
-    context::ModuleContext,
+    context::{Context, ModuleContext},
     handle::Handle,
-    result::NeonResult,
     sys::{self, raw},
     types::{private::ValueInternal, JsObject},
 };
@@ -50,11 +49,7 @@ pub trait ContextInternal<'a>: Sized {
     fn env(&self) -> Env;
 }
 
-pub unsafe fn initialize_module(
-    env: *mut c_void,
-    exports: *mut c_void,
-    init: fn(ModuleContext) -> NeonResult<()>,
-) {
+pub unsafe fn initialize_module(env: *mut c_void, exports: *mut c_void) {
     let env = env.cast();
 
     sys::setup(env);
@@ -66,7 +61,38 @@ pub unsafe fn initialize_module(
     let env = Env(env);
     let exports = Handle::new_internal(JsObject::from_local(env, exports.cast()));
 
-    ModuleContext::with(env, exports, |cx| {
-        let _ = init(cx);
+    ModuleContext::with(env, exports, |mut cx| {
+        // Exports and init hooks from every linked crate are registered before
+        // calling `main` so that `main` may observe or override them
+        for export in crate::macro_internal::EXPORTS {
+            if export(&mut cx).is_err() {
+                return;
+            }
+        }
+
+        for init in crate::macro_internal::INIT {
+            if init(&mut cx).is_err() {
+                return;
+            }
+        }
+
+        match *crate::macro_internal::MAIN {
+            [] => {}
+            [main] => {
+                let _ = main(cx);
+            }
+            _ => {
+                let _ =
+                    cx.throw_error::<_, ()>("multiple functions are marked with `#[neon::main]`");
+            }
+        }
     });
 }
+
+#[cfg(not(feature = "no-module-entry"))]
+#[no_mangle]
+// Called by Node when the module is loaded
+unsafe extern "C" fn napi_register_module_v1(env: *mut c_void, m: *mut c_void) -> *mut c_void {
+    initialize_module(env, m);
+    m
+}
@@ -149,6 +149,7 @@ use crate::{
     event::TaskBuilder,
     handle::Handle,
     object::Object,
+    panic::PanicPolicy,
     result::{JsResult, NeonResult, Throw},
     sys::{
         self, raw,
@@ -157,6 +158,7 @@ use crate::{
     types::{
         boxed::{Finalize, JsBox},
         error::JsError,
+        extract::TryFromJs,
         private::ValueInternal,
         Deferred, JsArray, JsArrayBuffer, JsBoolean, JsBuffer, JsFunction, JsNull, JsNumber,
         JsObject, JsPromise, JsString, JsUndefined, JsValue, StringResult, Value,
@@ -429,6 +431,45 @@ pub trait Context<'a>: ContextInternal<'a> {
         self.throw(err)
     }
 
+    /// Throws an instance of the [`Error`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/Error) class
+    /// with a `code` property.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// # fn test(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    /// cx.throw_error_with_code("ERR_NOT_FOUND", "user not found")
+    /// # }
+    /// ```
+    ///
+    /// **See also:** [`JsError::error_with`]
+    fn throw_error_with_code<S: AsRef<str>, M: AsRef<str>, T>(
+        &mut self,
+        code: S,
+        msg: M,
+    ) -> NeonResult<T> {
+        JsError::error_with(self, msg).code(code).throw(self)
+    }
+
+    /// Throws an instance of the [`TypeError`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/TypeError) class
+    /// with a `code` property.
+    fn throw_type_error_with_code<S: AsRef<str>, M: AsRef<str>, T>(
+        &mut self,
+        code: S,
+        msg: M,
+    ) -> NeonResult<T> {
+        JsError::type_error_with(self, msg).code(code).throw(self)
+    }
+
+    /// Throws an instance of the [`RangeError`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/RangeError) class
+    /// with a `code` property.
+    fn throw_range_error_with_code<S: AsRef<str>, M: AsRef<str>, T>(
+        &mut self,
+        code: S,
+        msg: M,
+    ) -> NeonResult<T> {
+        JsError::range_error_with(self, msg).code(code).throw(self)
+    }
+
     /// Convenience method for wrapping a value in a `JsBox`.
     ///
     /// # Example:
@@ -449,6 +490,18 @@ pub trait Context<'a>: ContextInternal<'a> {
         JsBox::new(self, v)
     }
 
+    /// Adjusts the amount of native memory kept alive by JavaScript objects that is
+    /// reported to the garbage collector by `change_in_bytes`. Returns the adjusted
+    /// amount.
+    ///
+    /// Reporting memory owned by Rust values, e.g., the contents of a [`JsBox`],
+    /// allows the garbage collector to collect their objects sooner. Each increase
+    /// should be matched by a decrease when the memory is released. See
+    /// [`JsBox::new_with_size`] for reporting the size of a `JsBox`.
+    fn adjust_external_memory(&mut self, change_in_bytes: i64) -> i64 {
+        unsafe { sys::mem::adjust_external_memory(self.env().to_raw(), change_in_bytes) }
+    }
+
     #[cfg(feature = "napi-4")]
     #[deprecated(since = "0.9.0", note = "Please use the channel() method instead")]
     #[doc(hidden)]
@@ -531,6 +584,91 @@ pub trait Context<'a>: ContextInternal<'a> {
         TaskBuilder::new(self, execute)
     }
 
+    #[cfg(all(feature = "napi-5", feature = "futures"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "napi-5", feature = "futures"))))]
+    /// Spawns a future on the [executor](crate::executor) and returns a
+    /// [`JsPromise`] settled with its output.
+    ///
+    /// The output is converted with [`TryIntoJs`](crate::types::extract::TryIntoJs);
+    /// an `Err` output rejects the promise. Throws an `Error` if no executor is
+    /// available; see [choosing an executor](crate::executor#choosing-an-executor).
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn greet(mut cx: FunctionContext) -> JsResult<JsPromise> {
+    ///     let name: String = cx.argument_as(0)?;
+    ///
+    ///     cx.spawn(async move { format!("Hello, {}!", name) })
+    /// }
+    /// ```
+    fn spawn<F, V>(&mut self, fut: F) -> JsResult<'a, JsPromise>
+    where
+        F: std::future::Future<Output = V> + Send + 'static,
+        V: for<'b> crate::types::extract::TryIntoJs<'b> + Send + 'static,
+    {
+        crate::executor::spawn_promise(self, fut)
+    }
+
+    #[cfg(feature = "napi-6")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "napi-6")))]
+    /// Registers a function to be called on the JavaScript thread when the current
+    /// instance of the module is unloaded, e.g., when a worker thread exits or Node
+    /// shuts down.
+    ///
+    /// Functions are called in the reverse order they were registered, before Neon
+    /// releases its own resources for the instance. JavaScript cannot be called
+    /// while the instance is being unloaded.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// # use std::{fs::File, io::Write};
+    /// fn open_log(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    ///     let mut log = File::create("addon.log").or_else(|err| cx.throw_error(err.to_string()))?;
+    ///
+    ///     cx.on_cleanup(move || {
+    ///         let _ = log.flush();
+    ///     });
+    ///
+    ///     Ok(cx.undefined())
+    /// }
+    /// ```
+    fn on_cleanup<F>(&mut self, f: F)
+    where
+        F: FnOnce() + 'static,
+    {
+        InstanceData::on_cleanup(self, f)
+    }
+
+    #[cfg(feature = "napi-8")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "napi-8")))]
+    /// Registers a function to be called on the
+    /// [Node worker pool](https://nodejs.org/en/docs/guides/dont-block-the-event-loop/)
+    /// when the current instance of the module is unloaded. Unloading waits for the
+    /// function to complete without blocking the JavaScript thread, making it suitable
+    /// for slow cleanup, e.g., joining threads.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// # use std::{sync::mpsc, thread};
+    /// fn start_worker(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    ///     let (tx, rx) = mpsc::channel::<String>();
+    ///     let worker = thread::spawn(move || for _msg in rx {});
+    ///
+    ///     cx.on_cleanup_async(move || {
+    ///         drop(tx);
+    ///         let _ = worker.join();
+    ///     });
+    ///
+    ///     Ok(cx.undefined())
+    /// }
+    /// ```
+    fn on_cleanup_async<F>(&mut self, f: F)
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        InstanceData::on_cleanup_async(self, f)
+    }
+
     #[cfg(feature = "sys")]
     #[cfg_attr(docsrs, doc(cfg(feature = "sys")))]
     /// Gets the raw `sys::Env` for usage with Node-API.
@@ -592,6 +730,14 @@ impl<'a> ModuleContext<'a> {
     pub fn exports_object(&mut self) -> JsResult<'a, JsObject> {
         Ok(self.exports)
     }
+
+    /// Sets how Rust panics are reported to JavaScript. The policy applies to
+    /// every instance of the module, including instances loaded by worker threads.
+    ///
+    /// See [`neon::panic`](crate::panic) for details.
+    pub fn set_panic_policy(&mut self, policy: PanicPolicy) {
+        crate::panic::set_policy(policy);
+    }
 }
 
 impl<'a> ContextInternal<'a> for ModuleContext<'a> {
@@ -691,6 +837,32 @@ impl<'a> FunctionContext<'a> {
         }
     }
 
+    /// Produces the `i`th argument and converts it to `T` with [`TryFromJs`]. Missing
+    /// arguments are converted from `undefined`.
+    ///
+    /// Throws a `TypeError` naming the argument if the conversion fails.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn add(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    ///     let a: f64 = cx.argument_as(0)?;
+    ///     let b: Option<f64> = cx.argument_as(1)?;
+    ///
+    ///     Ok(cx.number(a + b.unwrap_or(0.0)))
+    /// }
+    /// ```
+    pub fn argument_as<T: TryFromJs<'a>>(&mut self, i: usize) -> NeonResult<T> {
+        let v = match self.argument_opt(i) {
+            Some(v) => v,
+            None => self.undefined().upcast(),
+        };
+
+        match T::try_from_js(self, v)? {
+            Ok(v) => Ok(v),
+            Err(err) => err.index(i).field("arguments").throw(self),
+        }
+    }
+
     /// Produces a handle to the `this`-binding and attempts to downcast as a specific type.
     /// Equivalent to calling `cx.this_value().downcast_or_throw(&mut cx)`.
     ///
@@ -736,8 +908,12 @@ impl<'a> ContextInternal<'a> for TaskContext<'a> {
 
 impl<'a> Context<'a> for TaskContext<'a> {}
 
-/// A view of the JS engine in the context of a finalize method on garbage collection
-pub(crate) struct FinalizeContext<'a> {
+/// An execution context of a finalizer, called when an object is garbage collected.
+///
+/// A `FinalizeContext` is passed to the finalizers of [`Object::add_finalizer`](crate::object::Object::add_finalizer)
+/// and, as a generic [`Context`], to [`Finalize::finalize`](crate::types::Finalize::finalize)
+/// for the contents of a [`JsBox`](crate::types::JsBox).
+pub struct FinalizeContext<'a> {
     env: Env,
     _phantom_inner: PhantomData<&'a ()>,
 }
@@ -1,5 +1,6 @@
 use std::{
-    error, fmt, mem,
+    error, fmt,
+    mem::{self, MaybeUninit},
     sync::{
         atomic::{AtomicUsize, Ordering},
         Arc,
@@ -7,9 +8,10 @@ use std::{
 };
 
 use crate::{
-    context::{Context, TaskContext},
-    result::{NeonResult, ResultExt, Throw},
-    sys::{raw::Env, tsfn::ThreadsafeFunction},
+    context::{internal::ContextInternal, Context, TaskContext},
+    result::{JsException, NeonResult, ResultExt},
+    sys::{self, raw::Env, tsfn::ThreadsafeFunction},
+    types::{JsError, JsValue},
 };
 
 #[cfg(feature = "futures")]
@@ -120,6 +122,19 @@ impl Channel {
         }
     }
 
+    #[cfg(feature = "napi-6")]
+    /// Creates a channel that cannot schedule closures, used after the environment
+    /// has been torn down. Sending always fails with a [`SendError`].
+    pub(crate) fn closed() -> Self {
+        Self {
+            state: Arc::new(ChannelState {
+                tsfn: ThreadsafeFunction::closed(ChannelState::callback),
+                ref_count: AtomicUsize::new(0),
+            }),
+            has_ref: false,
+        }
+    }
+
     /// Allow the Node event loop to exit while this `Channel` exists.
     /// _Idempotent_
     pub fn unref<'a, C: Context<'a>>(&mut self, cx: &mut C) -> &mut Self {
@@ -172,8 +187,9 @@ impl Channel {
             // Note: It is sufficient to use `TaskContext`'s `InheritedHandleScope` because
             // N-API creates a `HandleScope` before calling the callback.
             TaskContext::with_context(env, move |cx| {
-                // Error can be ignored; it only means the user didn't join
-                let _ = tx.send(f(cx).map_err(Into::into));
+                let env = cx.env();
+
+                SendThrow::send(env, f(cx), |result| tx.send(result));
             });
         });
 
@@ -260,7 +276,8 @@ impl Drop for Channel {
 /// An owned permission to join on the result of a closure sent to the JavaScript main
 /// thread with [`Channel::send`].
 pub struct JoinHandle<T> {
-    // `Err` is always `Throw`, but `Throw` cannot be sent across threads
+    // `Err` is always `Throw`, but `Throw` cannot be sent across threads, so a
+    // copy of the exception is sent instead
     rx: oneshot::Receiver<Result<T, SendThrow>>,
 }
 
@@ -301,19 +318,32 @@ impl<T> Future for JoinHandle<T> {
 #[derive(Debug)]
 /// Error returned by [`JoinHandle::join`] indicating the associated closure panicked
 /// or threw an exception.
+///
+/// If the closure threw, the exception is available from [`JoinError::exception`].
+/// An exception is only emitted as an uncaught exception if the `JoinHandle` had
+/// already been dropped when the closure threw. Otherwise, the exception is only
+/// reported by joining the `JoinHandle` and is discarded if it is dropped later.
 pub struct JoinError(JoinErrorType);
 
 #[derive(Debug)]
 enum JoinErrorType {
     Panic,
-    Throw,
+    Throw(Option<JsException>),
 }
 
 impl JoinError {
     fn as_str(&self) -> &str {
         match &self.0 {
             JoinErrorType::Panic => "Closure panicked before returning",
-            JoinErrorType::Throw => "Closure threw an exception",
+            JoinErrorType::Throw(_) => "Closure threw an exception",
+        }
+    }
+
+    /// Returns the exception thrown by the closure, or `None` if it panicked.
+    pub fn exception(&self) -> Option<&JsException> {
+        match &self.0 {
+            JoinErrorType::Panic => None,
+            JoinErrorType::Throw(exception) => exception.as_ref(),
         }
     }
 }
@@ -324,7 +354,11 @@ impl fmt::Display for JoinError {
     }
 }
 
-impl error::Error for JoinError {}
+impl error::Error for JoinError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        self.exception().map(|exception| exception as _)
+    }
+}
 
 impl From<oneshot::error::RecvError> for JoinError {
     fn from(_: oneshot::error::RecvError) -> Self {
@@ -332,24 +366,60 @@ impl From<oneshot::error::RecvError> for JoinError {
     }
 }
 
-// Marker that a `Throw` occurred that can be sent across threads for use in `JoinError`
-pub(crate) struct SendThrow(());
+// A `Throw` with a copy of the exception that can be sent across threads for use
+// in `JoinError`
+pub(crate) struct SendThrow(Option<JsException>);
+
+impl SendThrow {
+    // Sends the result of a closure to a `JoinHandle` or `JsFuture`. If the closure
+    // threw, a copy of the exception is sent instead. The exception is only left
+    // pending, to be emitted as an uncaught exception, if the receiver has dropped.
+    pub(crate) fn send<T, E>(
+        env: crate::context::internal::Env,
+        result: NeonResult<T>,
+        send: impl FnOnce(Result<T, SendThrow>) -> Result<(), E>,
+    ) {
+        if let Ok(v) = result {
+            // Error can be ignored; it only means the user didn't join
+            let _ = send(Ok(v));
+            return;
+        }
 
-impl From<SendThrow> for JoinError {
-    fn from(_: SendThrow) -> Self {
-        JoinError(JoinErrorType::Throw)
+        TaskContext::with_context(env, |mut cx| unsafe {
+            let mut local = MaybeUninit::zeroed();
+
+            if !sys::error::catch_error(env.to_raw(), local.as_mut_ptr()) {
+                let _ = send(Err(SendThrow(None)));
+                return;
+            }
+
+            let local = local.assume_init();
+            let exception = JsException::new(&mut cx, JsValue::new_internal(local));
+
+            if send(Err(SendThrow(Some(exception)))).is_err() {
+                sys::error::throw(env.to_raw(), local);
+            }
+        });
     }
 }
 
-impl From<Throw> for SendThrow {
-    fn from(_: Throw) -> SendThrow {
-        SendThrow(())
+impl From<SendThrow> for JoinError {
+    fn from(throw: SendThrow) -> Self {
+        JoinError(JoinErrorType::Throw(throw.0))
     }
 }
 
 impl<T> ResultExt<T> for Result<T, JoinError> {
     fn or_throw<'a, C: Context<'a>>(self, cx: &mut C) -> NeonResult<T> {
-        self.or_else(|err| cx.throw_error(err.as_str()))
+        self.or_else(|err| {
+            let mut options = JsError::error_with(cx, err.as_str());
+
+            if let Some(exception) = err.exception() {
+                options.cause_error(exception);
+            }
+
+            options.throw(cx)
+        })
     }
 }
 
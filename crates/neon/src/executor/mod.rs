@@ -0,0 +1,254 @@
+//! Run Rust futures that settle JavaScript promises.
+//!
+//! Async functions, created with [`JsFunction::new_async`](crate::types::JsFunction::new_async)
+//! or exported with `#[neon::export]`, return a [`JsPromise`] that is settled
+//! with the output of a [`Future`]. The future is polled on an [`Executor`]
+//! and the promise is settled on the JavaScript thread through the shared
+//! [`Channel`](crate::event::Channel). Any future may be spawned in the same
+//! way with [`Context::spawn`].
+//!
+//! ```
+//! # use neon::prelude::*;
+//! #[neon::export]
+//! async fn add(a: f64, b: f64) -> f64 {
+//!     a + b
+//! }
+//!
+//! fn add_later(mut cx: FunctionContext) -> JsResult<JsPromise> {
+//!     let a: f64 = cx.argument_as(0)?;
+//!     let b: f64 = cx.argument_as(1)?;
+//!
+//!     cx.spawn(async move { a + b })
+//! }
+//! ```
+//!
+//! ## Choosing an executor
+//!
+//! The executor of a module instance is the first of:
+//!
+//! 1. The executor set for the instance with [`set_instance_executor`]
+//! 2. The executor shared by all instances, set with [`set_global_executor`]
+//! 3. A default tokio runtime, created for the instance the first time a future
+//!    is spawned
+//!
+//! The default runtime is a current-thread runtime, polled on a dedicated thread.
+//! With the `tokio-rt-multi-thread` feature, it is a multi-threaded runtime
+//! instead.
+//!
+//! Executors owned by an instance are [shut down](Executor::shutdown) when the
+//! instance is unloaded, for example, when a worker thread exits. Executors
+//! are owned by an instance with `napi-6` or later; otherwise, the default
+//! tokio runtime is shared by all instances.
+//!
+//! `Executor` may be implemented to use other runtimes, for example, a
+//! `futures::executor::ThreadPool`:
+//!
+//! ```ignore
+//! struct ThreadPool(futures::executor::ThreadPool);
+//!
+//! impl neon::executor::Executor for ThreadPool {
+//!     fn spawn(&self, fut: neon::executor::BoxFuture) {
+//!         self.0.spawn_ok(fut);
+//!     }
+//! }
+//! ```
+
+use std::{
+    any::Any,
+    future::Future,
+    panic::{catch_unwind, AssertUnwindSafe},
+    pin::Pin,
+    sync::Arc,
+    task::{self, Poll},
+};
+
+use once_cell::sync::OnceCell;
+
+use crate::{
+    context::Context,
+    result::JsResult,
+    types::{extract::TryIntoJs, JsPromise, JsValue},
+};
+
+#[cfg(feature = "napi-6")]
+use crate::lifecycle::InstanceData;
+
+mod tokio_rt;
+
+/// A boxed future spawned on an [`Executor`]
+pub type BoxFuture = Pin<Box<dyn Future<Output = ()> + Send + 'static>>;
+
+/// Polls futures to completion, for example, on a thread pool.
+///
+/// `Executor` is implemented for [`tokio::runtime::Runtime`] and
+/// [`tokio::runtime::Handle`].
+pub trait Executor: Send + Sync + 'static {
+    /// Spawns a future. The future must be polled to completion, but its
+    /// output does not need to be awaited.
+    fn spawn(&self, fut: BoxFuture);
+
+    /// Called when the module instance that owns the executor is unloaded.
+    /// Futures that have not completed can no longer settle their promise and
+    /// may be dropped.
+    ///
+    /// The default implementation drops the executor. Executors set with
+    /// [`set_global_executor`] are never shut down.
+    fn shutdown(self: Box<Self>) {}
+}
+
+impl<E: Executor> Executor for Arc<E> {
+    fn spawn(&self, fut: BoxFuture) {
+        E::spawn(self, fut);
+    }
+}
+
+static GLOBAL_EXECUTOR: OnceCell<Box<dyn Executor>> = OnceCell::new();
+
+/// Sets the executor shared by all instances of the module.
+///
+/// The executor may only be set once. If an executor is already set,
+/// `executor` is returned in the `Err`.
+///
+/// ```
+/// # use neon::prelude::*;
+/// # struct MyExecutor;
+/// # impl neon::executor::Executor for MyExecutor {
+/// #     fn spawn(&self, _fut: neon::executor::BoxFuture) {}
+/// # }
+/// #[neon::main]
+/// fn main(mut cx: ModuleContext) -> NeonResult<()> {
+///     if neon::set_global_executor(MyExecutor).is_err() {
+///         return cx.throw_error("executor already set");
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+pub fn set_global_executor<E: Executor>(executor: E) -> Result<(), E> {
+    let mut executor = Some(executor);
+
+    GLOBAL_EXECUTOR.get_or_init(|| Box::new(executor.take().unwrap()));
+
+    match executor {
+        Some(executor) => Err(executor),
+        None => Ok(()),
+    }
+}
+
+#[cfg(feature = "napi-6")]
+#[cfg_attr(docsrs, doc(cfg(feature = "napi-6")))]
+/// Sets the executor for the current instance of the module. The executor
+/// takes precedence over the global executor and is [shut down](Executor::shutdown)
+/// when the instance is unloaded.
+///
+/// If the instance already has an executor, `executor` is returned in the `Err`.
+pub fn set_instance_executor<'cx, C, E>(cx: &mut C, executor: E) -> Result<(), E>
+where
+    C: Context<'cx>,
+    E: Executor,
+{
+    if InstanceData::executor(cx).is_some() {
+        return Err(executor);
+    }
+
+    InstanceData::set_executor(cx, Box::new(executor));
+
+    Ok(())
+}
+
+#[cfg(feature = "napi-6")]
+// Finds the executor for the current instance, creating the default executor
+// if none is set. A new executor is not started after the instance is unloaded.
+fn executor<'a, 'cx, C: Context<'cx>>(cx: &'a mut C) -> Result<&'a dyn Executor, String> {
+    if InstanceData::is_torn_down(cx) {
+        return Err("the module instance has been unloaded".to_string());
+    }
+
+    if InstanceData::executor(cx).is_none() {
+        if let Some(executor) = GLOBAL_EXECUTOR.get() {
+            return Ok(&**executor);
+        }
+
+        InstanceData::set_executor(cx, default_executor()?);
+    }
+
+    Ok(InstanceData::executor(cx).unwrap())
+}
+
+#[cfg(not(feature = "napi-6"))]
+// Without instance data, the default executor is shared by all instances
+fn executor<'a, 'cx, C: Context<'cx>>(_cx: &'a mut C) -> Result<&'a dyn Executor, String> {
+    GLOBAL_EXECUTOR
+        .get_or_try_init(default_executor)
+        .map(|executor| &**executor)
+}
+
+fn default_executor() -> Result<Box<dyn Executor>, String> {
+    tokio_rt::runtime().map_err(|err| format!("failed to start tokio runtime: {}", err))
+}
+
+/// Spawns `fut` on the executor and returns a promise settled with its output.
+///
+/// The output is converted with [`TryIntoJs`]; if the conversion throws, for
+/// example, because the output is an `Err`, the promise is rejected. A panic
+/// in the future rejects the promise with an `Error`.
+pub(crate) fn spawn_promise<'cx, C, F, V>(cx: &mut C, fut: F) -> JsResult<'cx, JsPromise>
+where
+    C: Context<'cx>,
+    F: Future<Output = V> + Send + 'static,
+    V: for<'a> TryIntoJs<'a> + Send + 'static,
+{
+    let channel = cx.channel();
+    let (deferred, promise) = cx.promise();
+
+    let executor = match executor(cx) {
+        Ok(executor) => executor,
+        Err(msg) => {
+            // The promise is never returned, but a `Deferred` must be settled
+            let undefined = cx.undefined();
+
+            deferred.resolve(cx, undefined);
+
+            return cx.throw_error(msg);
+        }
+    };
+
+    executor.spawn(Box::pin(async move {
+        let res = CatchUnwind(Box::pin(fut)).await;
+
+        // If the event loop has stopped, the promise can never be observed
+        let _ = deferred.try_settle_with(&channel, move |mut cx| match res {
+            Ok(v) => v.try_into_js(&mut cx).map(|v| v.upcast::<JsValue>()),
+            Err(panic) => cx.throw_error(panic_message(&*panic)),
+        });
+    }));
+
+    Ok(promise)
+}
+
+// Resolves to `Err` with the panic payload if polling the inner future panics
+struct CatchUnwind<F>(Pin<Box<F>>);
+
+impl<F: Future> Future for CatchUnwind<F> {
+    type Output = Result<F::Output, Box<dyn Any + Send>>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut task::Context) -> Poll<Self::Output> {
+        let fut = self.0.as_mut();
+
+        match catch_unwind(AssertUnwindSafe(|| fut.poll(cx))) {
+            Ok(Poll::Ready(v)) => Poll::Ready(Ok(v)),
+            Ok(Poll::Pending) => Poll::Pending,
+            Err(panic) => Poll::Ready(Err(panic)),
+        }
+    }
+}
+
+fn panic_message(panic: &(dyn Any + Send)) -> String {
+    if let Some(msg) = panic.downcast_ref::<String>() {
+        format!("panic in async function: {}", msg)
+    } else if let Some(msg) = panic.downcast_ref::<&str>() {
+        format!("panic in async function: {}", msg)
+    } else {
+        "panic in async function".to_string()
+    }
+}
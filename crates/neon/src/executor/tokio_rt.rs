@@ -0,0 +1,63 @@
+use tokio::runtime::{Handle, Runtime};
+
+use super::{BoxFuture, Executor};
+
+impl Executor for Runtime {
+    fn spawn(&self, fut: BoxFuture) {
+        Runtime::spawn(self, fut);
+    }
+
+    // Dropping a runtime blocks until its worker threads exit, which could
+    // stall the JavaScript thread while the instance is unloaded
+    fn shutdown(self: Box<Self>) {
+        self.shutdown_background();
+    }
+}
+
+impl Executor for Handle {
+    fn spawn(&self, fut: BoxFuture) {
+        Handle::spawn(self, fut);
+    }
+}
+
+// A current-thread runtime, polled on a dedicated thread until the executor is
+// dropped. Dropping the sender wakes the thread, which then drops the runtime.
+#[cfg(not(feature = "tokio-rt-multi-thread"))]
+struct CurrentThread {
+    handle: Handle,
+    _stop: tokio::sync::oneshot::Sender<()>,
+}
+
+#[cfg(not(feature = "tokio-rt-multi-thread"))]
+impl Executor for CurrentThread {
+    fn spawn(&self, fut: BoxFuture) {
+        self.handle.spawn(fut);
+    }
+}
+
+// Default executor, created the first time an async function is called
+#[cfg(feature = "tokio-rt-multi-thread")]
+pub(super) fn runtime() -> std::io::Result<Box<dyn Executor>> {
+    Ok(Box::new(Runtime::new()?))
+}
+
+// Default executor, created the first time an async function is called
+#[cfg(not(feature = "tokio-rt-multi-thread"))]
+pub(super) fn runtime() -> std::io::Result<Box<dyn Executor>> {
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()?;
+    let handle = runtime.handle().clone();
+    let (stop, stopped) = tokio::sync::oneshot::channel::<()>();
+
+    std::thread::Builder::new()
+        .name("neon-runtime".to_string())
+        .spawn(move || {
+            let _ = runtime.block_on(stopped);
+        })?;
+
+    Ok(Box::new(CurrentThread {
+        handle,
+        _stop: stop,
+    }))
+}
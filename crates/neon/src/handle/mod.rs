@@ -58,7 +58,7 @@ use std::{
     ops::{Deref, DerefMut},
 };
 
-pub use self::root::Root;
+pub use self::root::{Root, WeakRoot};
 
 use crate::{
     context::Context,
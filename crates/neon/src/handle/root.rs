@@ -33,6 +33,14 @@ impl NapiRef {
     pub(crate) unsafe fn unref(self, env: raw::Env) {
         reference::unreference(env, self.0.cast());
     }
+
+    /// Deletes a weak reference, regardless of its reference count
+    ///
+    /// # Safety
+    /// Must only be used from the same module context that created the reference
+    pub(crate) unsafe fn delete(self, env: raw::Env) {
+        reference::delete(env, self.0.cast());
+    }
 }
 
 // # Safety
@@ -48,7 +56,8 @@ unsafe impl Sync for NapiRef {}
 /// prevents it from being garbage collected.
 ///
 /// A `Root<T>` may be sent across threads, but the referenced object may
-/// only be accessed on the JavaScript thread that created it.
+/// only be accessed on the JavaScript thread that created it. See [`WeakRoot`]
+/// for a reference that does not prevent garbage collection.
 pub struct Root<T> {
     // `Option` is used to skip `Drop` when `Root::drop` or `Root::into_inner` is used.
     // It will *always* be `Some` when a user is interacting with `Root`.
@@ -162,6 +171,32 @@ impl<T: Object> Root<T> {
         Handle::new_internal(unsafe { T::from_local(env, local) })
     }
 
+    /// Converts the `Root` to a [`WeakRoot`], allowing the JavaScript object to be
+    /// garbage collected once there are no other references to it.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if it is called from a different JavaScript thread than the
+    /// one in which the handle was created.
+    pub fn into_weak<'a, C: Context<'a>>(self, cx: &mut C) -> WeakRoot<T> {
+        let env = cx.env().to_raw();
+        let internal = self.into_napi_ref(cx);
+
+        // Clones of a `Root` share the same reference. If other clones are still
+        // alive, a new weak reference is created instead of reusing it.
+        let internal = unsafe {
+            if reference::release(env, internal.0.cast()) == 0 {
+                internal
+            } else {
+                let local = reference::get(env, internal.0.cast());
+
+                NapiRef(reference::new_weak(env, local) as *mut _)
+            }
+        };
+
+        WeakRoot::from_napi_ref(cx, internal)
+    }
+
     /// Access the inner JavaScript object without consuming the `Root`
     /// This method aliases the reference without changing the reference count. It
     /// can be used in place of a clone immediately followed by a call to `into_inner`.
@@ -197,6 +232,199 @@ impl<T: Object> Root<T> {
     }
 }
 
+/// A thread-safe handle that holds a weak reference to a JavaScript object.
+///
+/// Unlike a [`Root`], a `WeakRoot<T>` does not prevent the object from being
+/// garbage collected, which makes it suitable for caches and lists of observers.
+/// A `WeakRoot<T>` may be sent across threads, but it may only be upgraded on the
+/// JavaScript thread that created it.
+///
+/// ```
+/// # use neon::prelude::*;
+/// use neon::handle::WeakRoot;
+///
+/// struct Cache(WeakRoot<JsObject>);
+///
+/// impl Finalize for Cache {
+///     fn finalize<'a, C: Context<'a>>(self, cx: &mut C) {
+///         self.0.finalize(cx);
+///     }
+/// }
+///
+/// fn create_cache(mut cx: FunctionContext) -> JsResult<JsBox<Cache>> {
+///     let obj = cx.argument::<JsObject>(0)?;
+///     let weak = WeakRoot::new(&mut cx, &*obj);
+///
+///     Ok(cx.boxed(Cache(weak)))
+/// }
+///
+/// // Returns the object, or `undefined` if it was garbage collected
+/// fn get_cached(mut cx: FunctionContext) -> JsResult<JsValue> {
+///     let cache = cx.argument::<JsBox<Cache>>(0)?;
+///
+///     match cache.0.upgrade(&mut cx) {
+///         Some(obj) => Ok(obj.upcast()),
+///         None => Ok(cx.undefined().upcast()),
+///     }
+/// }
+/// ```
+pub struct WeakRoot<T> {
+    // `Option` is used to skip `Drop` when `WeakRoot::drop` or `WeakRoot::into_root`
+    // is used. It will *always* be `Some` when a user is interacting with `WeakRoot`.
+    internal: Option<NapiRef>,
+    instance_id: InstanceId,
+    #[cfg(feature = "napi-6")]
+    drop_queue: Arc<ThreadsafeFunction<DropData>>,
+    _phantom: PhantomData<T>,
+}
+
+impl<T> std::fmt::Debug for WeakRoot<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "WeakRoot<{}>", std::any::type_name::<T>())
+    }
+}
+
+// Safety: Identical to `Root`; `WeakRoot` only adds a different reference count.
+unsafe impl<T> Send for WeakRoot<T> {}
+
+unsafe impl<T> Sync for WeakRoot<T> {}
+
+impl<T: Object> WeakRoot<T> {
+    /// Create a weak reference to a JavaScript object. A `WeakRoot<T>` may only
+    /// be dropped on the JavaScript thread that created it.
+    ///
+    /// As with [`Root`], the caller _should_ ensure `WeakRoot::drop` or
+    /// `WeakRoot::into_root` is called to properly dispose of the `WeakRoot<T>`.
+    pub fn new<'a, C: Context<'a>>(cx: &mut C, value: &T) -> Self {
+        let env = cx.env().to_raw();
+        let internal = unsafe { reference::new_weak(env, value.to_local()) };
+
+        Self::from_napi_ref(cx, NapiRef(internal as *mut _))
+    }
+
+    fn from_napi_ref<'a, C: Context<'a>>(cx: &mut C, internal: NapiRef) -> Self {
+        Self {
+            internal: Some(internal),
+            instance_id: instance_id(cx),
+            #[cfg(feature = "napi-6")]
+            drop_queue: InstanceData::drop_queue(cx),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Return the referenced JavaScript object, or `None` if it has been
+    /// garbage collected.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if it is called from a different JavaScript thread than the
+    /// one in which the handle was created.
+    pub fn upgrade<'a, C: Context<'a>>(&self, cx: &mut C) -> Option<Handle<'a, T>> {
+        let env = cx.env();
+        let local = unsafe { reference::get(env.to_raw(), self.as_napi_ref(cx).0 as *mut _) };
+
+        if local.is_null() {
+            return None;
+        }
+
+        Some(Handle::new_internal(unsafe { T::from_local(env, local) }))
+    }
+
+    /// Converts the `WeakRoot` to a [`Root`] that prevents the JavaScript object
+    /// from being garbage collected. Returns `None` if the object has already
+    /// been garbage collected.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if it is called from a different JavaScript thread than the
+    /// one in which the handle was created.
+    pub fn into_root<'a, C: Context<'a>>(self, cx: &mut C) -> Option<Root<T>> {
+        let env = cx.env().to_raw();
+        let internal = self.into_napi_ref(cx);
+
+        unsafe {
+            if reference::get(env, internal.0.cast()).is_null() {
+                internal.delete(env);
+                return None;
+            }
+
+            reference::reference(env, internal.0.cast());
+        }
+
+        Some(Root {
+            internal: Some(internal),
+            instance_id: instance_id(cx),
+            #[cfg(feature = "napi-6")]
+            drop_queue: InstanceData::drop_queue(cx),
+            _phantom: PhantomData,
+        })
+    }
+
+    /// Safely drop a `WeakRoot<T>`.
+    pub fn drop<'a, C: Context<'a>>(self, cx: &mut C) {
+        let env = cx.env().to_raw();
+
+        unsafe {
+            self.into_napi_ref(cx).delete(env);
+        }
+    }
+
+    fn as_napi_ref<'a, C: Context<'a>>(&self, cx: &mut C) -> &NapiRef {
+        if self.instance_id != instance_id(cx) {
+            panic!("Attempted to dereference a `neon::handle::WeakRoot` from the wrong module ");
+        }
+
+        self.internal
+            .as_ref()
+            // `unwrap` will not `panic` because `internal` will always be `Some`
+            // until the `WeakRoot` is consumed.
+            .unwrap()
+    }
+
+    fn into_napi_ref<'a, C: Context<'a>>(mut self, cx: &mut C) -> NapiRef {
+        let reference = self.as_napi_ref(cx).clone();
+        // This uses `as_napi_ref` instead of `Option::take` for the instance id safety check
+        self.internal = None;
+        reference
+    }
+}
+
+impl<T: Object> Finalize for WeakRoot<T> {
+    fn finalize<'a, C: Context<'a>>(self, cx: &mut C) {
+        self.drop(cx);
+    }
+}
+
+impl<T> Drop for WeakRoot<T> {
+    #[cfg(not(feature = "napi-6"))]
+    fn drop(&mut self) {
+        // If `None`, the `NapiRef` has already been manually dropped
+        if self.internal.is_none() {
+            return;
+        }
+
+        // Destructors are called during stack unwinding, prevent a double
+        // panic and instead prefer to leak.
+        if std::thread::panicking() {
+            eprintln!("Warning: neon::handle::WeakRoot leaked during a panic");
+            return;
+        }
+
+        // Only panic if the event loop is still running
+        if let Ok(true) = crate::context::internal::IS_RUNNING.try_with(|v| *v.borrow()) {
+            panic!("Must call `into_root` or `drop` on `neon::handle::WeakRoot`");
+        }
+    }
+
+    #[cfg(feature = "napi-6")]
+    fn drop(&mut self) {
+        // If `None`, the `NapiRef` has already been manually dropped
+        if let Some(internal) = self.internal.take() {
+            let _ = self.drop_queue.call(DropData::WeakRef(internal), None);
+        }
+    }
+}
+
 // Allows putting `Root<T>` directly in a container that implements `Finalize`
 // For example, `Vec<Root<T>>` or `JsBox`.
 impl<T: Object> Finalize for Root<T> {
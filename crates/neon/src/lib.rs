@@ -73,6 +73,19 @@
 //! returns a JavaScript string. Because all Neon functions can potentially throw a
 //! JavaScript exception, the return type is wrapped in a [`JsResult`](result::JsResult).
 //!
+//! ## Splitting bindings across crates
+//!
+//! Functions exported with [`#[neon::export]`](export), classes defined with
+//! [`#[neon::class]`](class) and hooks registered with [`#[neon::init]`](init)
+//! are collected from every crate linked into the module and registered when
+//! it is loaded. A library crate may contribute bindings without a `main`;
+//! the module only needs to link it, for example, with `use my_bindings as _;`.
+//! A [`#[neon::main]`](main) function is optional and is called last.
+//!
+//! The `napi_register_module_v1` symbol that Node calls to load the module is
+//! always exported by `neon`, unless the `no-module-entry` feature is enabled,
+//! e.g., in an addon that defines its own entry point.
+//!
 //! [neon]: https://www.neon-bindings.com/
 //! [addons]: https://nodejs.org/api/addons.html
 //! [supported]: https://github.com/neon-bindings/neon#platform-support
@@ -80,16 +93,24 @@
 
 pub mod context;
 pub mod event;
+#[cfg(all(feature = "napi-5", feature = "futures"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "napi-5", feature = "futures"))))]
+pub mod executor;
 pub mod handle;
 pub mod meta;
 pub mod object;
+pub mod panic;
 pub mod prelude;
 pub mod reflect;
 pub mod result;
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub mod serde;
 #[cfg(not(feature = "sys"))]
 mod sys;
 #[cfg(feature = "napi-6")]
 pub mod thread;
+pub mod typescript;
 // To use the #[aquamarine] attribute on the top-level neon::types module docs, we have to
 // use this hack so we can keep the module docs in a separate file.
 // See: https://github.com/mersinvald/aquamarine/issues/5#issuecomment-1168816499
@@ -107,6 +128,10 @@ pub mod macro_internal;
 
 pub use neon_macros::*;
 
+#[cfg(all(feature = "napi-5", feature = "futures"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "napi-5", feature = "futures"))))]
+pub use executor::set_global_executor;
+
 #[cfg(feature = "napi-6")]
 mod lifecycle;
 
@@ -141,7 +166,7 @@ fn feature_matrix() {
     const EXTERNAL_BUFFERS: &str = "external-buffers";
     const FUTURES: &str = "futures";
     const NODE_API_VERSIONS: &[&str] = &[
-        "napi-1", "napi-2", "napi-3", "napi-4", "napi-5", "napi-6", "napi-7", "napi-8",
+        "napi-1", "napi-2", "napi-3", "napi-4", "napi-5", "napi-6", "napi-7", "napi-8", "napi-9",
     ];
 
     // If the number of features in Neon grows, we can use `itertools` to generate permutations.
@@ -25,6 +25,9 @@ use crate::{
     types::promise::NodeApiDeferred,
 };
 
+#[cfg(feature = "futures")]
+use crate::executor::Executor;
+
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 #[repr(transparent)]
 /// Uniquely identifies an instance of the module
@@ -58,13 +61,24 @@ pub(crate) struct InstanceData {
     /// could be replaced with a leaked `&'static ThreadsafeFunction<NapiRef>`. However,
     /// given the cost of FFI, this optimization is omitted until the cost of an
     /// `Arc` is demonstrated as significant.
+    ///
+    /// _Note_: Closed after the environment has been torn down
     drop_queue: Arc<ThreadsafeFunction<DropData>>,
 
     /// Shared `Channel` that is cloned to be returned by the `cx.channel()` method
+    ///
+    /// _Note_: Closed after the environment has been torn down
     shared_channel: Channel,
 
+    /// Set by `teardown`. Resources released by `teardown` are not created again.
+    torn_down: bool,
+
     /// Table of user-defined instance-local cells.
     locals: LocalTable,
+
+    #[cfg(feature = "futures")]
+    /// Executor owned by this instance, shut down when the environment is torn down
+    executor: Option<Box<dyn Executor>>,
 }
 
 #[derive(Default)]
@@ -213,6 +227,7 @@ impl<'cx, 'a, C: Context<'cx>> Drop for TryInitTransaction<'cx, 'a, C> {
 pub(crate) enum DropData {
     Deferred(NodeApiDeferred),
     Ref(NapiRef),
+    WeakRef(NapiRef),
 }
 
 impl DropData {
@@ -223,6 +238,7 @@ impl DropData {
                 match data {
                     DropData::Deferred(data) => data.leaked(env),
                     DropData::Ref(data) => data.unref(env),
+                    DropData::WeakRef(data) => data.delete(env),
                 }
             }
         }
@@ -244,26 +260,23 @@ impl InstanceData {
             return data;
         }
 
-        let drop_queue = unsafe {
-            let queue = ThreadsafeFunction::new(env, DropData::drop);
-            queue.unref(env);
-            queue
-        };
-
-        let shared_channel = {
-            let mut channel = Channel::new(cx);
-            channel.unref(cx);
-            channel
-        };
-
         let data = InstanceData {
             id: InstanceId::next(),
-            drop_queue: Arc::new(drop_queue),
-            shared_channel,
+            drop_queue: Arc::new(new_drop_queue(env)),
+            shared_channel: new_shared_channel(cx),
+            torn_down: false,
             locals: LocalTable::default(),
+            #[cfg(feature = "futures")]
+            executor: None,
         };
 
-        unsafe { &mut *lifecycle::set_instance_data(env, data) }
+        let data = unsafe { &mut *lifecycle::set_instance_data(env, data) };
+
+        // Cleanup hooks run in the reverse order they were added. Since `InstanceData`
+        // is initialized before any hooks are added by the user, `teardown` runs last.
+        unsafe { lifecycle::add_cleanup_hook(env, move || teardown(env)) };
+
+        data
     }
 
     /// Helper to return a reference to the `drop_queue` field of `InstanceData`
@@ -277,6 +290,7 @@ impl InstanceData {
     /// referenced, but the shared channel is unreferenced.
     pub(crate) fn channel<'cx, C: Context<'cx>>(cx: &mut C) -> Channel {
         let mut channel = InstanceData::get(cx).shared_channel.clone();
+
         channel.reference(cx);
         channel
     }
@@ -290,4 +304,116 @@ impl InstanceData {
     pub(crate) fn locals<'cx, C: Context<'cx>>(cx: &mut C) -> &mut LocalTable {
         &mut InstanceData::get(cx).locals
     }
+
+    #[cfg(feature = "futures")]
+    /// Executor owned by this instance, if one has been set
+    pub(crate) fn executor<'cx, C: Context<'cx>>(cx: &mut C) -> Option<&dyn Executor> {
+        InstanceData::get(cx).executor.as_deref()
+    }
+
+    #[cfg(feature = "futures")]
+    /// Sets the executor owned by this instance. The executor is shut down when
+    /// the instance is unloaded, or immediately if it has already been unloaded.
+    ///
+    /// _Note_: The executor must not already be set.
+    pub(crate) fn set_executor<'cx, C: Context<'cx>>(cx: &mut C, executor: Box<dyn Executor>) {
+        let data = InstanceData::get(cx);
+
+        if data.torn_down {
+            executor.shutdown();
+            return;
+        }
+
+        debug_assert!(data.executor.is_none());
+        data.executor = Some(executor);
+    }
+
+    #[cfg(feature = "futures")]
+    /// Checks if the environment has been torn down, e.g., when called from a
+    /// finalizer while the instance is unloaded
+    pub(crate) fn is_torn_down<'cx, C: Context<'cx>>(cx: &mut C) -> bool {
+        InstanceData::get(cx).torn_down
+    }
+
+    /// Registers a function to be called when the environment is torn down, before
+    /// Neon releases the resources of this instance.
+    pub(crate) fn on_cleanup<'cx, C, F>(cx: &mut C, f: F)
+    where
+        C: Context<'cx>,
+        F: FnOnce() + 'static,
+    {
+        let env = cx.env().to_raw();
+
+        // Ensure `teardown` is registered first
+        InstanceData::get(cx);
+
+        unsafe { lifecycle::add_cleanup_hook(env, f) };
+    }
+
+    #[cfg(feature = "napi-8")]
+    /// Registers a function to be called on the libuv thread pool when the environment
+    /// is torn down. Teardown waits for the function to complete.
+    pub(crate) fn on_cleanup_async<'cx, C, F>(cx: &mut C, f: F)
+    where
+        C: Context<'cx>,
+        F: FnOnce() + Send + 'static,
+    {
+        let env = cx.env().to_raw();
+
+        // Ensure `teardown` is registered first
+        InstanceData::get(cx);
+
+        unsafe { lifecycle::add_async_cleanup_hook(env, f) };
+    }
+}
+
+fn new_drop_queue(env: Env) -> ThreadsafeFunction<DropData> {
+    unsafe {
+        let queue = ThreadsafeFunction::new(env, DropData::drop);
+        queue.unref(env);
+        queue
+    }
+}
+
+fn new_shared_channel<'cx, C: Context<'cx>>(cx: &mut C) -> Channel {
+    let mut channel = Channel::new(cx);
+    channel.unref(cx);
+    channel
+}
+
+/// Releases the resources of an instance when the environment is torn down. Each
+/// resource may still be used by the ones released before it:
+///
+/// 1. The executor, which may own futures holding any of the resources below
+/// 2. Instance-local cells, which may hold a `Root` or a `Channel`
+/// 3. The shared `Channel`
+/// 4. The drop queue, which may have been used to drop any of the above
+///
+/// The instance data itself is freed by Node-API after all cleanup hooks have run.
+fn teardown(env: Env) {
+    let data = unsafe { lifecycle::get_instance_data::<InstanceData>(env).as_mut() };
+    let data = match data {
+        Some(data) => data,
+        None => return,
+    };
+
+    data.torn_down = true;
+
+    #[cfg(feature = "futures")]
+    if let Some(executor) = data.executor.take() {
+        executor.shutdown();
+    }
+
+    drop(std::mem::take(&mut data.locals));
+
+    // Replaced with closed versions since finalizers may still run, e.g., to drop a
+    // `Root` while the environment is torn down
+    drop(std::mem::replace(
+        &mut data.shared_channel,
+        Channel::closed(),
+    ));
+    drop(std::mem::replace(
+        &mut data.drop_queue,
+        Arc::new(ThreadsafeFunction::closed(DropData::drop)),
+    ));
 }
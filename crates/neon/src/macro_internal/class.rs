@@ -0,0 +1,192 @@
+//! Runtime support for `#[neon::class]`
+
+use std::{
+    any::Any,
+    cell::{Ref, RefCell, RefMut},
+};
+
+use crate::{
+    context::{internal::ContextInternal, CallKind, Context, FunctionContext, ModuleContext},
+    handle::Handle,
+    result::{JsResult, NeonResult, Throw},
+    sys::{
+        self,
+        class::{Property, PropertyValue},
+        wrap::WrapError,
+    },
+    types::{function, private::ValueInternal, JsFunction, JsValue},
+};
+
+/// Native implementation of a constructor, method or accessor
+pub type Method = for<'cx> fn(FunctionContext<'cx>) -> JsResult<'cx, JsValue>;
+
+// Wrapped by instances of a class. The wrapper is private to ensure the data
+// can only be accessed through the class.
+struct Instance<T>(RefCell<T>);
+
+/// Builds a JavaScript class from native callbacks
+pub struct ClassBuilder {
+    name: &'static str,
+    constructor: Method,
+    properties: Vec<Property>,
+}
+
+impl ClassBuilder {
+    pub fn new(name: &'static str, constructor: Method) -> Self {
+        Self {
+            name,
+            constructor,
+            properties: Vec::new(),
+        }
+    }
+
+    /// Defines a method on the prototype
+    pub fn method(mut self, name: &'static str, method: Method) -> Self {
+        self.properties.push(Property {
+            name: name.to_string(),
+            value: PropertyValue::Method(callback(method)),
+            attributes: sys::PropertyAttributes::WRITABLE | sys::PropertyAttributes::CONFIGURABLE,
+        });
+
+        self
+    }
+
+    /// Defines an accessor property on the prototype
+    pub fn accessor(
+        mut self,
+        name: &'static str,
+        getter: Option<Method>,
+        setter: Option<Method>,
+    ) -> Self {
+        self.properties.push(Property {
+            name: name.to_string(),
+            value: PropertyValue::Accessor {
+                getter: getter.map(callback),
+                setter: setter.map(callback),
+            },
+            attributes: sys::PropertyAttributes::CONFIGURABLE,
+        });
+
+        self
+    }
+
+    /// Defines a method on the constructor
+    pub fn static_method(mut self, name: &'static str, method: Method) -> Self {
+        self.properties.push(Property {
+            name: name.to_string(),
+            value: PropertyValue::Method(callback(method)),
+            attributes: sys::PropertyAttributes::WRITABLE
+                | sys::PropertyAttributes::CONFIGURABLE
+                | sys::PropertyAttributes::STATIC,
+        });
+
+        self
+    }
+
+    pub fn build<'cx>(self, cx: &mut ModuleContext<'cx>) -> JsResult<'cx, JsFunction> {
+        let name = self.name;
+        let constructor = self.constructor;
+        let constructor = Box::new(function::callback(move |mut cx: FunctionContext| {
+            if let CallKind::Call = cx.kind() {
+                return cx.throw_type_error(format!(
+                    "Class constructor {} cannot be invoked without 'new'",
+                    name
+                ));
+            }
+
+            constructor(cx)
+        }));
+
+        unsafe {
+            match sys::class::define(cx.env().to_raw(), name, constructor, self.properties) {
+                Ok(raw) => Ok(Handle::new_internal(JsFunction::from_local(cx.env(), raw))),
+                Err(_) => Err(Throw::new()),
+            }
+        }
+    }
+}
+
+fn callback(method: Method) -> sys::class::Callback {
+    Box::new(function::callback(method))
+}
+
+/// Value returned from a class constructor
+pub trait IntoInstance<T> {
+    fn into_instance(self) -> NeonResult<T>;
+}
+
+impl<T> IntoInstance<T> for T {
+    fn into_instance(self) -> NeonResult<T> {
+        Ok(self)
+    }
+}
+
+impl<T> IntoInstance<T> for Result<T, Throw> {
+    fn into_instance(self) -> NeonResult<T> {
+        self
+    }
+}
+
+/// Wraps `this` with the value returned from a class constructor
+pub fn construct<'cx, T, R>(cx: &mut FunctionContext<'cx>, res: R) -> JsResult<'cx, JsValue>
+where
+    T: 'static,
+    R: IntoInstance<T>,
+{
+    let value = res.into_instance()?;
+    let this = cx.this_value();
+    let instance: Box<dyn Any> = Box::new(Instance(RefCell::new(value)));
+    let res =
+        unsafe { sys::wrap::wrap(cx.env().to_raw(), this.to_local(), instance, |_, v| drop(v)) };
+
+    match res {
+        Ok(()) => Ok(this),
+        Err(WrapError::Status(sys::Status::PendingException)) => Err(unsafe { Throw::new() }),
+        Err(WrapError::Status(_)) => {
+            cx.throw_type_error("Class instance has already been initialized")
+        }
+        #[cfg(feature = "napi-8")]
+        Err(WrapError::Tagged) => cx.throw_type_error("Object already has a type tag"),
+    }
+}
+
+/// Unwraps the value of `this`, throwing a `TypeError` if it is not an
+/// instance of the class.
+pub fn this<'cx, T: 'static>(cx: &mut FunctionContext<'cx>) -> NeonResult<&'cx RefCell<T>> {
+    let this = cx.this_value();
+
+    // # Safety
+    // The instance is kept alive by the `this` handle and is not dropped until
+    // the object is garbage collected.
+    let instance = unsafe {
+        sys::wrap::unwrap::<Box<dyn Any>>(cx.env().to_raw(), this.to_local())
+            .and_then(|v| (*v).downcast_ref::<Instance<T>>())
+    };
+
+    match instance {
+        Some(Instance(v)) => Ok(v),
+        None => cx.throw_type_error("Illegal invocation"),
+    }
+}
+
+/// Borrows the value of `this`, throwing if it is mutably borrowed
+pub fn borrow<'a, 'cx, T>(
+    cx: &mut FunctionContext<'cx>,
+    v: &'a RefCell<T>,
+) -> NeonResult<Ref<'a, T>> {
+    match v.try_borrow() {
+        Ok(v) => Ok(v),
+        Err(_) => cx.throw_error("Class instance is already mutably borrowed"),
+    }
+}
+
+/// Mutably borrows the value of `this`, throwing if it is already borrowed
+pub fn borrow_mut<'a, 'cx, T>(
+    cx: &mut FunctionContext<'cx>,
+    v: &'a RefCell<T>,
+) -> NeonResult<RefMut<'a, T>> {
+    match v.try_borrow_mut() {
+        Ok(v) => Ok(v),
+        Err(_) => cx.throw_error("Class instance is already borrowed"),
+    }
+}
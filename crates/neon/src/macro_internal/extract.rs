@@ -0,0 +1,76 @@
+//! Helpers for `#[derive(TryFromJs, TryIntoJs)]`
+
+use crate::{
+    context::Context,
+    handle::Handle,
+    object::Object,
+    result::NeonResult,
+    types::{
+        extract::{ConversionError, TryFromJs, TryIntoJs},
+        JsObject, JsUndefined, JsValue,
+    },
+};
+
+/// Downcasts the value of a struct or tagged enum
+pub fn object<'cx, C>(
+    cx: &mut C,
+    v: Handle<'cx, JsValue>,
+) -> Result<Handle<'cx, JsObject>, ConversionError>
+where
+    C: Context<'cx>,
+{
+    v.downcast(cx).map_err(|_| ConversionError::new("object"))
+}
+
+/// Converts the property `key` of an object, recording the key in the path
+/// of any error
+pub fn field<'cx, T, C>(
+    cx: &mut C,
+    obj: Handle<'cx, JsObject>,
+    key: &'static str,
+) -> NeonResult<Result<T, ConversionError>>
+where
+    T: TryFromJs<'cx>,
+    C: Context<'cx>,
+{
+    let v = obj.get_value(cx, key)?;
+
+    Ok(T::try_from_js(cx, v)?.map_err(|err| err.field(key)))
+}
+
+/// Converts the property `key` of an object, calling `default` if the property
+/// is `undefined`
+pub fn field_or_else<'cx, T, C, F>(
+    cx: &mut C,
+    obj: Handle<'cx, JsObject>,
+    key: &'static str,
+    default: F,
+) -> NeonResult<Result<T, ConversionError>>
+where
+    T: TryFromJs<'cx>,
+    C: Context<'cx>,
+    F: FnOnce() -> T,
+{
+    let v = obj.get_value(cx, key)?;
+
+    if v.is_a::<JsUndefined, _>(cx) {
+        return Ok(Ok(default()));
+    }
+
+    Ok(T::try_from_js(cx, v)?.map_err(|err| err.field(key)))
+}
+
+/// Converts a value and sets it as the property `key` of an object
+pub fn set_field<'cx, T, C>(
+    cx: &mut C,
+    obj: Handle<'cx, JsObject>,
+    key: &'static str,
+    v: T,
+) -> NeonResult<()>
+where
+    T: TryIntoJs<'cx>,
+    C: Context<'cx>,
+{
+    obj.set_as(cx, key, v)?;
+    Ok(())
+}
@@ -1,3 +1,68 @@
 //! Internals needed by macros. These have to be exported for the macros to work
 
+pub use linkme;
+
+pub mod class;
+pub mod extract;
+pub mod typescript;
+
 pub use crate::context::internal::initialize_module;
+use crate::{
+    context::{FunctionContext, ModuleContext},
+    result::{JsResult, NeonResult},
+    types::{
+        extract::{TryFromJs, TryIntoJs},
+        JsValue,
+    },
+};
+
+/// Registers a value on the module exports object. Called for each item in
+/// [`EXPORTS`] when the module is initialized.
+pub type Export = for<'cx> fn(&mut ModuleContext<'cx>) -> NeonResult<()>;
+
+#[linkme::distributed_slice]
+/// Exports registered with `#[neon::export]` and `#[neon::class]`
+pub static EXPORTS: [Export] = [..];
+
+/// Initializes a module after its exports are registered. Called for each
+/// item in [`INIT`] before the `#[neon::main]` function.
+pub type Init = for<'cx> fn(&mut ModuleContext<'cx>) -> NeonResult<()>;
+
+#[linkme::distributed_slice]
+/// Init hooks registered with `#[neon::init]`
+pub static INIT: [Init] = [..];
+
+/// The entry point of a module marked with `#[neon::main]`
+pub type Main = for<'cx> fn(ModuleContext<'cx>) -> NeonResult<()>;
+
+#[linkme::distributed_slice]
+/// The function marked with `#[neon::main]`. There may be at most one.
+pub static MAIN: [Main] = [..];
+
+/// Extracts argument `i`, throwing a `TypeError` naming the argument if it
+/// is not of the expected type. Missing arguments are treated as `undefined`.
+pub fn argument<'cx, T>(cx: &mut FunctionContext<'cx>, i: usize) -> NeonResult<T>
+where
+    T: TryFromJs<'cx>,
+{
+    cx.argument_as(i)
+}
+
+/// Converts the return value of an exported function
+pub fn into_js<'cx, T>(cx: &mut FunctionContext<'cx>, v: T) -> JsResult<'cx, JsValue>
+where
+    T: TryIntoJs<'cx>,
+{
+    v.try_into_js(cx).map(|v| v.upcast())
+}
+
+#[cfg(all(feature = "napi-5", feature = "futures"))]
+/// Spawns the future returned by an `async` export, returning a promise
+/// settled with its output
+pub fn spawn<'cx, F, V>(cx: &mut FunctionContext<'cx>, fut: F) -> JsResult<'cx, JsValue>
+where
+    F: std::future::Future<Output = V> + Send + 'static,
+    V: for<'a> TryIntoJs<'a> + Send + 'static,
+{
+    crate::executor::spawn_promise(cx, fut).map(|v| v.upcast())
+}
@@ -0,0 +1,242 @@
+//! Rendering of TypeScript declarations recorded by `#[neon::export]` and
+//! `#[neon::class]`, and of the types of conversions
+
+/// Renders the declaration of an export. Called for each item in
+/// [`DECLARATIONS`] by [`crate::typescript::generate`].
+pub type Declaration = fn() -> String;
+
+#[linkme::distributed_slice]
+/// Declarations of exports registered with `#[neon::export]` and `#[neon::class]`
+pub static DECLARATIONS: [Declaration] = [..];
+
+/// `T[]`
+pub fn array(ty: String) -> String {
+    if is_compound(&ty) {
+        format!("({})[]", ty)
+    } else {
+        format!("{}[]", ty)
+    }
+}
+
+/// `T | undefined`
+pub fn optional(ty: String) -> String {
+    union(vec![ty, "undefined".to_string()])
+}
+
+/// `Record<string, T>`
+pub fn record(ty: String) -> String {
+    format!("Record<string, {}>", ty)
+}
+
+/// `Set<T>`
+pub fn set(ty: String) -> String {
+    format!("Set<{}>", ty)
+}
+
+/// `[A, B, ...]`
+pub fn tuple(tys: Vec<String>) -> String {
+    format!("[{}]", tys.join(", "))
+}
+
+/// `A | B | ...`, without duplicates
+pub fn union(tys: Vec<String>) -> String {
+    let mut members: Vec<String> = Vec::new();
+
+    for ty in tys {
+        for member in split_union(&ty) {
+            if !members.contains(&member) {
+                members.push(member);
+            }
+        }
+    }
+
+    members.join(" | ")
+}
+
+/// `Promise<T>`, with `undefined` rendered as `void`
+pub fn promise(ty: String) -> String {
+    format!("Promise<{}>", returns(ty))
+}
+
+/// A string literal type
+pub fn string(s: &str) -> String {
+    let mut lit = String::with_capacity(s.len() + 2);
+
+    lit.push('"');
+
+    for c in s.chars() {
+        match c {
+            '"' => lit.push_str("\\\""),
+            '\\' => lit.push_str("\\\\"),
+            '\n' => lit.push_str("\\n"),
+            c => lit.push(c),
+        }
+    }
+
+    lit.push('"');
+    lit
+}
+
+/// An object type from `(name, optional, type)` properties
+pub fn object(props: Vec<(&str, bool, String)>) -> String {
+    if props.is_empty() {
+        return "{}".to_string();
+    }
+
+    let props = props
+        .into_iter()
+        .map(|(name, optional, ty)| {
+            let optional = if optional { "?" } else { "" };
+
+            format!("{}{}: {}", property(name), optional, ty)
+        })
+        .collect::<Vec<_>>();
+
+    format!("{{ {} }}", props.join("; "))
+}
+
+/// `export function name(params): ret;`
+pub fn function(name: &str, params: Vec<(&str, String)>, ret: String) -> String {
+    format!(
+        "export function {}({}): {};",
+        name,
+        parameters(params),
+        returns(ret)
+    )
+}
+
+/// `export class Name { members }`
+pub fn class(name: &str, members: Vec<String>) -> String {
+    let mut decl = format!("export class {} {{\n", name);
+
+    for member in members {
+        decl.push_str("  ");
+        decl.push_str(&member);
+        decl.push('\n');
+    }
+
+    decl.push('}');
+    decl
+}
+
+/// `constructor(params);`
+pub fn constructor(params: Vec<(&str, String)>) -> String {
+    format!("constructor({});", parameters(params))
+}
+
+/// `name(params): ret;` or `static name(params): ret;`
+pub fn method(name: &str, is_static: bool, params: Vec<(&str, String)>, ret: String) -> String {
+    format!(
+        "{}{}({}): {};",
+        if is_static { "static " } else { "" },
+        property(name),
+        parameters(params),
+        returns(ret)
+    )
+}
+
+/// `get name(): ty;`
+pub fn getter(name: &str, ty: String) -> String {
+    format!("get {}(): {};", property(name), ty)
+}
+
+/// `set name(value: ty);`
+pub fn setter(name: &str, ty: String) -> String {
+    format!("set {}(value: {});", property(name), ty)
+}
+
+// Trailing parameters that accept `undefined` are optional
+fn parameters(params: Vec<(&str, String)>) -> String {
+    let required = params
+        .iter()
+        .rposition(|(_, ty)| !split_union(ty).iter().any(|m| m == "undefined"))
+        .map(|i| i + 1)
+        .unwrap_or(0);
+
+    params
+        .into_iter()
+        .enumerate()
+        .map(|(i, (name, ty))| {
+            if i < required {
+                return format!("{}: {}", name, ty);
+            }
+
+            let members = split_union(&ty)
+                .into_iter()
+                .filter(|m| m != "undefined")
+                .collect::<Vec<_>>();
+
+            if members.is_empty() {
+                format!("{}?: undefined", name)
+            } else {
+                format!("{}?: {}", name, members.join(" | "))
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn returns(ty: String) -> String {
+    if ty == "undefined" {
+        "void".to_string()
+    } else {
+        ty
+    }
+}
+
+// Quotes property names that are not identifiers
+fn property(name: &str) -> String {
+    let mut chars = name.chars();
+    let is_ident = chars
+        .next()
+        .map(|c| c.is_ascii_alphabetic() || c == '_' || c == '$')
+        .unwrap_or(false)
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$');
+
+    if is_ident {
+        name.to_string()
+    } else {
+        string(name)
+    }
+}
+
+// Splits a type on the top-level `|`, outside of brackets and strings
+fn split_union(ty: &str) -> Vec<String> {
+    let mut members = Vec::new();
+    let mut depth = 0usize;
+    let mut in_string = false;
+    let mut escaped = false;
+    let mut start = 0;
+
+    for (i, c) in ty.char_indices() {
+        if in_string {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => in_string = false,
+                _ => {}
+            }
+
+            continue;
+        }
+
+        match c {
+            '"' => in_string = true,
+            '(' | '[' | '{' | '<' => depth += 1,
+            ')' | ']' | '}' | '>' => depth = depth.saturating_sub(1),
+            '|' if depth == 0 => {
+                members.push(ty[start..i].trim().to_string());
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+
+    members.push(ty[start..].trim().to_string());
+    members
+}
+
+// Types that must be parenthesized in an array
+fn is_compound(ty: &str) -> bool {
+    split_union(ty).len() > 1 || ty.contains("=>")
+}
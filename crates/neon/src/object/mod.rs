@@ -8,11 +8,11 @@
 //! ## Property Keys
 //!
 //! Object properties are accessed by a _property key_, which in JavaScript
-//! can be a string or [symbol][symbol]. (Neon does not yet have support for
-//! symbols.) For convenience, the [`PropertyKey`](PropertyKey) trait allows
-//! Neon programs to use various Rust string types, as well as numeric types,
-//! as keys when accessing object properties, converting the keys to strings
-//! as necessary:
+//! can be a string or [symbol][symbol]. A handle to a
+//! [`JsSymbol`](crate::types::JsSymbol) may be used as a symbol key. For
+//! convenience, the [`PropertyKey`](PropertyKey) trait allows Neon programs to
+//! use various Rust string types, as well as numeric types, as keys when
+//! accessing object properties, converting the keys to strings as necessary:
 //!
 //! ```
 //! # use neon::prelude::*;
@@ -32,17 +32,34 @@
 //! [hierarchy]: crate::types#the-javascript-type-hierarchy
 //! [symbol]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Symbol
 
+use std::any::Any;
+
 use crate::{
-    context::Context,
+    context::{Context, FinalizeContext},
     handle::{Handle, Root},
     result::{NeonResult, Throw},
-    sys::{self, raw},
-    types::{build, function::CallOptions, utf8::Utf8, JsFunction, JsUndefined, JsValue, Value},
+    sys::{self, raw, wrap::WrapError},
+    types::{
+        boxed::Finalize,
+        build,
+        extract::{TryFromJs, TryIntoJs},
+        function::CallOptions,
+        private::ValueInternal,
+        utf8::Utf8,
+        JsFunction, JsUndefined, JsValue, Value,
+    },
 };
 
 #[cfg(feature = "napi-6")]
 use crate::{result::JsResult, types::JsArray};
 
+#[cfg(feature = "napi-8")]
+use crate::types::TypeTag;
+
+mod property;
+
+pub use self::property::PropertyDescriptor;
+
 /// A property key in a JavaScript object.
 pub trait PropertyKey {
     unsafe fn get_from<'c, C: Context<'c>>(
@@ -61,6 +78,21 @@ pub trait PropertyKey {
     ) -> bool;
 }
 
+/// A [`PropertyKey`] that can name a property defined with a [`PropertyDescriptor`]:
+/// a string, an integer or a handle to a JavaScript value. This trait is sealed and
+/// cannot be implemented by types outside of the Neon crate.
+pub trait DescriptorKey: PropertyKey + private::DescriptorKeyInternal {}
+
+mod private {
+    use crate::{context::Context, sys::raw};
+
+    /// This type marks the `DescriptorKey` trait as sealed.
+    pub trait DescriptorKeyInternal {
+        /// Converts the key to a JavaScript value naming a property
+        fn to_key<'c, C: Context<'c>>(self, cx: &mut C) -> raw::Local;
+    }
+}
+
 impl PropertyKey for u32 {
     unsafe fn get_from<'c, C: Context<'c>>(
         self,
@@ -82,6 +114,14 @@ impl PropertyKey for u32 {
     }
 }
 
+impl private::DescriptorKeyInternal for u32 {
+    fn to_key<'c, C: Context<'c>>(self, cx: &mut C) -> raw::Local {
+        cx.string(self.to_string()).to_local()
+    }
+}
+
+impl DescriptorKey for u32 {}
+
 impl<'a, K: Value> PropertyKey for Handle<'a, K> {
     unsafe fn get_from<'c, C: Context<'c>>(
         self,
@@ -107,6 +147,14 @@ impl<'a, K: Value> PropertyKey for Handle<'a, K> {
     }
 }
 
+impl<K: Value> private::DescriptorKeyInternal for Handle<'_, K> {
+    fn to_key<'c, C: Context<'c>>(self, _cx: &mut C) -> raw::Local {
+        self.to_local()
+    }
+}
+
+impl<K: Value> DescriptorKey for Handle<'_, K> {}
+
 impl<'a> PropertyKey for &'a str {
     unsafe fn get_from<'c, C: Context<'c>>(
         self,
@@ -134,6 +182,18 @@ impl<'a> PropertyKey for &'a str {
     }
 }
 
+impl private::DescriptorKeyInternal for &str {
+    fn to_key<'c, C: Context<'c>>(self, cx: &mut C) -> raw::Local {
+        cx.string(self).to_local()
+    }
+}
+
+impl DescriptorKey for &str {}
+
+// Rust value associated with an object by `Object::wrap`. A distinct type prevents
+// reading the instances of classes defined with `#[neon::class]` as a wrapped value.
+struct Wrapped<T>(T);
+
 /// The trait of all object types.
 pub trait Object: Value {
     /// Gets a property from a JavaScript object that may be `undefined` and
@@ -178,6 +238,29 @@ pub trait Object: Value {
         self.get_value(cx, key)?.downcast_or_throw(cx)
     }
 
+    /// Gets a property from a JavaScript object and converts it to `T` with
+    /// [`TryFromJs`].
+    ///
+    /// Throws a `TypeError` if the value cannot be converted.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// # fn foo(mut cx: FunctionContext) -> NeonResult<()> {
+    /// # let obj = cx.empty_object();
+    /// let retries: Option<u32> = obj.get_as(&mut cx, "retries")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn get_as<'a, T: TryFromJs<'a>, C: Context<'a>, K: PropertyKey>(
+        &self,
+        cx: &mut C,
+        key: K,
+    ) -> NeonResult<T> {
+        let v = self.get_value(cx, key)?;
+
+        T::from_js(cx, v)
+    }
+
     #[cfg(feature = "napi-6")]
     #[cfg_attr(docsrs, doc(cfg(feature = "napi-6")))]
     fn get_own_property_names<'a, C: Context<'a>>(&self, cx: &mut C) -> JsResult<'a, JsArray> {
@@ -214,6 +297,36 @@ pub trait Object: Value {
         }
     }
 
+    /// Tags the object with `tag`, which can later be checked with
+    /// [`Object::check_type_tag`]. See [`TypeTag`] for an example.
+    ///
+    /// Throws a `TypeError` if the object already has a type tag. An object can
+    /// only be tagged once, including by Neon, which tags objects wrapped with
+    /// [`Object::wrap`] and instances of classes defined with `#[neon::class]`.
+    /// Those objects cannot be tagged, and tagged objects cannot be wrapped.
+    #[cfg(feature = "napi-8")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "napi-8")))]
+    fn type_tag<'a, C: Context<'a>>(&self, cx: &mut C, tag: &TypeTag) -> NeonResult<()> {
+        let env = cx.env().to_raw();
+
+        unsafe {
+            match sys::tag::type_tag_object(env, self.to_local(), &tag.to_raw()) {
+                Ok(()) => Ok(()),
+                Err(sys::Status::PendingException) => Err(Throw::new()),
+                Err(_) => cx.throw_type_error("Object already has a type tag"),
+            }
+        }
+    }
+
+    /// Checks if the object was tagged with `tag` by [`Object::type_tag`].
+    #[cfg(feature = "napi-8")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "napi-8")))]
+    fn check_type_tag<'a, C: Context<'a>>(&self, cx: &mut C, tag: &TypeTag) -> bool {
+        let env = cx.env().to_raw();
+
+        unsafe { sys::tag::check_object_type_tag(env, self.to_local(), &tag.to_raw()) }
+    }
+
     fn set<'a, C: Context<'a>, K: PropertyKey, W: Value>(
         &self,
         cx: &mut C,
@@ -230,10 +343,214 @@ pub trait Object: Value {
         }
     }
 
+    /// Converts a Rust value with [`TryIntoJs`] and sets it as a property of a
+    /// JavaScript object.
+    fn set_as<'a, C: Context<'a>, K: PropertyKey, T: TryIntoJs<'a>>(
+        &self,
+        cx: &mut C,
+        key: K,
+        val: T,
+    ) -> NeonResult<bool> {
+        let val = val.try_into_js(cx)?;
+
+        self.set(cx, key, val)
+    }
+
+    /// Defines a property of the object described by `descriptor`, e.g., a read-only
+    /// or non-enumerable property. See [`PropertyDescriptor`] for an example.
+    ///
+    /// Throws a `TypeError` if the property cannot be defined, e.g., if the object
+    /// is frozen or the property exists and is not configurable.
+    fn define_property<'a, C: Context<'a>>(
+        &self,
+        cx: &mut C,
+        descriptor: PropertyDescriptor<'a>,
+    ) -> NeonResult<()> {
+        self.define_properties(cx, [descriptor])
+    }
+
+    /// Defines multiple properties of the object in a single call. See
+    /// [`Object::define_property`].
+    fn define_properties<'a, C, I>(&self, cx: &mut C, descriptors: I) -> NeonResult<()>
+    where
+        C: Context<'a>,
+        I: IntoIterator<Item = PropertyDescriptor<'a>>,
+    {
+        let properties = descriptors
+            .into_iter()
+            .map(PropertyDescriptor::into_property)
+            .collect::<Vec<_>>();
+
+        let intrinsics = if properties
+            .iter()
+            .any(|p| p.getter.is_some() || p.setter.is_some())
+        {
+            Some(property::intrinsics(cx)?)
+        } else {
+            None
+        };
+
+        let env = cx.env().to_raw();
+        let status = unsafe {
+            sys::object::define_properties(env, self.to_local(), properties, intrinsics.as_ref())
+        };
+
+        match status {
+            sys::Status::Ok => Ok(()),
+            sys::Status::PendingException => Err(unsafe { Throw::new() }),
+            _ => cx.throw_type_error("Cannot define property"),
+        }
+    }
+
     fn root<'a, C: Context<'a>>(&self, cx: &mut C) -> Root<Self> {
         Root::new(cx, self)
     }
 
+    /// Associates a Rust value with the object, which is finalized when the object
+    /// is garbage collected, as with [`JsBox`](crate::types::JsBox). Unlike a `JsBox`,
+    /// the object may be any object, e.g., the `this` of a JavaScript class.
+    ///
+    /// Throws a `TypeError` if the object already has a wrapped value.
+    ///
+    /// **Note:** On Node-API 8, Neon tags wrapped objects to detect objects wrapped
+    /// by other native modules. Since an object can only have a single type tag, an
+    /// object tagged with [`Object::type_tag`] cannot be wrapped and a `TypeError`
+    /// is thrown.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// struct Counter(std::cell::Cell<u32>);
+    ///
+    /// impl Finalize for Counter {}
+    ///
+    /// // Called as `new Counter()` from a JavaScript class constructor
+    /// fn counter_new(mut cx: FunctionContext) -> JsResult<JsObject> {
+    ///     let this = cx.this::<JsObject>()?;
+    ///
+    ///     this.wrap(&mut cx, Counter(std::cell::Cell::new(0)))?;
+    ///
+    ///     Ok(this)
+    /// }
+    ///
+    /// // Called as `counter.increment()`
+    /// fn counter_increment(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    ///     let this = cx.this::<JsObject>()?;
+    ///     let counter = match this.unwrap::<Counter, _>(&mut cx) {
+    ///         Some(counter) => counter,
+    ///         None => return cx.throw_type_error("Illegal invocation"),
+    ///     };
+    ///
+    ///     counter.0.set(counter.0.get() + 1);
+    ///
+    ///     Ok(cx.number(counter.0.get()))
+    /// }
+    /// ```
+    fn wrap<'a, T: Finalize + 'static, C: Context<'a>>(
+        &self,
+        cx: &mut C,
+        value: T,
+    ) -> NeonResult<()> {
+        fn finalizer<U: Finalize + 'static>(env: raw::Env, data: Box<dyn Any>) {
+            let Wrapped(data) = *data.downcast::<Wrapped<U>>().unwrap();
+
+            FinalizeContext::with(env.into(), move |mut cx| data.finalize(&mut cx));
+        }
+
+        let data: Box<dyn Any> = Box::new(Wrapped(value));
+        let env = cx.env().to_raw();
+
+        match unsafe { sys::wrap::wrap(env, self.to_local(), data, finalizer::<T>) } {
+            Ok(()) => Ok(()),
+            Err(WrapError::Status(sys::Status::PendingException)) => Err(unsafe { Throw::new() }),
+            Err(WrapError::Status(_)) => cx.throw_type_error("Object has already been wrapped"),
+            #[cfg(feature = "napi-8")]
+            Err(WrapError::Tagged) => cx.throw_type_error("Object already has a type tag"),
+        }
+    }
+
+    /// Returns a reference to the Rust value associated with the object by
+    /// [`Object::wrap`], or `None` if the object has not been wrapped or its
+    /// value is not a `T`.
+    fn unwrap<'a, T: 'static, C: Context<'a>>(&self, cx: &mut C) -> Option<&T> {
+        // # Safety
+        // Objects wrapped by other native modules are detected with type tags
+        // on Node-API 8. The value is kept alive by `self` and is only removed
+        // by the `unsafe` method `remove_wrap`.
+        unsafe {
+            sys::wrap::unwrap::<Box<dyn Any>>(cx.env().to_raw(), self.to_local())
+                .and_then(|v| (*v).downcast_ref::<Wrapped<T>>())
+                .map(|Wrapped(v)| v)
+        }
+    }
+
+    /// Removes the Rust value associated with the object by [`Object::wrap`] and
+    /// returns it without finalizing it. Returns `None` if the object has not been
+    /// wrapped or its value is not a `T`.
+    ///
+    /// # Safety
+    ///
+    /// References returned by [`Object::unwrap`] must not be used after the value
+    /// is removed.
+    unsafe fn remove_wrap<'a, T: 'static, C: Context<'a>>(&self, cx: &mut C) -> Option<T> {
+        let env = cx.env().to_raw();
+
+        // Check the type before removing the value, so it is left in place on a mismatch
+        self.unwrap::<T, _>(cx)?;
+
+        let data = sys::wrap::remove_wrap::<Box<dyn Any>>(env, self.to_local())?;
+        let Wrapped(v) = *data.downcast::<Wrapped<T>>().ok()?;
+
+        Some(v)
+    }
+
+    /// Calls `f` with `data` when the object is garbage collected, similar to a
+    /// JavaScript [`FinalizationRegistry`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/FinalizationRegistry).
+    /// An object may have any number of finalizers.
+    ///
+    /// Finalizers are useful to release native resources associated with objects
+    /// that are not created by the addon. Unlike a [`JsBox`](crate::types::JsBox)
+    /// or [`Object::wrap`], the data is not accessible from the object.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// # use std::fs::File;
+    /// // Closes the file when the object is garbage collected
+    /// fn close_on_gc(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    ///     let obj = cx.argument::<JsObject>(0)?;
+    ///     let path = cx.argument::<JsString>(1)?.value(&mut cx);
+    ///     let file = File::open(path).or_else(|err| cx.throw_error(err.to_string()))?;
+    ///
+    ///     obj.add_finalizer(&mut cx, file, |_cx, file| drop(file))?;
+    ///
+    ///     Ok(cx.undefined())
+    /// }
+    /// ```
+    #[cfg(feature = "napi-5")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "napi-5")))]
+    fn add_finalizer<'a, T, F, C>(&self, cx: &mut C, data: T, f: F) -> NeonResult<()>
+    where
+        T: 'static,
+        F: for<'b> FnOnce(FinalizeContext<'b>, T) + 'static,
+        C: Context<'a>,
+    {
+        fn finalizer<T, F>(env: raw::Env, (data, f): (T, F))
+        where
+            F: for<'b> FnOnce(FinalizeContext<'b>, T),
+        {
+            FinalizeContext::with(env.into(), move |cx| f(cx, data));
+        }
+
+        let env = cx.env().to_raw();
+
+        unsafe {
+            match sys::wrap::add_finalizer(env, self.to_local(), (data, f), finalizer) {
+                Ok(()) => Ok(()),
+                Err(sys::Status::PendingException) => Err(Throw::new()),
+                Err(_) => cx.throw_error("Failed to add a finalizer"),
+            }
+        }
+    }
+
     fn call_method_with<'a, C, K>(&self, cx: &mut C, method: K) -> NeonResult<CallOptions<'a>>
     where
         C: Context<'a>,
@@ -0,0 +1,221 @@
+use std::marker::PhantomData;
+
+use crate::{
+    context::Context,
+    handle::Handle,
+    object::{DescriptorKey, Object},
+    result::NeonResult,
+    sys::{
+        self,
+        object::{Intrinsics, Property},
+        raw,
+    },
+    types::{private::ValueInternal, JsFunction, Value},
+};
+
+#[cfg(feature = "napi-6")]
+use crate::{handle::Root, thread::LocalKey};
+
+#[cfg(feature = "napi-5")]
+use crate::{context::FunctionContext, result::JsResult, types::function};
+
+/// Describes a property defined with [`Object::define_property`](super::Object::define_property)
+/// or [`Object::define_properties`](super::Object::define_properties).
+///
+/// Like [`Object.defineProperty`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/defineProperty),
+/// properties are not writable, enumerable or configurable unless enabled with
+/// [`writable`](PropertyDescriptor::writable), [`enumerable`](PropertyDescriptor::enumerable)
+/// and [`configurable`](PropertyDescriptor::configurable).
+///
+/// ```
+/// # use neon::prelude::*;
+/// use neon::object::PropertyDescriptor;
+///
+/// fn constants(mut cx: FunctionContext) -> JsResult<JsObject> {
+///     let obj = cx.empty_object();
+///     let version = cx.string("1.0.0");
+///     let secret = cx.string("hunter2");
+///
+///     let descriptors = [
+///         // Read-only, but listed by `Object.keys`
+///         PropertyDescriptor::value(&mut cx, "version", version).enumerable(true),
+///         // Hidden from `Object.keys` and `JSON.stringify`
+///         PropertyDescriptor::value(&mut cx, "secret", secret),
+///     ];
+///
+///     obj.define_properties(&mut cx, descriptors)?;
+///
+///     Ok(obj)
+/// }
+/// ```
+pub struct PropertyDescriptor<'a> {
+    property: Property,
+    _lifetime: PhantomData<&'a ()>,
+}
+
+impl<'a> PropertyDescriptor<'a> {
+    /// Describes a data property named `key` containing `value`.
+    pub fn value<C, K, V>(cx: &mut C, key: K, value: Handle<'a, V>) -> Self
+    where
+        C: Context<'a>,
+        K: DescriptorKey,
+        V: Value,
+    {
+        Self::new(cx, key, value.to_local())
+    }
+
+    /// Describes a data property like one created by assignment, i.e., writable,
+    /// enumerable and configurable. Unlike assignment, setters on the prototype,
+    /// including `__proto__`, are not called.
+    pub(crate) fn own_value<C, K, V>(cx: &mut C, key: K, value: Handle<'a, V>) -> Self
+    where
+        C: Context<'a>,
+        K: DescriptorKey,
+        V: Value,
+    {
+        Self::value(cx, key, value)
+            .writable(true)
+            .enumerable(true)
+            .configurable(true)
+    }
+
+    #[cfg(feature = "napi-5")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "napi-5")))]
+    /// Describes an accessor property named `key` with a `getter` called when the
+    /// property is read. A setter may be added with [`PropertyDescriptor::setter`].
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// use neon::object::PropertyDescriptor;
+    ///
+    /// fn create_timer(mut cx: FunctionContext) -> JsResult<JsObject> {
+    ///     let obj = cx.empty_object();
+    ///     let start = std::time::Instant::now();
+    ///     let elapsed = PropertyDescriptor::accessor(&mut cx, "elapsed", move |mut cx| {
+    ///         Ok(cx.number(start.elapsed().as_secs_f64()))
+    ///     });
+    ///
+    ///     obj.define_property(&mut cx, elapsed.enumerable(true))?;
+    ///
+    ///     Ok(obj)
+    /// }
+    /// ```
+    pub fn accessor<C, K, F, V>(cx: &mut C, key: K, getter: F) -> Self
+    where
+        C: Context<'a>,
+        K: DescriptorKey,
+        F: Fn(FunctionContext) -> JsResult<V> + 'static,
+        V: Value,
+    {
+        let mut descriptor = Self::new(cx, key, std::ptr::null_mut());
+
+        descriptor.property.getter = Some(Box::new(function::callback(getter)));
+        descriptor
+    }
+
+    #[cfg(feature = "napi-5")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "napi-5")))]
+    /// Adds a `setter` to an accessor property, called with the assigned value as the
+    /// first argument. The value returned by `setter` is ignored.
+    ///
+    /// **Note:** Has no effect on data properties.
+    pub fn setter<F, V>(mut self, setter: F) -> Self
+    where
+        F: Fn(FunctionContext) -> JsResult<V> + 'static,
+        V: Value,
+    {
+        if self.property.getter.is_some() {
+            self.property.setter = Some(Box::new(function::callback(setter)));
+        }
+
+        self
+    }
+
+    /// Sets whether the value of a data property can be changed by assignment.
+    /// Accessor properties are never writable.
+    pub fn writable(self, writable: bool) -> Self {
+        self.attribute(sys::PropertyAttributes::WRITABLE, writable)
+    }
+
+    /// Sets whether the property is listed by `Object.keys` and `for...in` loops.
+    pub fn enumerable(self, enumerable: bool) -> Self {
+        self.attribute(sys::PropertyAttributes::ENUMERABLE, enumerable)
+    }
+
+    /// Sets whether the property can be deleted or redefined.
+    pub fn configurable(self, configurable: bool) -> Self {
+        self.attribute(sys::PropertyAttributes::CONFIGURABLE, configurable)
+    }
+
+    fn new<C, K>(cx: &mut C, key: K, value: raw::Local) -> Self
+    where
+        C: Context<'a>,
+        K: DescriptorKey,
+    {
+        Self {
+            property: Property {
+                name: key.to_key(cx),
+                value,
+                getter: None,
+                setter: None,
+                attributes: sys::PropertyAttributes::DEFAULT,
+            },
+            _lifetime: PhantomData,
+        }
+    }
+
+    fn attribute(mut self, attribute: sys::PropertyAttributes, enabled: bool) -> Self {
+        let attributes = &mut self.property.attributes;
+
+        if enabled {
+            *attributes |= attribute;
+        } else {
+            attributes.0 &= !attribute.0;
+        }
+
+        self
+    }
+
+    pub(crate) fn into_property(self) -> Property {
+        self.property
+    }
+}
+
+// `Object.create` and `Object.defineProperty` are looked up once per instance of
+// the module, instead of on every call, since `globalThis` may be modified by
+// user code
+#[cfg(feature = "napi-6")]
+pub(super) fn intrinsics<'a, C: Context<'a>>(cx: &mut C) -> NeonResult<Intrinsics> {
+    static INTRINSICS: LocalKey<(Root<JsFunction>, Root<JsFunction>)> = LocalKey::new();
+
+    let (create, define_property) = INTRINSICS.get_or_try_init(cx, |cx| {
+        let (create, define_property) = lookup_intrinsics(cx)?;
+
+        Ok((create.root(cx), define_property.root(cx)))
+    })?;
+
+    Ok(Intrinsics {
+        create: create.to_inner(cx).to_local(),
+        define_property: define_property.to_inner(cx).to_local(),
+    })
+}
+
+#[cfg(not(feature = "napi-6"))]
+pub(super) fn intrinsics<'a, C: Context<'a>>(cx: &mut C) -> NeonResult<Intrinsics> {
+    let (create, define_property) = lookup_intrinsics(cx)?;
+
+    Ok(Intrinsics {
+        create: create.to_local(),
+        define_property: define_property.to_local(),
+    })
+}
+
+fn lookup_intrinsics<'a, C: Context<'a>>(
+    cx: &mut C,
+) -> NeonResult<(Handle<'a, JsFunction>, Handle<'a, JsFunction>)> {
+    let object = cx.global::<JsFunction>("Object")?;
+    let create = object.get(cx, "create")?;
+    let define_property = object.get(cx, "defineProperty")?;
+
+    Ok((create, define_property))
+}
@@ -0,0 +1,292 @@
+//! Configuring how Rust panics are reported to JavaScript.
+//!
+//! A panic in a Neon function is caught and thrown as a JavaScript `Error` with
+//! the message `internal error in Neon module: ...`. A panic in a closure sent
+//! with a [`Channel`](crate::event::Channel) or in the callback of a task is
+//! emitted as an uncaught exception, with the panic as the `panic` property
+//! of the error. On Node versions older than 14, or without the `napi-3`
+//! feature, it is emitted as an unhandled rejection instead.
+//!
+//! By default, the location of the panic and a backtrace are only printed to
+//! stderr. A [`PanicPolicy`] adds them to the JavaScript error, so that they
+//! are included in crash reports. The policy applies to the whole module and
+//! is usually set when the module is loaded, either with the `panic_policy`
+//! option of [`#[neon::main]`](crate::main) or with
+//! [`ModuleContext::set_panic_policy`](crate::context::ModuleContext::set_panic_policy):
+//!
+//! ```no_run
+//! # mod example {
+//! # use neon::prelude::*;
+//! use neon::panic::PanicPolicy;
+//!
+//! fn panic_policy() -> PanicPolicy {
+//!     PanicPolicy::new().location(true).backtrace(true)
+//! }
+//!
+//! #[neon::main(panic_policy = "panic_policy")]
+//! fn main(cx: ModuleContext) -> NeonResult<()> {
+//!     Ok(())
+//! }
+//! # }
+//! ```
+//!
+//! A panic then creates an error with a `rustBacktrace` property:
+//!
+//! ```text
+//! panicked at src/lib.rs:10:5
+//!    0: std::backtrace::Backtrace::force_capture
+//!    ...
+//! ```
+
+use std::{
+    backtrace::Backtrace,
+    cell::{Cell, RefCell},
+    panic::{self, catch_unwind, AssertUnwindSafe},
+    sync::{Once, RwLock},
+};
+
+use crate::{
+    context::{internal::Env, Context, TaskContext},
+    handle::Handle,
+    object::Object,
+    result::{JsResult, NeonResult},
+    sys::{self, raw},
+    types::{private::ValueInternal, JsObject, Value},
+};
+
+/// Creates the error for a panic from its message, e.g., an instance of a
+/// custom error class.
+pub type PanicError = for<'cx> fn(&mut TaskContext<'cx>, &str) -> JsResult<'cx, JsObject>;
+
+/// Options for reporting Rust panics as JavaScript errors.
+///
+/// ```
+/// # use neon::prelude::*;
+/// use neon::panic::PanicPolicy;
+///
+/// // Creates a `RustPanic` instead of an `Error`
+/// fn panic_error<'cx>(cx: &mut TaskContext<'cx>, msg: &str) -> JsResult<'cx, JsObject> {
+///     let err = cx.error(msg)?;
+///     let name = cx.string("RustPanic");
+///
+///     err.set(cx, "name", name)?;
+///
+///     Ok(err.upcast())
+/// }
+///
+/// let policy = PanicPolicy::new()
+///     .location(true)
+///     .backtrace(true)
+///     .stack(true)
+///     .error(panic_error);
+/// ```
+#[derive(Clone, Copy)]
+pub struct PanicPolicy {
+    location: bool,
+    backtrace: bool,
+    stack: bool,
+    error: Option<PanicError>,
+}
+
+impl PanicPolicy {
+    /// Creates a policy that reports panics like Neon does by default.
+    pub const fn new() -> Self {
+        Self {
+            location: false,
+            backtrace: false,
+            stack: false,
+            error: None,
+        }
+    }
+
+    /// Include the file, line and column of the panic.
+    pub fn location(mut self, location: bool) -> Self {
+        self.location = location;
+        self
+    }
+
+    /// Include a backtrace captured when the panic occurs. The backtrace is
+    /// captured regardless of the `RUST_BACKTRACE` environment variable.
+    pub fn backtrace(mut self, backtrace: bool) -> Self {
+        self.backtrace = backtrace;
+        self
+    }
+
+    /// Append the location and backtrace to the JavaScript `stack` of the error,
+    /// instead of setting the `rustBacktrace` property.
+    pub fn stack(mut self, stack: bool) -> Self {
+        self.stack = stack;
+        self
+    }
+
+    /// Create errors for panics with a function instead of the `Error`
+    /// constructor. If the function throws or panics, an `Error` is created.
+    pub fn error(mut self, error: PanicError) -> Self {
+        self.error = Some(error);
+        self
+    }
+
+    fn captures(&self) -> bool {
+        self.location || self.backtrace
+    }
+}
+
+impl Default for PanicPolicy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+static POLICY: RwLock<PanicPolicy> = RwLock::new(PanicPolicy::new());
+
+thread_local! {
+    // Details of the last panic on this thread, recorded by the panic hook
+    static LAST_PANIC: RefCell<Option<Report>> = const { RefCell::new(None) };
+
+    // Whether this thread is running a Neon callback that reports panics to
+    // JavaScript. Panics elsewhere in the process are not captured.
+    static IN_BOUNDARY: Cell<bool> = const { Cell::new(false) };
+}
+
+// Location and backtrace of a panic
+struct Report {
+    location: Option<String>,
+    backtrace: Option<String>,
+}
+
+impl Report {
+    fn text(&self) -> Option<String> {
+        let location = self
+            .location
+            .as_ref()
+            .map(|location| format!("panicked at {}", location));
+
+        match (location, &self.backtrace) {
+            (Some(location), Some(backtrace)) => Some(format!("{}\n{}", location, backtrace)),
+            (Some(location), None) => Some(location),
+            (None, Some(backtrace)) => Some(backtrace.clone()),
+            (None, None) => None,
+        }
+    }
+}
+
+// Runs `f` in a Neon callback, so that the panic hook captures the details of
+// a panic in `f` for `create_error`
+pub(crate) fn boundary<T>(f: impl FnOnce() -> T) -> T {
+    struct Reset(bool);
+
+    impl Drop for Reset {
+        fn drop(&mut self) {
+            IN_BOUNDARY.with(|in_boundary| in_boundary.set(self.0));
+        }
+    }
+
+    let _reset = Reset(IN_BOUNDARY.with(|in_boundary| in_boundary.replace(true)));
+
+    f()
+}
+
+fn policy() -> PanicPolicy {
+    *POLICY.read().unwrap_or_else(|err| err.into_inner())
+}
+
+pub(crate) fn set_policy(policy: PanicPolicy) {
+    *POLICY.write().unwrap_or_else(|err| err.into_inner()) = policy;
+
+    if policy.captures() {
+        install_hook();
+    }
+}
+
+// The location and backtrace of a panic are only available in the panic hook.
+// The existing hook is still called, so that panics are printed as usual.
+fn install_hook() {
+    static HOOK: Once = Once::new();
+
+    HOOK.call_once(|| {
+        let hook = panic::take_hook();
+
+        panic::set_hook(Box::new(move |info| {
+            let policy = policy();
+
+            // Capturing a backtrace is expensive; skip panics on threads that are
+            // not running a Neon callback, since they are never reported
+            if policy.captures() && IN_BOUNDARY.with(Cell::get) {
+                let report = Report {
+                    location: info
+                        .location()
+                        .filter(|_| policy.location)
+                        .map(ToString::to_string),
+                    backtrace: policy
+                        .backtrace
+                        .then(|| Backtrace::force_capture().to_string()),
+                };
+
+                LAST_PANIC.with(|last| *last.borrow_mut() = Some(report));
+            }
+
+            hook(info);
+        }));
+    });
+}
+
+// Creates the error for a panic with the message `msg` following the policy of
+// the module. Returns `None`, without a pending exception, if the error could
+// not be created.
+pub(crate) fn create_error(env: Env, msg: &str) -> Option<raw::Local> {
+    let policy = policy();
+    let report = LAST_PANIC
+        .with(|last| last.borrow_mut().take())
+        .and_then(|report| report.text());
+
+    TaskContext::with_context(env, |mut cx| {
+        let err = catch_unwind(AssertUnwindSafe(|| {
+            let err = match policy.error {
+                Some(error) => {
+                    let err = cx.try_catch(|cx| error(cx, msg)).ok();
+
+                    match err {
+                        Some(err) => err,
+                        None => cx.error(msg).ok()?.upcast(),
+                    }
+                }
+                None => cx.error(msg).ok()?.upcast(),
+            };
+
+            if let Some(report) = &report {
+                cx.try_catch(|cx| add_report(cx, err, &policy, report))
+                    .ok()?;
+            }
+
+            Some(err.to_local())
+        }));
+
+        let err = err.ok().flatten();
+
+        if err.is_none() {
+            unsafe { sys::error::clear_exception(env.to_raw()) };
+        }
+
+        err
+    })
+}
+
+fn add_report<'a, C: Context<'a>>(
+    cx: &mut C,
+    err: Handle<JsObject>,
+    policy: &PanicPolicy,
+    report: &str,
+) -> NeonResult<()> {
+    if !policy.stack {
+        let report = cx.string(report);
+
+        return err.set(cx, "rustBacktrace", report).map(|_| ());
+    }
+
+    let header = err.to_string(cx)?.value(cx);
+    let stack = cx.string(format!("{}\n{}", header, report));
+
+    err.set(cx, "stack", stack)?;
+
+    Ok(())
+}
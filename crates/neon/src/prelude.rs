@@ -3,18 +3,18 @@
 #[doc(no_inline)]
 pub use crate::{
     context::{
-        CallKind, ComputeContext, Context, ExecuteContext, FunctionContext, ModuleContext,
-        TaskContext,
+        CallKind, ComputeContext, Context, ExecuteContext, FinalizeContext, FunctionContext,
+        ModuleContext, TaskContext,
     },
     handle::{Handle, Root},
     object::Object,
     result::{JsResult, NeonResult, ResultExt as NeonResultExt},
     types::{
         boxed::{Finalize, JsBox},
-        JsArray, JsArrayBuffer, JsBigInt64Array, JsBigUint64Array, JsBoolean, JsBuffer, JsError,
-        JsFloat32Array, JsFloat64Array, JsFunction, JsInt16Array, JsInt32Array, JsInt8Array,
-        JsNull, JsNumber, JsObject, JsPromise, JsString, JsTypedArray, JsUint16Array,
-        JsUint32Array, JsUint8Array, JsUndefined, JsValue, Value,
+        JsArray, JsArrayBuffer, JsBigInt64Array, JsBigUint64Array, JsBoolean, JsBuffer, JsDataView,
+        JsError, JsFloat32Array, JsFloat64Array, JsFunction, JsInt16Array, JsInt32Array,
+        JsInt8Array, JsNull, JsNumber, JsObject, JsPromise, JsString, JsTypedArray, JsUint16Array,
+        JsUint32Array, JsUint8Array, JsUint8ClampedArray, JsUndefined, JsValue, Value,
     },
 };
 
@@ -31,14 +31,71 @@
 //! }
 //! ```
 //!
+//! ## Rust errors
+//!
+//! Rust errors can't be returned with `?` from a function that returns a
+//! `NeonResult`, since [`Throw`] means an exception is already pending. Any
+//! [`std::error::Error`] can be converted to an [`Error`] and thrown as a
+//! JavaScript `Error` with [`ResultExt::or_throw`]:
+//!
+//! ```
+//! # use neon::prelude::*;
+//! use neon::result::Error;
+//!
+//! fn read_file(mut cx: FunctionContext) -> JsResult<JsString> {
+//!     let path = cx.argument::<JsString>(0)?.value(&mut cx);
+//!     let contents = std::fs::read_to_string(path)
+//!         .map_err(Error::from)
+//!         .or_throw(&mut cx)?;
+//!
+//!     Ok(cx.string(contents))
+//! }
+//! ```
+//!
+//! Alternatively, functions exported with [`#[neon::export]`](crate::export)
+//! may return a `Result` with the error type [`Error`]. Both Rust errors and
+//! [`Throw`] convert to `Error` with `?`:
+//!
+//! ```
+//! # use neon::prelude::*;
+//! #[neon::export]
+//! fn read_file(cx: &mut FunctionContext, path: Handle<JsString>) -> Result<String, neon::result::Error> {
+//!     let path = path.value(cx);
+//!     let contents = std::fs::read_to_string(path)?;
+//!
+//!     if contents.is_empty() {
+//!         cx.throw_error("empty file")?;
+//!     }
+//!
+//!     Ok(contents)
+//! }
+//! ```
+//!
+//! ## Caught exceptions
+//!
+//! A [`Handle`] to a caught exception, e.g., from
+//! [`Context::try_catch`](crate::context::Context::try_catch), can't outlive its
+//! context. [`JsException`] copies the details of the exception so that it may
+//! be logged or sent to another thread. It is also included in the
+//! [`JoinError`](crate::event::JoinError) of a closure that threw.
+//!
 //! [question-mark]: https://doc.rust-lang.org/edition-guide/rust-2018/error-handling-and-panics/the-question-mark-operator-for-easier-error-handling.html
 
 use std::{
-    fmt::{Display, Formatter, Result as FmtResult},
+    error,
+    fmt::{self, Debug, Display, Formatter, Result as FmtResult},
     marker::PhantomData,
 };
 
-use crate::{context::Context, handle::Handle, types::Value};
+use crate::{
+    context::Context,
+    handle::Handle,
+    object::Object,
+    types::{
+        error::error_chain, extract::TryIntoJs, JsError, JsNumber, JsObject, JsString, JsUndefined,
+        JsValue, Value,
+    },
+};
 
 /// A [unit type][unit] indicating that the JavaScript thread is throwing an exception.
 ///
@@ -98,3 +155,297 @@ where
         self.or_else(|err| cx.throw(err))
     }
 }
+
+impl<T> ResultExt<T> for Result<T, Error> {
+    fn or_throw<'cx, C: Context<'cx>>(self, cx: &mut C) -> NeonResult<T> {
+        self.or_else(|err| err.throw(cx))
+    }
+}
+
+/// An error that is thrown as a JavaScript exception.
+///
+/// Any [`std::error::Error`] may be converted to an `Error` with `?`. It is
+/// thrown as a JavaScript `Error` with the [`Display`] message of the error
+/// and the [source](std::error::Error::source) chain converted to its `cause`.
+/// A [`Throw`] may also be converted, propagating the pending exception.
+///
+/// `Error` is converted with [`TryIntoJs`], so that it may be returned from
+/// functions exported with [`#[neon::export]`](crate::export).
+///
+/// Like [`Throw`], `Error` does not implement [`std::error::Error`].
+pub struct Error {
+    repr: Repr,
+}
+
+enum Repr {
+    Throw(Throw),
+    Rust {
+        // Messages of the error and each of its sources
+        messages: Vec<String>,
+        code: Option<String>,
+    },
+}
+
+impl Error {
+    /// Creates an error with a message.
+    pub fn new<S: AsRef<str>>(msg: S) -> Self {
+        Self {
+            repr: Repr::Rust {
+                messages: vec![msg.as_ref().to_string()],
+                code: None,
+            },
+        }
+    }
+
+    /// Sets the `code` property of the thrown error, e.g., `ERR_NOT_FOUND`.
+    /// Has no effect if the error is a [`Throw`].
+    pub fn code<S: AsRef<str>>(mut self, code: S) -> Self {
+        if let Repr::Rust { code: c, .. } = &mut self.repr {
+            *c = Some(code.as_ref().to_string());
+        }
+
+        self
+    }
+
+    /// Throws the error, or propagates the pending exception of a [`Throw`].
+    pub fn throw<'cx, C: Context<'cx>, T>(self, cx: &mut C) -> NeonResult<T> {
+        match self.repr {
+            Repr::Throw(throw) => Err(throw),
+            Repr::Rust { .. } => {
+                let err = self.try_into_js(cx)?;
+
+                cx.throw(err)
+            }
+        }
+    }
+}
+
+impl<E: error::Error> From<E> for Error {
+    fn from(err: E) -> Self {
+        Self {
+            repr: Repr::Rust {
+                messages: error_chain(&err),
+                code: None,
+            },
+        }
+    }
+}
+
+impl From<Throw> for Error {
+    fn from(throw: Throw) -> Self {
+        Self {
+            repr: Repr::Throw(throw),
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match &self.repr {
+            Repr::Throw(throw) => Display::fmt(throw, f),
+            Repr::Rust { messages, .. } => f.write_str(&messages[0]),
+        }
+    }
+}
+
+impl Debug for Error {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match &self.repr {
+            Repr::Throw(throw) => f.debug_tuple("Error").field(throw).finish(),
+            Repr::Rust { messages, code } => f
+                .debug_struct("Error")
+                .field("messages", messages)
+                .field("code", code)
+                .finish(),
+        }
+    }
+}
+
+impl<'cx> TryIntoJs<'cx> for Error {
+    type Value = JsError;
+
+    fn try_into_js<C>(self, cx: &mut C) -> JsResult<'cx, JsError>
+    where
+        C: Context<'cx>,
+    {
+        let (mut messages, code) = match self.repr {
+            Repr::Throw(throw) => return Err(throw),
+            Repr::Rust { messages, code } => (messages, code),
+        };
+
+        let msg = messages.remove(0);
+        let mut options = JsError::error_with(cx, msg);
+
+        options.cause_messages(messages);
+
+        if let Some(code) = code {
+            options.code(code);
+        }
+
+        options.apply(cx)
+    }
+
+    fn ts_output() -> String {
+        "Error".to_string()
+    }
+}
+
+// Causes are read up to this depth, since a `cause` may be circular
+const MAX_CAUSE_DEPTH: usize = 16;
+
+/// A JavaScript exception copied into an owned Rust error.
+///
+/// Captures the `name`, `message`, `stack`, `code` and `cause` of a thrown
+/// value. Unlike a [`Handle`], a `JsException` is [`Send`] and implements
+/// [`std::error::Error`], so it may be logged or returned from another thread.
+///
+/// ```
+/// # use neon::prelude::*;
+/// use neon::result::JsException;
+///
+/// fn call_logged(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+///     let f = cx.argument::<JsFunction>(0)?;
+///
+///     if let Err(err) = cx.try_catch(|cx| f.call_with(&*cx).exec(cx)) {
+///         let err = JsException::new(&mut cx, err);
+///
+///         eprintln!("callback failed: {}", err);
+///     }
+///
+///     Ok(cx.undefined())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct JsException {
+    name: Option<String>,
+    message: String,
+    stack: Option<String>,
+    code: Option<String>,
+    cause: Option<Box<JsException>>,
+}
+
+impl JsException {
+    /// Copies a thrown value, e.g., the `Err` of
+    /// [`Context::try_catch`](crate::context::Context::try_catch).
+    ///
+    /// Properties are read from objects; any other value is converted to a
+    /// string for the message. Exceptions thrown while reading properties are
+    /// caught and the property is treated as missing.
+    pub fn new<'a, C: Context<'a>, V: Value>(cx: &mut C, value: Handle<V>) -> Self {
+        Self::with_depth(cx, value.upcast(), 0)
+    }
+
+    fn with_depth<'a, C: Context<'a>>(cx: &mut C, value: Handle<JsValue>, depth: usize) -> Self {
+        let obj = match value.downcast::<JsObject, _>(cx) {
+            Ok(obj) => obj,
+            Err(_) => {
+                return Self {
+                    name: None,
+                    message: to_string(cx, value).unwrap_or_default(),
+                    stack: None,
+                    code: None,
+                    cause: None,
+                }
+            }
+        };
+
+        let name = get(cx, obj, "name").and_then(|v| string(cx, v));
+        let message = match get(cx, obj, "message").and_then(|v| string(cx, v)) {
+            Some(message) => message,
+            None => to_string(cx, value).unwrap_or_default(),
+        };
+
+        let stack = get(cx, obj, "stack").and_then(|v| string(cx, v));
+        let code = get(cx, obj, "code").and_then(|v| {
+            if v.is_a::<JsNumber, _>(cx) {
+                to_string(cx, v)
+            } else {
+                string(cx, v)
+            }
+        });
+
+        let cause = match get(cx, obj, "cause") {
+            Some(cause) if depth < MAX_CAUSE_DEPTH => {
+                Some(Box::new(Self::with_depth(cx, cause, depth + 1)))
+            }
+            _ => None,
+        };
+
+        Self {
+            name,
+            message,
+            stack,
+            code,
+            cause,
+        }
+    }
+
+    /// The `name` of the error, e.g., `TypeError`.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// The `message` of the error, or the thrown value converted to a string.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// The `stack` of the error.
+    pub fn stack(&self) -> Option<&str> {
+        self.stack.as_deref()
+    }
+
+    /// The `code` of the error, e.g., `ERR_INVALID_ARG_TYPE`.
+    pub fn code(&self) -> Option<&str> {
+        self.code.as_deref()
+    }
+
+    /// The `cause` of the error.
+    pub fn cause(&self) -> Option<&JsException> {
+        self.cause.as_deref()
+    }
+}
+
+impl Display for JsException {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match &self.name {
+            Some(name) if !self.message.is_empty() => write!(f, "{}: {}", name, self.message),
+            Some(name) => f.write_str(name),
+            None => f.write_str(&self.message),
+        }
+    }
+}
+
+impl error::Error for JsException {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        self.cause().map(|cause| cause as _)
+    }
+}
+
+// Reads a property, ignoring exceptions and `undefined`
+fn get<'a, C: Context<'a>>(
+    cx: &mut C,
+    obj: Handle<JsObject>,
+    key: &str,
+) -> Option<Handle<'a, JsValue>> {
+    let v = cx.try_catch(|cx| obj.get_value(cx, key)).ok()?;
+
+    if v.is_a::<JsUndefined, _>(cx) {
+        None
+    } else {
+        Some(v)
+    }
+}
+
+fn string<'a, C: Context<'a>>(cx: &mut C, v: Handle<JsValue>) -> Option<String> {
+    let s = v.downcast::<JsString, _>(cx).ok()?;
+
+    Some(s.value(cx))
+}
+
+// Converts with `String(v)`, ignoring exceptions
+fn to_string<'a, C: Context<'a>>(cx: &mut C, v: Handle<JsValue>) -> Option<String> {
+    let s = cx.try_catch(|cx| v.to_string(cx)).ok()?;
+
+    Some(s.value(cx))
+}
@@ -0,0 +1,330 @@
+use ::serde::de::{
+    self, value::SeqDeserializer, DeserializeSeed, Error as _, IntoDeserializer, Visitor,
+};
+
+use super::Error;
+use crate::{
+    context::Context,
+    handle::Handle,
+    types::{
+        buffer::TypedArray, JsArray, JsArrayBuffer, JsBoolean, JsNull, JsNumber, JsObject,
+        JsString, JsTypedArray, JsUndefined, JsValue,
+    },
+};
+
+#[cfg(feature = "napi-6")]
+use crate::{object::Object, types::JsBigInt};
+
+// Largest integer that can be represented exactly by a JavaScript number
+const MAX_SAFE_INTEGER: f64 = 9_007_199_254_740_991.0;
+
+pub(super) struct Deserializer<'a, 'cx, C> {
+    cx: &'a mut C,
+    v: Handle<'cx, JsValue>,
+}
+
+impl<'a, 'cx, C: Context<'cx>> Deserializer<'a, 'cx, C> {
+    pub(super) fn new(cx: &'a mut C, v: Handle<'cx, JsValue>) -> Self {
+        Self { cx, v }
+    }
+
+    fn is_nullish(&mut self) -> bool {
+        self.v.is_a::<JsUndefined, _>(self.cx) || self.v.is_a::<JsNull, _>(self.cx)
+    }
+
+    // Copies the contents of an `ArrayBuffer` or `Uint8Array` (including `Buffer`)
+    fn bytes(&mut self) -> Option<Vec<u8>> {
+        if let Ok(buf) = self.v.downcast::<JsTypedArray<u8>, _>(self.cx) {
+            return Some(buf.as_slice(self.cx).to_vec());
+        }
+
+        if let Ok(buf) = self.v.downcast::<JsArrayBuffer, _>(self.cx) {
+            return Some(buf.as_slice(self.cx).to_vec());
+        }
+
+        None
+    }
+}
+
+// Visits the elements of a typed array as a sequence
+macro_rules! visit_typed_arrays {
+    ($deserializer:expr, $visitor:expr; $($t:ty),*) => {$(
+        if let Ok(arr) = $deserializer.v.downcast::<JsTypedArray<$t>, _>($deserializer.cx) {
+            let values = arr.as_slice($deserializer.cx).to_vec();
+
+            return $visitor.visit_seq(SeqDeserializer::new(values.into_iter()));
+        }
+    )*};
+}
+
+impl<'de, 'a, 'cx, C: Context<'cx>> de::Deserializer<'de> for Deserializer<'a, 'cx, C> {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(mut self, visitor: V) -> Result<V::Value, Error> {
+        if self.is_nullish() {
+            return visitor.visit_unit();
+        }
+
+        if let Some(bytes) = self.bytes() {
+            return visitor.visit_seq(SeqDeserializer::new(bytes.into_iter()));
+        }
+
+        visit_typed_arrays!(self, visitor; i8, i16, u16, i32, u32, f32, f64, i64, u64);
+
+        let cx = self.cx;
+        let v = self.v;
+
+        if let Ok(b) = v.downcast::<JsBoolean, _>(cx) {
+            return visitor.visit_bool(b.value(cx));
+        }
+
+        if let Ok(n) = v.downcast::<JsNumber, _>(cx) {
+            let n = n.value(cx);
+
+            // Integral numbers are visited as integers so that integer types
+            // (and `serde_json::Value`) can be deserialized
+            return if n.fract() == 0.0 && n.abs() <= MAX_SAFE_INTEGER {
+                if n < 0.0 {
+                    visitor.visit_i64(n as i64)
+                } else {
+                    visitor.visit_u64(n as u64)
+                }
+            } else {
+                visitor.visit_f64(n)
+            };
+        }
+
+        if let Ok(s) = v.downcast::<JsString, _>(cx) {
+            return visitor.visit_string(s.value(cx));
+        }
+
+        #[cfg(feature = "napi-6")]
+        if let Ok(n) = v.downcast::<JsBigInt, _>(cx) {
+            if let Ok(n) = n.to_u64(cx) {
+                return visitor.visit_u64(n);
+            }
+
+            if let Ok(n) = n.to_i64(cx) {
+                return visitor.visit_i64(n);
+            }
+
+            if let Ok(n) = n.to_u128(cx) {
+                return visitor.visit_u128(n);
+            }
+
+            if let Ok(n) = n.to_i128(cx) {
+                return visitor.visit_i128(n);
+            }
+
+            return Err(Error::custom("bigint out of range of i128 and u128"));
+        }
+
+        if let Ok(arr) = v.downcast::<JsArray, _>(cx) {
+            let values = arr.to_vec(cx)?.into_iter();
+
+            return visitor.visit_seq(SeqAccess { cx, values });
+        }
+
+        if let Ok(obj) = v.downcast::<JsObject, _>(cx) {
+            #[cfg(feature = "napi-6")]
+            {
+                let keys = obj.get_own_property_names(cx)?.to_vec(cx)?;
+
+                return visitor.visit_map(MapAccess {
+                    cx,
+                    obj,
+                    keys: keys.into_iter(),
+                    value: None,
+                });
+            }
+
+            #[cfg(not(feature = "napi-6"))]
+            {
+                let _ = obj;
+
+                return Err(Error::custom("deserializing objects requires `napi-6`"));
+            }
+        }
+
+        Err(Error::custom("unsupported JavaScript value"))
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(mut self, visitor: V) -> Result<V::Value, Error> {
+        if self.is_nullish() {
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_bytes<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.deserialize_byte_buf(visitor)
+    }
+
+    fn deserialize_byte_buf<V: Visitor<'de>>(mut self, visitor: V) -> Result<V::Value, Error> {
+        match self.bytes() {
+            Some(bytes) => visitor.visit_byte_buf(bytes),
+            None => self.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        let cx = self.cx;
+        let v = self.v;
+
+        // Unit variants are represented by their name
+        if let Ok(s) = v.downcast::<JsString, _>(cx) {
+            return visitor.visit_enum(IntoDeserializer::<Error>::into_deserializer(s.value(cx)));
+        }
+
+        // Other variants are represented by an object with a single property
+        #[cfg(feature = "napi-6")]
+        if let Ok(obj) = v.downcast::<JsObject, _>(cx) {
+            let keys = obj.get_own_property_names(cx)?.to_vec(cx)?;
+
+            if let [key] = keys.as_slice() {
+                let key = key.downcast_or_throw::<JsString, _>(cx)?;
+                let value = obj.get_value(cx, key)?;
+                let variant = key.value(cx);
+
+                return visitor.visit_enum(EnumAccess { cx, variant, value });
+            }
+        }
+
+        Err(Error::custom(
+            "expected a string or an object with a single property",
+        ))
+    }
+
+    ::serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        unit unit_struct seq tuple tuple_struct map struct identifier ignored_any
+    }
+}
+
+struct SeqAccess<'a, 'cx, C> {
+    cx: &'a mut C,
+    values: std::vec::IntoIter<Handle<'cx, JsValue>>,
+}
+
+impl<'de, 'a, 'cx, C: Context<'cx>> de::SeqAccess<'de> for SeqAccess<'a, 'cx, C> {
+    type Error = Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, Error> {
+        match self.values.next() {
+            Some(v) => seed.deserialize(Deserializer::new(self.cx, v)).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.values.len())
+    }
+}
+
+#[cfg(feature = "napi-6")]
+struct MapAccess<'a, 'cx, C> {
+    cx: &'a mut C,
+    obj: Handle<'cx, JsObject>,
+    keys: std::vec::IntoIter<Handle<'cx, JsValue>>,
+    // Value of the last key visited
+    value: Option<Handle<'cx, JsValue>>,
+}
+
+#[cfg(feature = "napi-6")]
+impl<'de, 'a, 'cx, C: Context<'cx>> de::MapAccess<'de> for MapAccess<'a, 'cx, C> {
+    type Error = Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Error> {
+        let key = match self.keys.next() {
+            Some(key) => key.downcast_or_throw::<JsString, _>(self.cx)?,
+            None => return Ok(None),
+        };
+
+        self.value = Some(self.obj.get_value(self.cx, key)?);
+
+        seed.deserialize(IntoDeserializer::<Error>::into_deserializer(
+            key.value(self.cx),
+        ))
+        .map(Some)
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, Error> {
+        let v = self
+            .value
+            .take()
+            .ok_or_else(|| Error::custom("next_value_seed called before next_key_seed"))?;
+
+        seed.deserialize(Deserializer::new(self.cx, v))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.keys.len())
+    }
+}
+
+#[cfg(feature = "napi-6")]
+struct EnumAccess<'a, 'cx, C> {
+    cx: &'a mut C,
+    variant: String,
+    value: Handle<'cx, JsValue>,
+}
+
+#[cfg(feature = "napi-6")]
+impl<'de, 'a, 'cx, C: Context<'cx>> de::EnumAccess<'de> for EnumAccess<'a, 'cx, C> {
+    type Error = Error;
+    type Variant = Deserializer<'a, 'cx, C>;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(
+        self,
+        seed: V,
+    ) -> Result<(V::Value, Self::Variant), Error> {
+        let variant =
+            seed.deserialize(IntoDeserializer::<Error>::into_deserializer(self.variant))?;
+
+        Ok((variant, Deserializer::new(self.cx, self.value)))
+    }
+}
+
+impl<'de, 'a, 'cx, C: Context<'cx>> de::VariantAccess<'de> for Deserializer<'a, 'cx, C> {
+    type Error = Error;
+
+    fn unit_variant(self) -> Result<(), Error> {
+        de::Deserialize::deserialize(self)
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(self, seed: T) -> Result<T::Value, Error> {
+        seed.deserialize(self)
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, _len: usize, visitor: V) -> Result<V::Value, Error> {
+        de::Deserializer::deserialize_seq(self, visitor)
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        de::Deserializer::deserialize_map(self, visitor)
+    }
+}
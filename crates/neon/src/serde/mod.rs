@@ -0,0 +1,130 @@
+//! Convert values between Rust and JavaScript with [serde](https://serde.rs).
+//!
+//! [`to_value`] serializes a Rust value directly to a JavaScript value and
+//! [`from_value`] deserializes a Rust value directly from a JavaScript value.
+//! Values are walked with the Node-API; they are never encoded as JSON.
+//!
+//! ```
+//! # use neon::prelude::*;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Point {
+//!     x: f64,
+//!     y: f64,
+//! }
+//!
+//! fn flip(mut cx: FunctionContext) -> JsResult<JsValue> {
+//!     let point = cx.argument::<JsValue>(0)?;
+//!     let Point { x, y } = neon::serde::from_value(&mut cx, point)?;
+//!
+//!     neon::serde::to_value(&mut cx, &Point { x: y, y: x })
+//! }
+//! ```
+//!
+//! ## Data model
+//!
+//! | Rust (serde)                      | JavaScript                          |
+//! |-----------------------------------|-------------------------------------|
+//! | `bool`                            | `boolean`                           |
+//! | integers, `f32`, `f64`            | `number`                            |
+//! | integers beyond ±2<sup>53</sup>   | `bigint` (`napi-6`)                 |
+//! | `char`, `String`                  | `string`                            |
+//! | bytes (e.g., `serde_bytes`)       | `Buffer`                            |
+//! | `None`, `()`, unit structs        | `null`                              |
+//! | sequences and tuples              | `Array`                             |
+//! | maps and structs                  | `Object`                            |
+//! | enums                             | `"Variant"` or `{ Variant: value }` |
+//!
+//! When deserializing, `undefined` is treated as `null` and typed arrays,
+//! `ArrayBuffer` and `Buffer` may be deserialized either as bytes or as a
+//! sequence of numbers. Enums use the same externally tagged representation
+//! as `serde_json`, so a `serde_json::Value` round-trips through JavaScript
+//! without change, except that JavaScript does not distinguish `1.0` from `1`.
+
+use std::{error, fmt};
+
+use crate::{
+    context::Context,
+    handle::Handle,
+    result::{JsResult, NeonResult, Throw},
+    types::JsValue,
+};
+
+mod de;
+mod ser;
+
+/// Serialize a Rust value to a JavaScript value.
+///
+/// Throws a `TypeError` if the value cannot be represented in JavaScript,
+/// for example, a map with keys that are not strings or numbers.
+pub fn to_value<'cx, C, T>(cx: &mut C, v: &T) -> JsResult<'cx, JsValue>
+where
+    C: Context<'cx>,
+    T: ::serde::Serialize + ?Sized,
+{
+    match v.serialize(ser::Serializer::new(cx)) {
+        Ok(v) => Ok(v),
+        Err(err) => err.throw(cx),
+    }
+}
+
+/// Deserialize a Rust value from a JavaScript value.
+///
+/// Throws a `TypeError` if the value is not of the shape expected by `T`.
+pub fn from_value<'cx, C, T>(cx: &mut C, v: Handle<'cx, JsValue>) -> NeonResult<T>
+where
+    C: Context<'cx>,
+    T: ::serde::de::DeserializeOwned,
+{
+    match T::deserialize(de::Deserializer::new(cx, v)) {
+        Ok(v) => Ok(v),
+        Err(err) => err.throw(cx),
+    }
+}
+
+#[derive(Debug)]
+// Errors are either a pending exception, propagated as-is, or a message
+// that is thrown as a `TypeError`
+enum Error {
+    Throw(Throw),
+    Custom(String),
+}
+
+impl Error {
+    fn throw<'cx, C: Context<'cx>, T>(self, cx: &mut C) -> NeonResult<T> {
+        match self {
+            Error::Throw(throw) => Err(throw),
+            Error::Custom(msg) => cx.throw_type_error(msg),
+        }
+    }
+}
+
+impl From<Throw> for Error {
+    fn from(throw: Throw) -> Self {
+        Error::Throw(throw)
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Throw(throw) => fmt::Display::fmt(throw, f),
+            Error::Custom(msg) => f.write_str(msg),
+        }
+    }
+}
+
+impl error::Error for Error {}
+
+impl ::serde::ser::Error for Error {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Error::Custom(msg.to_string())
+    }
+}
+
+impl ::serde::de::Error for Error {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Error::Custom(msg.to_string())
+    }
+}
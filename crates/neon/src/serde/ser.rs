@@ -0,0 +1,451 @@
+use std::marker::PhantomData;
+
+use ::serde::ser::{self, Error as _, Serialize};
+
+use super::Error;
+use crate::{
+    context::Context,
+    handle::Handle,
+    object::{DescriptorKey, Object, PropertyDescriptor},
+    types::{JsArray, JsBuffer, JsNumber, JsObject, JsString, JsValue, Value},
+};
+
+#[cfg(feature = "napi-6")]
+use crate::types::JsBigInt;
+
+// Largest integer that can be represented exactly by a JavaScript number
+const MAX_SAFE_INTEGER: u64 = 9_007_199_254_740_991;
+
+pub(super) struct Serializer<'a, 'cx, C> {
+    cx: &'a mut C,
+    _lifetime: PhantomData<&'cx ()>,
+}
+
+impl<'a, 'cx, C: Context<'cx>> Serializer<'a, 'cx, C> {
+    pub(super) fn new(cx: &'a mut C) -> Self {
+        Self {
+            cx,
+            _lifetime: PhantomData,
+        }
+    }
+}
+
+fn upcast<V: Value>(v: Handle<V>) -> Result<Handle<JsValue>, Error> {
+    Ok(v.upcast())
+}
+
+// Wraps a value in an object with a single property naming the variant
+fn variant<'cx, C: Context<'cx>>(
+    cx: &mut C,
+    variant: &'static str,
+    v: Handle<'cx, JsValue>,
+) -> Result<Handle<'cx, JsValue>, Error> {
+    let obj = cx.empty_object();
+    let descriptor = PropertyDescriptor::own_value(cx, variant, v);
+
+    obj.define_property(cx, descriptor)?;
+    upcast(obj)
+}
+
+impl<'a, 'cx, C: Context<'cx>> ser::Serializer for Serializer<'a, 'cx, C> {
+    type Ok = Handle<'cx, JsValue>;
+    type Error = Error;
+
+    type SerializeSeq = ArraySerializer<'a, 'cx, C>;
+    type SerializeTuple = ArraySerializer<'a, 'cx, C>;
+    type SerializeTupleStruct = ArraySerializer<'a, 'cx, C>;
+    type SerializeTupleVariant = ArraySerializer<'a, 'cx, C>;
+    type SerializeMap = ObjectSerializer<'a, 'cx, C>;
+    type SerializeStruct = ObjectSerializer<'a, 'cx, C>;
+    type SerializeStructVariant = ObjectSerializer<'a, 'cx, C>;
+
+    fn serialize_bool(self, v: bool) -> Result<Self::Ok, Self::Error> {
+        upcast(self.cx.boolean(v))
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<Self::Ok, Self::Error> {
+        upcast(self.cx.number(v))
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<Self::Ok, Self::Error> {
+        upcast(self.cx.number(v))
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<Self::Ok, Self::Error> {
+        upcast(self.cx.number(v))
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<Self::Ok, Self::Error> {
+        if v.unsigned_abs() <= MAX_SAFE_INTEGER {
+            return upcast(self.cx.number(v as f64));
+        }
+
+        self.serialize_i128(v.into())
+    }
+
+    fn serialize_i128(self, v: i128) -> Result<Self::Ok, Self::Error> {
+        #[cfg(feature = "napi-6")]
+        {
+            upcast(JsBigInt::from_i128(self.cx, v))
+        }
+
+        #[cfg(not(feature = "napi-6"))]
+        {
+            Err(Error::custom(format!(
+                "{} cannot be represented exactly as a number",
+                v
+            )))
+        }
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<Self::Ok, Self::Error> {
+        upcast(self.cx.number(v))
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<Self::Ok, Self::Error> {
+        upcast(self.cx.number(v))
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<Self::Ok, Self::Error> {
+        upcast(self.cx.number(v))
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<Self::Ok, Self::Error> {
+        if v <= MAX_SAFE_INTEGER {
+            return upcast(self.cx.number(v as f64));
+        }
+
+        self.serialize_u128(v.into())
+    }
+
+    fn serialize_u128(self, v: u128) -> Result<Self::Ok, Self::Error> {
+        #[cfg(feature = "napi-6")]
+        {
+            upcast(JsBigInt::from_u128(self.cx, v))
+        }
+
+        #[cfg(not(feature = "napi-6"))]
+        {
+            Err(Error::custom(format!(
+                "{} cannot be represented exactly as a number",
+                v
+            )))
+        }
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<Self::Ok, Self::Error> {
+        upcast(self.cx.number(v))
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<Self::Ok, Self::Error> {
+        upcast(self.cx.number(v))
+    }
+
+    fn serialize_char(self, v: char) -> Result<Self::Ok, Self::Error> {
+        let mut buf = [0; 4];
+
+        self.serialize_str(v.encode_utf8(&mut buf))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<Self::Ok, Self::Error> {
+        upcast(self.cx.string(v))
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<Self::Ok, Self::Error> {
+        upcast(JsBuffer::from_slice(self.cx, v)?)
+    }
+
+    fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
+        upcast(self.cx.null())
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<Self::Ok, Self::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
+        upcast(self.cx.null())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Self::Ok, Self::Error> {
+        self.serialize_unit()
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.serialize_str(variant)
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        let v = value.serialize(Serializer::new(&mut *self.cx))?;
+
+        self::variant(self.cx, variant, v)
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        Ok(ArraySerializer::new(self.cx, len.unwrap_or(0), None))
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        Ok(ArraySerializer::new(self.cx, len, None))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleStruct, Self::Error> {
+        Ok(ArraySerializer::new(self.cx, len, None))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+        Ok(ArraySerializer::new(self.cx, len, Some(variant)))
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        Ok(ObjectSerializer::new(self.cx, None))
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStruct, Self::Error> {
+        Ok(ObjectSerializer::new(self.cx, None))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, Self::Error> {
+        Ok(ObjectSerializer::new(self.cx, Some(variant)))
+    }
+}
+
+pub(super) struct ArraySerializer<'a, 'cx, C> {
+    cx: &'a mut C,
+    arr: Handle<'cx, JsArray>,
+    len: u32,
+    // Name of the variant for tuple variants
+    variant: Option<&'static str>,
+}
+
+impl<'a, 'cx, C: Context<'cx>> ArraySerializer<'a, 'cx, C> {
+    fn new(cx: &'a mut C, len: usize, variant: Option<&'static str>) -> Self {
+        let arr = JsArray::new(cx, len);
+
+        Self {
+            cx,
+            arr,
+            len: 0,
+            variant,
+        }
+    }
+
+    fn push<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        let v = value.serialize(Serializer::new(&mut *self.cx))?;
+
+        self.arr.set(self.cx, self.len, v)?;
+        self.len += 1;
+
+        Ok(())
+    }
+
+    fn finish(self) -> Result<Handle<'cx, JsValue>, Error> {
+        match self.variant {
+            Some(name) => variant(self.cx, name, self.arr.upcast()),
+            None => upcast(self.arr),
+        }
+    }
+}
+
+impl<'a, 'cx, C: Context<'cx>> ser::SerializeSeq for ArraySerializer<'a, 'cx, C> {
+    type Ok = Handle<'cx, JsValue>;
+    type Error = Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Error> {
+        self.finish()
+    }
+}
+
+impl<'a, 'cx, C: Context<'cx>> ser::SerializeTuple for ArraySerializer<'a, 'cx, C> {
+    type Ok = Handle<'cx, JsValue>;
+    type Error = Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Error> {
+        self.finish()
+    }
+}
+
+impl<'a, 'cx, C: Context<'cx>> ser::SerializeTupleStruct for ArraySerializer<'a, 'cx, C> {
+    type Ok = Handle<'cx, JsValue>;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Error> {
+        self.finish()
+    }
+}
+
+impl<'a, 'cx, C: Context<'cx>> ser::SerializeTupleVariant for ArraySerializer<'a, 'cx, C> {
+    type Ok = Handle<'cx, JsValue>;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Error> {
+        self.finish()
+    }
+}
+
+pub(super) struct ObjectSerializer<'a, 'cx, C> {
+    cx: &'a mut C,
+    obj: Handle<'cx, JsObject>,
+    // Key of a map entry waiting for its value
+    key: Option<Handle<'cx, JsString>>,
+    // Name of the variant for struct variants
+    variant: Option<&'static str>,
+}
+
+impl<'a, 'cx, C: Context<'cx>> ObjectSerializer<'a, 'cx, C> {
+    fn new(cx: &'a mut C, variant: Option<&'static str>) -> Self {
+        let obj = cx.empty_object();
+
+        Self {
+            cx,
+            obj,
+            key: None,
+            variant,
+        }
+    }
+
+    fn set<K, T>(&mut self, key: K, value: &T) -> Result<(), Error>
+    where
+        K: DescriptorKey,
+        T: Serialize + ?Sized,
+    {
+        let v = value.serialize(Serializer::new(&mut *self.cx))?;
+        let descriptor = PropertyDescriptor::own_value(self.cx, key, v);
+
+        // Defined instead of set, so that a `__proto__` key is an own property
+        self.obj.define_property(self.cx, descriptor)?;
+        Ok(())
+    }
+
+    fn finish(self) -> Result<Handle<'cx, JsValue>, Error> {
+        match self.variant {
+            Some(name) => variant(self.cx, name, self.obj.upcast()),
+            None => upcast(self.obj),
+        }
+    }
+}
+
+impl<'a, 'cx, C: Context<'cx>> ser::SerializeMap for ObjectSerializer<'a, 'cx, C> {
+    type Ok = Handle<'cx, JsValue>;
+    type Error = Error;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), Error> {
+        let key = key.serialize(Serializer::new(&mut *self.cx))?;
+
+        // Like JSON, numeric keys are converted to strings
+        let key = if let Ok(key) = key.downcast::<JsString, _>(self.cx) {
+            key
+        } else if let Ok(key) = key.downcast::<JsNumber, _>(self.cx) {
+            let key = key.value(self.cx).to_string();
+
+            self.cx.string(key)
+        } else {
+            return Err(Error::custom("map keys must be strings or numbers"));
+        };
+
+        self.key = Some(key);
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        let key = self
+            .key
+            .take()
+            .ok_or_else(|| Error::custom("serialize_value called before serialize_key"))?;
+
+        self.set(key, value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Error> {
+        self.finish()
+    }
+}
+
+impl<'a, 'cx, C: Context<'cx>> ser::SerializeStruct for ObjectSerializer<'a, 'cx, C> {
+    type Ok = Handle<'cx, JsValue>;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        self.set(key, value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Error> {
+        self.finish()
+    }
+}
+
+impl<'a, 'cx, C: Context<'cx>> ser::SerializeStructVariant for ObjectSerializer<'a, 'cx, C> {
+    type Ok = Handle<'cx, JsValue>;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        self.set(key, value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Error> {
+        self.finish()
+    }
+}
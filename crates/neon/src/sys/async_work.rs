@@ -20,7 +20,6 @@ use super::{
 
 const BOUNDARY: FailureBoundary = FailureBoundary {
     both: "A panic and exception occurred while executing a `neon::event::TaskBuilder` task",
-    exception: "An exception occurred while executing a `neon::event::TaskBuilder` task",
     panic: "A panic occurred while executing a `neon::event::TaskBuilder` task",
 };
 
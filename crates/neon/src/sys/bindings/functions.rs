@@ -88,6 +88,8 @@ mod napi1 {
                 result: *mut Value,
             ) -> Status;
 
+            fn create_symbol(env: Env, description: Value, result: *mut Value) -> Status;
+
             fn create_arraybuffer(
                 env: Env,
                 byte_length: usize,
@@ -121,6 +123,25 @@ mod napi1 {
                 offset: *mut usize,
             ) -> Status;
 
+            fn create_dataview(
+                env: Env,
+                length: usize,
+                arraybuffer: Value,
+                byte_offset: usize,
+                result: *mut Value,
+            ) -> Status;
+
+            fn is_dataview(env: Env, value: Value, result: *mut bool) -> Status;
+
+            fn get_dataview_info(
+                env: Env,
+                dataview: Value,
+                bytelength: *mut usize,
+                data: *mut *mut c_void,
+                arraybuffer: *mut Value,
+                byte_offset: *mut usize,
+            ) -> Status;
+
             fn create_buffer(
                 env: Env,
                 length: usize,
@@ -210,6 +231,12 @@ mod napi1 {
 
             fn strict_equals(env: Env, lhs: Value, rhs: Value, result: *mut bool) -> Status;
 
+            fn adjust_external_memory(
+                env: Env,
+                change_in_bytes: i64,
+                adjusted_value: *mut i64,
+            ) -> Status;
+
             #[cfg(any(feature = "sys", feature = "external-buffers"))]
             fn create_external_arraybuffer(
                 env: Env,
@@ -248,6 +275,40 @@ mod napi1 {
             fn resolve_deferred(env: Env, deferred: Deferred, resolution: Value) -> Status;
             fn reject_deferred(env: Env, deferred: Deferred, rejection: Value) -> Status;
 
+            fn define_class(
+                env: Env,
+                utf8name: *const c_char,
+                length: usize,
+                constructor: Callback,
+                data: *mut c_void,
+                property_count: usize,
+                properties: *const PropertyDescriptor,
+                result: *mut Value,
+            ) -> Status;
+
+            fn define_properties(
+                env: Env,
+                object: Value,
+                property_count: usize,
+                properties: *const PropertyDescriptor,
+            ) -> Status;
+
+            fn wrap(
+                env: Env,
+                js_object: Value,
+                native_object: *mut c_void,
+                finalize_cb: Finalize,
+                finalize_hint: *mut c_void,
+                result: *mut Ref,
+            ) -> Status;
+
+            fn unwrap(env: Env, js_object: Value, result: *mut *mut c_void) -> Status;
+
+            fn remove_wrap(env: Env, js_object: Value, result: *mut *mut c_void) -> Status;
+
+            #[cfg(any(feature = "sys", feature = "napi-3"))]
+            fn get_node_version(env: Env, result: *mut *const NodeVersion) -> Status;
+
             fn fatal_error(
                 location: *const c_char,
                 location_len: usize,
@@ -258,6 +319,23 @@ mod napi1 {
     );
 }
 
+#[cfg(feature = "napi-3")]
+// Cleanup hooks are only used by instance data, which requires `napi-6`
+#[cfg_attr(not(feature = "napi-6"), allow(dead_code))]
+mod napi3 {
+    use super::super::types::*;
+    use std::os::raw::c_void;
+
+    generate!(
+        #[cfg_attr(docsrs, doc(cfg(feature = "napi-3")))]
+        extern "C" {
+            fn add_env_cleanup_hook(env: Env, fun: CleanupHook, arg: *mut c_void) -> Status;
+
+            fn fatal_exception(env: Env, err: Value) -> Status;
+        }
+    );
+}
+
 #[cfg(feature = "napi-4")]
 mod napi4 {
     use super::super::types::*;
@@ -390,6 +468,7 @@ mod napi6 {
 #[cfg(feature = "napi-8")]
 mod napi8 {
     use super::super::types::*;
+    use std::os::raw::c_void;
 
     generate!(
         #[cfg_attr(docsrs, doc(cfg(feature = "napi-8")))]
@@ -403,11 +482,41 @@ mod napi8 {
                 tag: *const TypeTag,
                 result: *mut bool,
             ) -> Status;
+
+            fn add_async_cleanup_hook(
+                env: Env,
+                hook: AsyncCleanupHook,
+                arg: *mut c_void,
+                remove_handle: *mut AsyncCleanupHookHandle,
+            ) -> Status;
+
+            fn remove_async_cleanup_hook(remove_handle: AsyncCleanupHookHandle) -> Status;
+        }
+    );
+}
+
+#[cfg(feature = "napi-9")]
+mod napi9 {
+    use super::super::types::*;
+    use std::os::raw::c_char;
+
+    generate!(
+        #[cfg_attr(docsrs, doc(cfg(feature = "napi-9")))]
+        extern "C" {
+            fn symbol_for(
+                env: Env,
+                utf8description: *const c_char,
+                length: usize,
+                result: *mut Value,
+            ) -> Status;
         }
     );
 }
 
 pub use napi1::*;
+#[cfg(feature = "napi-3")]
+#[cfg_attr(not(feature = "napi-6"), allow(unused_imports))]
+pub use napi3::*;
 #[cfg(feature = "napi-4")]
 pub use napi4::*;
 #[cfg(feature = "napi-5")]
@@ -416,6 +525,8 @@ pub use napi5::*;
 pub use napi6::*;
 #[cfg(feature = "napi-8")]
 pub use napi8::*;
+#[cfg(feature = "napi-9")]
+pub use napi9::*;
 
 use super::{Env, Status};
 
@@ -441,6 +552,9 @@ pub(crate) unsafe fn load(env: Env) -> Result<(), libloading::Error> {
 
     napi1::load(&host, version, 1);
 
+    #[cfg(feature = "napi-3")]
+    napi3::load(&host, version, 3);
+
     #[cfg(feature = "napi-4")]
     napi4::load(&host, version, 4);
 
@@ -453,5 +567,8 @@ pub(crate) unsafe fn load(env: Env) -> Result<(), libloading::Error> {
     #[cfg(feature = "napi-8")]
     napi8::load(&host, version, 8);
 
+    #[cfg(feature = "napi-9")]
+    napi9::load(&host, version, 9);
+
     Ok(())
 }
@@ -21,6 +21,10 @@ macro_rules! napi_name {
     (typeof_value) => {
         "napi_typeof"
     };
+    // Newer functions are prefixed with `node_api_` instead of `napi_`
+    (symbol_for) => {
+        "node_api_symbol_for"
+    };
     // Default case: Stringify the identifier and prefix with `napi_`
     ($name:ident) => {
         concat!("napi_", stringify!($name))
@@ -88,6 +88,30 @@ pub type Callback = Option<unsafe extern "C" fn(env: Env, info: CallbackInfo) ->
 pub type Finalize =
     Option<unsafe extern "C" fn(env: Env, finalize_data: *mut c_void, finalize_hint: *mut c_void)>;
 
+#[cfg_attr(docsrs, doc(cfg(feature = "napi-3")))]
+#[cfg(feature = "napi-3")]
+/// Cleanup hook registered with [`napi_add_env_cleanup_hook`](https://nodejs.org/api/n-api.html#napi_add_env_cleanup_hook)
+pub type CleanupHook = Option<unsafe extern "C" fn(arg: *mut c_void)>;
+
+#[cfg(feature = "napi-8")]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+#[doc(hidden)]
+pub struct AsyncCleanupHookHandle__ {
+    _unused: [u8; 0],
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "napi-8")))]
+#[cfg(feature = "napi-8")]
+/// [`napi_async_cleanup_hook_handle`](https://nodejs.org/api/n-api.html#napi_async_cleanup_hook_handle)
+pub type AsyncCleanupHookHandle = *mut AsyncCleanupHookHandle__;
+
+#[cfg_attr(docsrs, doc(cfg(feature = "napi-8")))]
+#[cfg(feature = "napi-8")]
+/// [`napi_async_cleanup_hook`](https://nodejs.org/api/n-api.html#napi_async_cleanup_hook)
+pub type AsyncCleanupHook =
+    Option<unsafe extern "C" fn(handle: AsyncCleanupHookHandle, data: *mut c_void)>;
+
 #[cfg_attr(docsrs, doc(cfg(feature = "napi-4")))]
 #[cfg(feature = "napi-4")]
 /// [`napi_threadsafe_function_call_js`](https://nodejs.org/api/n-api.html#napi_threadsafe_function_call_js)
@@ -257,6 +281,62 @@ impl std::ops::BitAndAssign for KeyFilter {
     }
 }
 
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(docsrs, doc(cfg(feature = "napi-1")))]
+/// [`napi_property_attributes`](https://nodejs.org/api/n-api.html#napi_property_attributes)
+pub struct PropertyAttributes(pub ::std::os::raw::c_uint);
+
+#[allow(dead_code)]
+impl PropertyAttributes {
+    pub const DEFAULT: PropertyAttributes = PropertyAttributes(0);
+    pub const WRITABLE: PropertyAttributes = PropertyAttributes(1);
+    pub const ENUMERABLE: PropertyAttributes = PropertyAttributes(2);
+    pub const CONFIGURABLE: PropertyAttributes = PropertyAttributes(4);
+    pub const STATIC: PropertyAttributes = PropertyAttributes(1024);
+}
+
+impl std::ops::BitOr<PropertyAttributes> for PropertyAttributes {
+    type Output = Self;
+    #[inline]
+    fn bitor(self, other: Self) -> Self {
+        PropertyAttributes(self.0 | other.0)
+    }
+}
+
+impl std::ops::BitOrAssign for PropertyAttributes {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: PropertyAttributes) {
+        self.0 |= rhs.0;
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(docsrs, doc(cfg(feature = "napi-1")))]
+/// [`napi_property_descriptor`](https://nodejs.org/api/n-api.html#napi_property_descriptor)
+pub struct PropertyDescriptor {
+    pub utf8name: *const ::std::os::raw::c_char,
+    pub name: Value,
+    pub method: Callback,
+    pub getter: Callback,
+    pub setter: Callback,
+    pub value: Value,
+    pub attributes: PropertyAttributes,
+    pub data: *mut c_void,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(docsrs, doc(cfg(feature = "napi-1")))]
+/// [`napi_node_version`](https://nodejs.org/api/n-api.html#napi_node_version)
+pub struct NodeVersion {
+    pub major: u32,
+    pub minor: u32,
+    pub patch: u32,
+    pub release: *const ::std::os::raw::c_char,
+}
+
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 #[doc(hidden)]
@@ -0,0 +1,207 @@
+//! Facilities for defining JavaScript classes.
+
+use std::{mem::MaybeUninit, os::raw::c_void, ptr};
+
+use super::{
+    bindings as napi,
+    raw::{Env, Local},
+};
+
+/// Native implementation of a constructor, method or accessor
+pub type Callback = Box<dyn Fn(Env, napi::CallbackInfo) -> Local + 'static>;
+
+pub enum PropertyValue {
+    Method(Callback),
+    Accessor {
+        getter: Option<Callback>,
+        setter: Option<Callback>,
+    },
+}
+
+/// A property defined on the class prototype or, with
+/// `napi::PropertyAttributes::STATIC`, on the constructor
+pub struct Property {
+    pub name: String,
+    pub value: PropertyValue,
+    pub attributes: napi::PropertyAttributes,
+}
+
+struct Accessor {
+    getter: Option<Callback>,
+    setter: Option<Callback>,
+}
+
+// Owns the closures referenced by the `data` fields of a class. Addresses are
+// stable because the vectors are not modified after the class is defined.
+struct ClassData {
+    _constructor: Box<Callback>,
+    _methods: Vec<Callback>,
+    _accessors: Vec<Accessor>,
+}
+
+enum Data {
+    Method(usize),
+    Accessor(usize),
+}
+
+pub unsafe fn define(
+    env: Env,
+    name: &str,
+    constructor: Callback,
+    properties: Vec<Property>,
+) -> Result<Local, napi::Status> {
+    let constructor = Box::new(constructor);
+    let mut methods = Vec::new();
+    let mut accessors = Vec::new();
+    let mut descriptors = Vec::with_capacity(properties.len());
+
+    for property in properties {
+        let descriptor = napi::PropertyDescriptor {
+            utf8name: ptr::null(),
+            name: super::string(env, &property.name),
+            method: None,
+            getter: None,
+            setter: None,
+            value: ptr::null_mut(),
+            attributes: property.attributes,
+            data: ptr::null_mut(),
+        };
+
+        let data = match property.value {
+            PropertyValue::Method(method) => {
+                methods.push(method);
+                Data::Method(methods.len() - 1)
+            }
+            PropertyValue::Accessor { getter, setter } => {
+                accessors.push(Accessor { getter, setter });
+                Data::Accessor(accessors.len() - 1)
+            }
+        };
+
+        descriptors.push((descriptor, data));
+    }
+
+    // Data pointers are assigned after all closures have been collected
+    let descriptors = descriptors
+        .into_iter()
+        .map(|(mut descriptor, data)| {
+            match data {
+                Data::Method(i) => {
+                    descriptor.method = Some(call_method);
+                    descriptor.data = &methods[i] as *const Callback as *mut c_void;
+                }
+                Data::Accessor(i) => {
+                    let accessor = &accessors[i];
+
+                    if accessor.getter.is_some() {
+                        descriptor.getter = Some(call_getter);
+                    }
+
+                    if accessor.setter.is_some() {
+                        descriptor.setter = Some(call_setter);
+                    }
+
+                    descriptor.data = accessor as *const Accessor as *mut c_void;
+                }
+            }
+
+            descriptor
+        })
+        .collect::<Vec<_>>();
+
+    let mut out = MaybeUninit::uninit();
+    let status = napi::define_class(
+        env,
+        name.as_ptr().cast(),
+        name.len(),
+        Some(call_method),
+        &*constructor as *const Callback as *mut c_void,
+        descriptors.len(),
+        descriptors.as_ptr(),
+        out.as_mut_ptr(),
+    );
+
+    if status == napi::Status::PendingException {
+        return Err(status);
+    }
+
+    assert_eq!(status, napi::Status::Ok);
+
+    let out = out.assume_init();
+    let data = Box::into_raw(Box::new(ClassData {
+        _constructor: constructor,
+        _methods: methods,
+        _accessors: accessors,
+    }));
+
+    #[cfg(feature = "napi-5")]
+    {
+        unsafe extern "C" fn drop_class(
+            _env: Env,
+            _finalize_data: *mut c_void,
+            finalize_hint: *mut c_void,
+        ) {
+            drop(Box::from_raw(finalize_hint.cast::<ClassData>()));
+        }
+
+        let status = napi::add_finalizer(
+            env,
+            out,
+            ptr::null_mut(),
+            Some(drop_class),
+            data.cast(),
+            ptr::null_mut(),
+        );
+
+        // If adding the finalizer fails the closures will leak, but it would
+        // be unsafe to drop them because there's no guarantee V8 won't use the
+        // pointers.
+        assert_eq!(status, napi::Status::Ok);
+    }
+
+    #[cfg(not(feature = "napi-5"))]
+    let _ = data;
+
+    Ok(out)
+}
+
+unsafe fn data<T>(env: Env, info: napi::CallbackInfo) -> &'static T {
+    let mut data = MaybeUninit::uninit();
+    let status = napi::get_cb_info(
+        env,
+        info,
+        ptr::null_mut(),
+        ptr::null_mut(),
+        ptr::null_mut(),
+        data.as_mut_ptr(),
+    );
+
+    assert_eq!(status, napi::Status::Ok);
+
+    &*data.assume_init().cast::<T>()
+}
+
+// C ABI compatible function for invoking a boxed constructor or method
+unsafe extern "C" fn call_method(env: Env, info: napi::CallbackInfo) -> Local {
+    let callback = data::<Callback>(env, info);
+
+    callback(env, info)
+}
+
+unsafe extern "C" fn call_getter(env: Env, info: napi::CallbackInfo) -> Local {
+    let accessor = data::<Accessor>(env, info);
+
+    match &accessor.getter {
+        Some(getter) => getter(env, info),
+        None => ptr::null_mut(),
+    }
+}
+
+unsafe extern "C" fn call_setter(env: Env, info: napi::CallbackInfo) -> Local {
+    let accessor = data::<Accessor>(env, info);
+
+    match &accessor.setter {
+        Some(setter) => setter(env, info),
+        None => ptr::null_mut(),
+    }
+}
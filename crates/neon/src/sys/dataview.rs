@@ -0,0 +1,57 @@
+use std::{ffi::c_void, mem::MaybeUninit};
+
+use super::{
+    bindings as napi,
+    raw::{Env, Local},
+};
+
+#[derive(Debug)]
+/// Information describing a JavaScript [`DataView`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView)
+pub struct DataViewInfo {
+    pub size: usize,
+    pub data: *mut c_void,
+    pub buf: Local,
+    pub offset: usize,
+}
+
+/// Get [information](DataViewInfo) describing a JavaScript `DataView`
+///
+/// # Safety
+/// * `env` must be valid `napi_env` for the current scope
+/// * `value` must be a handle pointing to a `DataView`
+pub unsafe fn info(env: Env, value: Local) -> DataViewInfo {
+    let mut info = MaybeUninit::<DataViewInfo>::zeroed();
+    let ptr = info.as_mut_ptr();
+
+    assert_eq!(
+        napi::get_dataview_info(
+            env,
+            value,
+            &mut (*ptr).size,
+            &mut (*ptr).data,
+            &mut (*ptr).buf,
+            &mut (*ptr).offset,
+        ),
+        napi::Status::Ok,
+    );
+
+    info.assume_init()
+}
+
+pub unsafe fn new(
+    env: Env,
+    buffer: Local,
+    offset: usize,
+    size: usize,
+) -> Result<Local, napi::Status> {
+    let mut view = MaybeUninit::uninit();
+    let status = napi::create_dataview(env, size, buffer, offset, view.as_mut_ptr());
+
+    if status == napi::Status::PendingException {
+        return Err(status);
+    }
+
+    assert_eq!(status, napi::Status::Ok);
+
+    Ok(view.assume_init())
+}
@@ -1,4 +1,4 @@
-use std::mem::MaybeUninit;
+use std::{any::Any, mem::MaybeUninit};
 
 use super::{
     bindings as napi,
@@ -6,6 +6,20 @@ use super::{
     raw::{Env, Local},
 };
 
+/// Contents of the `napi_external` of a `JsBox`. Also created by `no_panic`, which
+/// creates a `JsBox` from a panic.
+pub struct BoxData {
+    pub value: Box<dyn Any + 'static>,
+    /// Size reported to the garbage collector by `JsBox::new_with_size`
+    pub size: i64,
+}
+
+impl BoxData {
+    pub fn new(value: Box<dyn Any + 'static>) -> Self {
+        Self { value, size: 0 }
+    }
+}
+
 /// `finalize_external` is invoked immediately before a `napi_external` is garbage collected
 extern "C" fn finalize_external<T: 'static>(
     env: Env,
@@ -83,7 +97,10 @@ pub unsafe fn create<T: 'static>(env: Env, v: T, finalizer: fn(Env, T)) -> Local
 
     #[cfg(feature = "napi-8")]
     // Tag the object as coming from this module
-    super::tag::type_tag_object(env, external, &crate::MODULE_TAG);
+    assert_eq!(
+        super::tag::type_tag_object(env, external, &crate::MODULE_TAG),
+        Ok(())
+    );
 
     external
 }
@@ -10,7 +10,7 @@
 
 use std::{mem::MaybeUninit, os::raw::c_void, ptr};
 
-use super::{bindings as napi, raw::Env};
+use super::{bindings as napi, no_panic::FailureBoundary, raw::Env};
 
 /// # Safety
 /// `env` must point to a valid `napi_env` for this thread
@@ -44,3 +44,150 @@ pub unsafe fn get_instance_data<T: Send + 'static>(env: Env) -> *mut T {
 unsafe extern "C" fn drop_box<T>(_env: Env, data: *mut c_void, _hint: *mut c_void) {
     drop(Box::<T>::from_raw(data.cast()));
 }
+
+const BOUNDARY: FailureBoundary = FailureBoundary {
+    both: "A panic and exception occurred while executing a cleanup hook",
+    panic: "A panic occurred while executing a cleanup hook",
+};
+
+/// Registers `f` to be called on the JavaScript thread when the environment is torn
+/// down. Hooks are called in the reverse order they were added.
+///
+/// # Safety
+/// `env` must point to a valid `napi_env` for this thread
+pub unsafe fn add_cleanup_hook<F>(env: Env, f: F)
+where
+    F: FnOnce() + 'static,
+{
+    let data = Box::into_raw(Box::new(CleanupHook { env, f }));
+
+    assert_eq!(
+        napi::add_env_cleanup_hook(env, Some(call_cleanup_hook::<F>), data.cast()),
+        napi::Status::Ok,
+    );
+}
+
+struct CleanupHook<F> {
+    env: Env,
+    f: F,
+}
+
+unsafe extern "C" fn call_cleanup_hook<F: FnOnce()>(data: *mut c_void) {
+    let CleanupHook { env, f } = *Box::<CleanupHook<F>>::from_raw(data.cast());
+
+    BOUNDARY.catch_failure(env, None, move |_| {
+        f();
+        ptr::null_mut()
+    });
+}
+
+#[cfg(feature = "napi-8")]
+/// Registers `f` to be called on the libuv thread pool when the environment is torn
+/// down. Teardown of the environment waits for `f` to complete.
+///
+/// # Safety
+/// `env` must point to a valid `napi_env` for this thread
+pub unsafe fn add_async_cleanup_hook<F>(env: Env, f: F)
+where
+    F: FnOnce() + Send + 'static,
+{
+    let data = Box::into_raw(Box::new(AsyncCleanupHook {
+        env,
+        f: Some(f),
+        // Set by `add_async_cleanup_hook` and `create_async_work`
+        handle: ptr::null_mut(),
+        work: ptr::null_mut(),
+    }));
+
+    assert_eq!(
+        napi::add_async_cleanup_hook(
+            env,
+            Some(start_async_cleanup_hook::<F>),
+            data.cast(),
+            &mut (*data).handle,
+        ),
+        napi::Status::Ok,
+    );
+}
+
+#[cfg(feature = "napi-8")]
+struct AsyncCleanupHook<F> {
+    env: Env,
+    f: Option<F>,
+    handle: napi::AsyncCleanupHookHandle,
+    work: napi::AsyncWork,
+}
+
+#[cfg(feature = "napi-8")]
+// Called on the JavaScript thread when the environment is torn down. The event loop
+// keeps running until the hook is removed, so `f` is executed as async work.
+unsafe extern "C" fn start_async_cleanup_hook<F: FnOnce() + Send>(
+    _handle: napi::AsyncCleanupHookHandle,
+    data: *mut c_void,
+) {
+    let hook = &mut *data.cast::<AsyncCleanupHook<F>>();
+    let env = hook.env;
+    // Cleanup hooks are not called with an open handle scope
+    let scope = super::scope::HandleScope::new(env);
+
+    let status = napi::create_async_work(
+        env,
+        ptr::null_mut(),
+        super::string(env, "neon_async_cleanup_hook"),
+        Some(execute_async_cleanup_hook::<F>),
+        Some(complete_async_cleanup_hook::<F>),
+        data,
+        &mut hook.work,
+    );
+
+    drop(scope);
+
+    if status == napi::Status::Ok && napi::queue_async_work(env, hook.work) == napi::Status::Ok {
+        return;
+    }
+
+    // If the work could not be scheduled, block the JavaScript thread instead
+    if !hook.work.is_null() {
+        napi::delete_async_work(env, hook.work);
+    }
+
+    execute_async_cleanup_hook::<F>(env, data);
+    finish_async_cleanup_hook::<F>(data);
+}
+
+#[cfg(feature = "napi-8")]
+unsafe extern "C" fn execute_async_cleanup_hook<F: FnOnce() + Send>(_: Env, data: *mut c_void) {
+    let hook = &mut *data.cast::<AsyncCleanupHook<F>>();
+
+    // The environment cannot be used from the thread pool; a panic is always fatal
+    if let Some(f) = hook.f.take() {
+        BOUNDARY.catch_failure(ptr::null_mut(), None, move |_| {
+            f();
+            ptr::null_mut()
+        });
+    }
+}
+
+#[cfg(feature = "napi-8")]
+unsafe extern "C" fn complete_async_cleanup_hook<F: FnOnce() + Send>(
+    env: Env,
+    _status: napi::Status,
+    data: *mut c_void,
+) {
+    napi::delete_async_work(env, (*data.cast::<AsyncCleanupHook<F>>()).work);
+
+    // Cancelled work was never executed
+    execute_async_cleanup_hook::<F>(env, data);
+    finish_async_cleanup_hook::<F>(data);
+}
+
+#[cfg(feature = "napi-8")]
+// Removing the hook allows the environment to finish teardown
+unsafe fn finish_async_cleanup_hook<F>(data: *mut c_void) {
+    let hook = Box::<AsyncCleanupHook<F>>::from_raw(data.cast());
+
+    assert_eq!(
+        napi::remove_async_cleanup_hook(hook.handle),
+        napi::Status::Ok,
+    );
+}
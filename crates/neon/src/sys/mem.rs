@@ -11,3 +11,14 @@ pub unsafe fn strict_equals(env: Env, lhs: Local, rhs: Local) -> bool {
     );
     result
 }
+
+/// Adjusts the amount of memory kept alive by JavaScript objects that is reported
+/// to the garbage collector. Returns the adjusted amount.
+pub unsafe fn adjust_external_memory(env: Env, change_in_bytes: i64) -> i64 {
+    let mut result = 0;
+    assert_eq!(
+        napi::adjust_external_memory(env, change_in_bytes, &mut result as *mut _),
+        napi::Status::Ok
+    );
+    result
+}
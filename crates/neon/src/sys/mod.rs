@@ -76,7 +76,9 @@ pub(crate) mod arraybuffer;
 pub(crate) mod async_work;
 pub(crate) mod buffer;
 pub(crate) mod call;
+pub(crate) mod class;
 pub(crate) mod convert;
+pub(crate) mod dataview;
 pub(crate) mod error;
 pub(crate) mod external;
 pub(crate) mod fun;
@@ -91,6 +93,7 @@ pub(crate) mod scope;
 pub(crate) mod string;
 pub(crate) mod tag;
 pub(crate) mod typedarray;
+pub(crate) mod wrap;
 
 pub mod bindings;
 
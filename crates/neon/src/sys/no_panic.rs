@@ -20,6 +20,7 @@ use super::{
     bindings as napi,
     debug_send_wrapper::DebugSendWrapper,
     error::fatal_error,
+    external::BoxData,
     raw::{Env, Local},
 };
 
@@ -33,7 +34,7 @@ const UNKNOWN_PANIC_MESSAGE: &str = "Unknown panic";
 /// in order of ascending severity:
 ///
 /// 1. Reject a `Promise` if a `Deferred` was provided
-/// 2. Emit a fatal exception
+/// 2. Emit an uncaught exception
 /// 3. Abort the process with a message and location
 ///
 /// This process will be aborted if any step unrecoverably fails. For example,
@@ -41,7 +42,6 @@ const UNKNOWN_PANIC_MESSAGE: &str = "Unknown panic";
 /// emit a fatal exception.
 pub struct FailureBoundary {
     pub both: &'static str,
-    pub exception: &'static str,
     pub panic: &'static str,
 }
 
@@ -57,7 +57,7 @@ impl FailureBoundary {
 
         // Run the user supplied callback, catching panics
         // This is unwind safe because control is never yielded back to the caller
-        let panic = catch_unwind(AssertUnwindSafe(move || f(env)));
+        let panic = catch_unwind(AssertUnwindSafe(move || crate::panic::boundary(|| f(env))));
 
         // Unwrap the `Env`
         let env = if let Some(env) = env {
@@ -82,16 +82,15 @@ impl FailureBoundary {
             // Exception and a panic
             (Some(_), Err(_)) => self.both,
 
-            // Exception, but not a panic
+            // Exception, but not a panic; reject or emit it without wrapping
             (Some(err), Ok(_)) => {
-                // Reject the promise without wrapping
                 if let Some(deferred) = deferred {
                     reject_deferred(env, deferred, err);
-
-                    return;
+                } else {
+                    fatal_exception(env, err);
                 }
 
-                self.exception
+                return;
             }
 
             // Panic, but not an exception
@@ -136,10 +135,26 @@ fn can_call_into_js(env: Env) -> bool {
     !env.is_null() && unsafe { napi::throw(env, ptr::null_mut()) == napi::Status::InvalidArg }
 }
 
-// We cannot use `napi_fatal_exception` because of this bug; instead, cause an
-// unhandled rejection which has similar behavior on recent versions of Node.
+// Emits the error as an uncaught exception, handled by `process.on('uncaughtException')`
+// like an exception thrown from JavaScript.
+//
+// We cannot always use `napi_fatal_exception` because of this bug; on older versions
+// of Node, it may abort the process instead of emitting an uncaught exception.
 // https://github.com/nodejs/node/issues/33771
+//
+// It is used with Node-API 3 on Node 14 and later, which are not affected. Otherwise, cause
+// an unhandled rejection which has similar behavior on recent versions of Node.
+#[track_caller]
 unsafe fn fatal_exception(env: Env, error: Local) {
+    #[cfg(feature = "napi-3")]
+    if matches!(node_major_version(env), Some(major) if major >= 14) {
+        if napi::fatal_exception(env, error) != napi::Status::Ok {
+            fatal_error("Failed to emit an uncaught exception");
+        }
+
+        return;
+    }
+
     let mut deferred = MaybeUninit::uninit();
     let mut promise = MaybeUninit::uninit();
 
@@ -153,6 +168,17 @@ unsafe fn fatal_exception(env: Env, error: Local) {
     }
 }
 
+#[cfg(feature = "napi-3")]
+unsafe fn node_major_version(env: Env) -> Option<u32> {
+    let mut version = MaybeUninit::uninit();
+
+    if napi::get_node_version(env, version.as_mut_ptr()) != napi::Status::Ok {
+        return None;
+    }
+
+    Some((*version.assume_init()).major)
+}
+
 #[track_caller]
 unsafe fn create_error(
     env: Env,
@@ -222,7 +248,7 @@ unsafe fn error_from_message(env: Env, msg: &str) -> Local {
 #[track_caller]
 unsafe fn error_from_panic(env: Env, panic: Panic) -> Local {
     if let Some(msg) = panic_msg(&panic) {
-        error_from_message(env, msg)
+        crate::panic::create_error(env.into(), msg).unwrap_or_else(|| error_from_message(env, msg))
     } else {
         let error = error_from_message(env, UNKNOWN_PANIC_MESSAGE);
         let panic = external_from_panic(env, panic);
@@ -257,7 +283,7 @@ unsafe fn external_from_panic(env: Env, panic: Panic) -> Local {
     let mut result = MaybeUninit::uninit();
     let status = napi::create_external(
         env,
-        Box::into_raw(Box::new(DebugSendWrapper::new(panic))).cast(),
+        Box::into_raw(Box::new(DebugSendWrapper::new(BoxData::new(panic)))).cast(),
         Some(finalize_panic),
         ptr::null_mut(),
         result.as_mut_ptr(),
@@ -279,7 +305,7 @@ unsafe fn external_from_panic(env: Env, panic: Panic) -> Local {
 
 extern "C" fn finalize_panic(_env: Env, data: *mut c_void, _hint: *mut c_void) {
     unsafe {
-        drop(Box::from_raw(data.cast::<Panic>()));
+        drop(Box::from_raw(data.cast::<DebugSendWrapper<BoxData>>()));
     }
 }
 
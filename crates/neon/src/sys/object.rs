@@ -1,7 +1,8 @@
-use std::mem::MaybeUninit;
+use std::{mem::MaybeUninit, ptr};
 
 use super::{
     bindings as napi,
+    class::Callback,
     raw::{Env, Local},
 };
 
@@ -148,3 +149,164 @@ pub unsafe fn set(out: &mut bool, env: Env, object: Local, key: Local, val: Loca
 
     *out
 }
+
+/// A property defined with `define_properties`. A data property if both `getter`
+/// and `setter` are `None`; otherwise, an accessor property.
+pub struct Property {
+    pub name: Local,
+    pub value: Local,
+    pub getter: Option<Callback>,
+    pub setter: Option<Callback>,
+    pub attributes: napi::PropertyAttributes,
+}
+
+/// `Object.create` and `Object.defineProperty`, used to define accessor properties
+pub struct Intrinsics {
+    pub create: Local,
+    pub define_property: Local,
+}
+
+/// Defines properties on `object` in order, stopping at the first failure. Accessor
+/// properties fail with `InvalidArg` if `intrinsics` is `None`.
+pub unsafe fn define_properties(
+    env: Env,
+    object: Local,
+    properties: Vec<Property>,
+    intrinsics: Option<&Intrinsics>,
+) -> napi::Status {
+    for property in properties {
+        let status = if property.getter.is_none() && property.setter.is_none() {
+            define_data_property(env, object, property)
+        } else if let Some(intrinsics) = intrinsics {
+            match define_accessor_property(env, object, property, intrinsics) {
+                Ok(()) => napi::Status::Ok,
+                Err(status) => status,
+            }
+        } else {
+            napi::Status::InvalidArg
+        };
+
+        if status != napi::Status::Ok {
+            return status;
+        }
+    }
+
+    napi::Status::Ok
+}
+
+unsafe fn define_data_property(env: Env, object: Local, property: Property) -> napi::Status {
+    let descriptor = data_descriptor(property.name, property.value, property.attributes);
+
+    napi::define_properties(env, object, 1, &descriptor)
+}
+
+fn data_descriptor(
+    name: Local,
+    value: Local,
+    attributes: napi::PropertyAttributes,
+) -> napi::PropertyDescriptor {
+    napi::PropertyDescriptor {
+        utf8name: ptr::null(),
+        name,
+        method: None,
+        getter: None,
+        setter: None,
+        value,
+        attributes,
+        data: ptr::null_mut(),
+    }
+}
+
+// Accessors defined by `napi_define_properties` cannot own their closures; the
+// functions may outlive `object`, e.g., `Object.getOwnPropertyDescriptor(o, k).get`.
+// Instead, the getter and setter are created with `fun::new`, which drops each
+// closure with its function, and defined with `Object.defineProperty`. The
+// descriptor has a `null` prototype, so that fields are not inherited from a
+// modified `Object.prototype`.
+unsafe fn define_accessor_property(
+    env: Env,
+    object: Local,
+    property: Property,
+    intrinsics: &Intrinsics,
+) -> Result<(), napi::Status> {
+    fn check(status: napi::Status) -> Result<(), napi::Status> {
+        match status {
+            napi::Status::Ok => Ok(()),
+            status => Err(status),
+        }
+    }
+
+    unsafe fn call(env: Env, f: Local, args: &[Local]) -> Result<Local, napi::Status> {
+        let mut this = MaybeUninit::uninit();
+        let mut out = MaybeUninit::uninit();
+
+        check(napi::get_undefined(env, this.as_mut_ptr()))?;
+        check(napi::call_function(
+            env,
+            this.assume_init(),
+            f,
+            args.len(),
+            args.as_ptr(),
+            out.as_mut_ptr(),
+        ))?;
+
+        Ok(out.assume_init())
+    }
+
+    unsafe fn boolean(env: Env, value: bool) -> Result<Local, napi::Status> {
+        let mut out = MaybeUninit::uninit();
+
+        check(napi::get_boolean(env, value, out.as_mut_ptr()))?;
+
+        Ok(out.assume_init())
+    }
+
+    let mut null = MaybeUninit::uninit();
+
+    check(napi::get_null(env, null.as_mut_ptr()))?;
+
+    let descriptor = call(env, intrinsics.create, &[null.assume_init()])?;
+    let attributes = property.attributes.0;
+    let enumerable = attributes & napi::PropertyAttributes::ENUMERABLE.0 != 0;
+    let configurable = attributes & napi::PropertyAttributes::CONFIGURABLE.0 != 0;
+    let mut fields = Vec::with_capacity(4);
+
+    if let Some(getter) = property.getter {
+        fields.push(("get", super::fun::new(env, "", getter)?));
+    }
+
+    if let Some(setter) = property.setter {
+        fields.push(("set", super::fun::new(env, "", setter)?));
+    }
+
+    fields.push(("enumerable", boolean(env, enumerable)?));
+    fields.push(("configurable", boolean(env, configurable)?));
+
+    let fields = fields
+        .into_iter()
+        .map(|(key, value)| {
+            data_descriptor(
+                super::string(env, key),
+                value,
+                napi::PropertyAttributes::WRITABLE
+                    | napi::PropertyAttributes::ENUMERABLE
+                    | napi::PropertyAttributes::CONFIGURABLE,
+            )
+        })
+        .collect::<Vec<_>>();
+
+    check(napi::define_properties(
+        env,
+        descriptor,
+        fields.len(),
+        fields.as_ptr(),
+    ))?;
+
+    call(
+        env,
+        intrinsics.define_property,
+        &[object, property.name, descriptor],
+    )?;
+
+    Ok(())
+}
@@ -45,3 +45,22 @@ pub unsafe fn number_value(env: Env, p: Local) -> f64 {
     );
     value
 }
+
+/// Mutates the `out` argument provided to refer to a newly created symbol. The `description`
+/// must be a string or `NULL`.
+pub unsafe fn symbol(out: &mut Local, env: Env, description: Local) {
+    assert_eq!(
+        napi::create_symbol(env, description, out as *mut Local),
+        napi::Status::Ok
+    );
+}
+
+/// Mutates the `out` argument provided to refer to the symbol for `key` in the global
+/// symbol registry, creating it if it does not exist.
+#[cfg(feature = "napi-9")]
+pub unsafe fn symbol_for(out: &mut Local, env: Env, key: &str) {
+    assert_eq!(
+        napi::symbol_for(env, key.as_ptr().cast(), key.len(), out as *mut Local),
+        napi::Status::Ok
+    );
+}
@@ -6,10 +6,20 @@ use super::{
 };
 
 pub unsafe fn new(env: Env, value: Local) -> napi::Ref {
+    create(env, value, 1)
+}
+
+/// Creates a weak reference, with a reference count of `0`, that does not
+/// prevent `value` from being garbage collected
+pub unsafe fn new_weak(env: Env, value: Local) -> napi::Ref {
+    create(env, value, 0)
+}
+
+unsafe fn create(env: Env, value: Local, count: u32) -> napi::Ref {
     let mut result = MaybeUninit::uninit();
 
     assert_eq!(
-        napi::create_reference(env, value, 1, result.as_mut_ptr()),
+        napi::create_reference(env, value, count, result.as_mut_ptr()),
         napi::Status::Ok,
     );
 
@@ -32,6 +42,17 @@ pub unsafe fn reference(env: Env, value: napi::Ref) -> usize {
 /// # Safety
 /// Must only be used from the same module context that created the reference
 pub unsafe fn unreference(env: Env, value: napi::Ref) {
+    if release(env, value) == 0 {
+        delete(env, value);
+    }
+}
+
+/// Decrements the reference count without deleting the reference when it
+/// reaches `0`. Returns the new reference count.
+///
+/// # Safety
+/// Must only be used from the same module context that created the reference
+pub unsafe fn release(env: Env, value: napi::Ref) -> usize {
     let mut result = MaybeUninit::uninit();
 
     assert_eq!(
@@ -39,11 +60,17 @@ pub unsafe fn unreference(env: Env, value: napi::Ref) {
         napi::Status::Ok,
     );
 
-    if result.assume_init() == 0 {
-        assert_eq!(napi::delete_reference(env, value), napi::Status::Ok);
-    }
+    result.assume_init() as usize
+}
+
+/// # Safety
+/// Must only be used from the same module context that created the reference
+pub unsafe fn delete(env: Env, value: napi::Ref) {
+    assert_eq!(napi::delete_reference(env, value), napi::Status::Ok);
 }
 
+/// Returns a null pointer if the value of a weak reference was garbage collected
+///
 /// # Safety
 /// Must only be used from the same module context that created the reference
 pub unsafe fn get(env: Env, value: napi::Ref) -> Local {
@@ -36,6 +36,11 @@ pub unsafe fn is_string(env: Env, val: Local) -> bool {
     is_type(env, val, napi::ValueType::String)
 }
 
+/// Is `val` a JavaScript symbol?
+pub unsafe fn is_symbol(env: Env, val: Local) -> bool {
+    is_type(env, val, napi::ValueType::Symbol)
+}
+
 pub unsafe fn is_object(env: Env, val: Local) -> bool {
     is_type(env, val, napi::ValueType::Object)
 }
@@ -92,6 +97,16 @@ pub unsafe fn is_typedarray(env: Env, val: Local) -> bool {
     result
 }
 
+/// Is `val` a DataView instance?
+pub unsafe fn is_dataview(env: Env, val: Local) -> bool {
+    let mut result = false;
+    assert_eq!(
+        napi::is_dataview(env, val, &mut result as *mut _),
+        napi::Status::Ok
+    );
+    result
+}
+
 #[cfg(feature = "napi-5")]
 pub unsafe fn is_date(env: Env, val: Local) -> bool {
     let mut result = false;
@@ -115,12 +130,17 @@ pub unsafe fn is_promise(env: Env, val: Local) -> bool {
     result
 }
 
+/// Fails with `napi::Status::InvalidArg` if the object already has a type tag
 #[cfg(feature = "napi-8")]
-pub unsafe fn type_tag_object(env: Env, object: Local, tag: &super::TypeTag) {
-    assert_eq!(
-        napi::type_tag_object(env, object, tag as *const _),
-        napi::Status::Ok
-    );
+pub unsafe fn type_tag_object(
+    env: Env,
+    object: Local,
+    tag: &super::TypeTag,
+) -> Result<(), napi::Status> {
+    match napi::type_tag_object(env, object, tag as *const _) {
+        napi::Status::Ok => Ok(()),
+        status => Err(status),
+    }
 }
 
 #[cfg(feature = "napi-8")]
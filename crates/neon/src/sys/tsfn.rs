@@ -11,7 +11,6 @@ use super::{bindings as napi, no_panic::FailureBoundary, raw::Env};
 
 const BOUNDARY: FailureBoundary = FailureBoundary {
     both: "A panic and exception occurred while executing a `neon::event::Channel::send` callback",
-    exception: "An exception occurred while executing a `neon::event::Channel::send` callback",
     panic: "A panic occurred while executing a `neon::event::Channel::send` callback",
 };
 
@@ -83,6 +82,18 @@ impl<T: Send + 'static> ThreadsafeFunction<T> {
         }
     }
 
+    #[cfg(feature = "napi-6")]
+    /// Creates a threadsafe function that has already been finalized, e.g., to use
+    /// after the environment has been torn down. Calls fail and referencing has
+    /// no effect.
+    pub fn closed(callback: fn(Option<Env>, T)) -> Self {
+        Self {
+            tsfn: Tsfn(ptr::null_mut()),
+            is_finalized: Arc::new(Mutex::new(true)),
+            callback,
+        }
+    }
+
     /// Schedule a threadsafe function to be executed with some data
     pub fn call(
         &self,
@@ -128,6 +139,10 @@ impl<T: Send + 'static> ThreadsafeFunction<T> {
     /// References a threadsafe function to prevent exiting the event loop until it has been dropped. (Default)
     /// Safety: `Env` must be valid for the current thread
     pub unsafe fn reference(&self, env: Env) {
+        if *self.is_finalized.lock().unwrap() {
+            return;
+        }
+
         assert_eq!(
             napi::ref_threadsafe_function(env, self.tsfn.0),
             napi::Status::Ok,
@@ -137,6 +152,10 @@ impl<T: Send + 'static> ThreadsafeFunction<T> {
     /// Unreferences a threadsafe function to allow exiting the event loop before it has been dropped.
     /// Safety: `Env` must be valid for the current thread
     pub unsafe fn unref(&self, env: Env) {
+        if *self.is_finalized.lock().unwrap() {
+            return;
+        }
+
         assert_eq!(
             napi::unref_threadsafe_function(env, self.tsfn.0),
             napi::Status::Ok,
@@ -0,0 +1,165 @@
+//! Facilities for associating native data with JavaScript objects.
+
+use std::{mem::MaybeUninit, ptr};
+
+use super::{
+    bindings as napi,
+    debug_send_wrapper::DebugSendWrapper,
+    raw::{Env, Local},
+};
+
+/// `finalize_wrap` is invoked immediately before a wrapped object, or an object with
+/// a finalizer, is garbage collected
+extern "C" fn finalize_wrap<T: 'static>(
+    env: Env,
+    // Raw pointer to a `Box<T>` stored by `napi_wrap` or `napi_add_finalizer`
+    data: *mut std::ffi::c_void,
+    // Pointer to a Rust `fn` stored in the `hint` parameter of `napi_wrap` or
+    // `napi_add_finalizer` called
+    // with the contents of `data` immediately before the object is garbage collected.
+    hint: *mut std::ffi::c_void,
+) {
+    unsafe {
+        let data = Box::<DebugSendWrapper<T>>::from_raw(data as *mut _);
+        let finalizer: fn(Env, T) = std::mem::transmute(hint as *const ());
+
+        finalizer(env, data.take());
+    }
+}
+
+/// Error returned when a JavaScript object cannot be wrapped
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum WrapError {
+    /// `napi_wrap` failed, e.g., with `napi::Status::InvalidArg` if the object has
+    /// already been wrapped
+    Status(napi::Status),
+    /// The object has a type tag other than the one used by this module to tag
+    /// wrapped objects. An object can only have a single type tag.
+    #[cfg(feature = "napi-8")]
+    Tagged,
+}
+
+/// Associates a Rust value with a JavaScript object.
+pub unsafe fn wrap<T: 'static>(
+    env: Env,
+    object: Local,
+    v: T,
+    finalizer: fn(Env, T),
+) -> Result<(), WrapError> {
+    let data = Box::into_raw(Box::new(DebugSendWrapper::new(v)));
+    let status = napi::wrap(
+        env,
+        object,
+        data.cast(),
+        Some(finalize_wrap::<T>),
+        // Casting to `*const ()` is required to ensure the correct layout
+        // https://rust-lang.github.io/unsafe-code-guidelines/layout/function-pointers.html
+        finalizer as *const () as *mut _,
+        ptr::null_mut(),
+    );
+
+    if status != napi::Status::Ok {
+        drop(Box::from_raw(data));
+
+        return Err(WrapError::Status(status));
+    }
+
+    #[cfg(feature = "napi-8")]
+    // Tag the object as wrapped by this module. An object can only be tagged once and
+    // keeps its tag if it is unwrapped with `remove_wrap` and wrapped again.
+    if !super::tag::check_object_type_tag(env, object, &crate::MODULE_TAG)
+        && super::tag::type_tag_object(env, object, &crate::MODULE_TAG).is_err()
+    {
+        // The object was already tagged, e.g., with `Object::type_tag` or by another
+        // native module
+        let mut result = MaybeUninit::uninit();
+
+        assert_eq!(
+            napi::remove_wrap(env, object, result.as_mut_ptr()),
+            napi::Status::Ok,
+        );
+
+        drop(Box::from_raw(data));
+
+        return Err(WrapError::Tagged);
+    }
+
+    Ok(())
+}
+
+/// Returns a pointer to the data wrapped by a JavaScript object
+/// Safety: `unwrap` must only be called with objects wrapped by this module.
+/// Calling `unwrap` with an object wrapped by another native module is undefined
+/// behavior unless type tags are available to detect it.
+pub unsafe fn unwrap<T: 'static>(env: Env, object: Local) -> Option<*const T> {
+    let mut result = MaybeUninit::uninit();
+
+    // Fails if `object` is not an object or has not been wrapped
+    if napi::unwrap(env, object, result.as_mut_ptr()) != napi::Status::Ok {
+        return None;
+    }
+
+    #[cfg(feature = "napi-8")]
+    // Check the object was wrapped by this module
+    if !super::tag::check_object_type_tag(env, object, &crate::MODULE_TAG) {
+        return None;
+    }
+
+    let v = result.assume_init();
+    let v = &**v.cast_const().cast::<DebugSendWrapper<T>>() as *const T;
+
+    Some(v)
+}
+
+/// Removes the data wrapped by a JavaScript object and returns it, without calling
+/// the finalizer.
+/// Safety: Same as `unwrap`. Additionally, no references returned by `unwrap` may be
+/// used after the data is removed.
+pub unsafe fn remove_wrap<T: 'static>(env: Env, object: Local) -> Option<T> {
+    #[cfg(feature = "napi-8")]
+    // Check the object was wrapped by this module
+    if !super::tag::check_object_type_tag(env, object, &crate::MODULE_TAG) {
+        return None;
+    }
+
+    let mut result = MaybeUninit::uninit();
+
+    // Fails if `object` is not an object or has not been wrapped
+    if napi::remove_wrap(env, object, result.as_mut_ptr()) != napi::Status::Ok {
+        return None;
+    }
+
+    let data = Box::<DebugSendWrapper<T>>::from_raw(result.assume_init().cast());
+
+    Some(data.take())
+}
+
+/// Calls `finalizer` with `v` immediately before a JavaScript object is garbage
+/// collected. An object may have any number of finalizers.
+#[cfg(feature = "napi-5")]
+pub unsafe fn add_finalizer<T: 'static>(
+    env: Env,
+    object: Local,
+    v: T,
+    finalizer: fn(Env, T),
+) -> Result<(), napi::Status> {
+    let data = Box::into_raw(Box::new(DebugSendWrapper::new(v)));
+    let status = napi::add_finalizer(
+        env,
+        object,
+        data.cast(),
+        Some(finalize_wrap::<T>),
+        // Casting to `*const ()` is required to ensure the correct layout
+        // https://rust-lang.github.io/unsafe-code-guidelines/layout/function-pointers.html
+        finalizer as *const () as *mut _,
+        ptr::null_mut(),
+    );
+
+    if status != napi::Status::Ok {
+        drop(Box::from_raw(data));
+
+        return Err(status);
+    }
+
+    Ok(())
+}
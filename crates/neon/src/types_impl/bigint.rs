@@ -432,6 +432,10 @@ impl private::ValueInternal for JsBigInt {
         "BigInt".to_string()
     }
 
+    fn ts_type() -> String {
+        "bigint".to_string()
+    }
+
     fn is_typeof<Other: Value>(env: Env, other: &Other) -> bool {
         unsafe { sys::tag::is_bigint(env.to_raw(), other.to_local()) }
     }
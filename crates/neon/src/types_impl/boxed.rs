@@ -1,5 +1,6 @@
 use std::{
     any::{self, Any},
+    convert::TryFrom,
     ops::Deref,
 };
 
@@ -7,7 +8,11 @@ use crate::{
     context::{internal::Env, Context, FinalizeContext},
     handle::{internal::TransparentNoCopyWrapper, Handle},
     object::Object,
-    sys::{external, raw},
+    sys::{
+        self,
+        external::{self, BoxData},
+        raw,
+    },
     types::{boxed::private::JsBoxInner, private::ValueInternal, Value},
 };
 
@@ -158,7 +163,7 @@ impl<T: 'static> std::fmt::Debug for JsBox<T> {
 // Attempt to use a `napi_value` as a `napi_external` to unwrap a `BoxAny>
 /// Safety: `local` must be a `napi_value` that is valid for the lifetime `'a`.
 unsafe fn maybe_external_deref<'a>(env: Env, local: raw::Local) -> Option<&'a BoxAny> {
-    external::deref::<BoxAny>(env.to_raw(), local).map(|v| &*v)
+    external::deref::<BoxData>(env.to_raw(), local).map(|v| &(*v).value)
 }
 
 // Custom `Clone` implementation since `T` might not be `Clone`
@@ -238,22 +243,68 @@ impl<T: Finalize + 'static> JsBox<T> {
     where
         C: Context<'a>,
         T: 'static,
+    {
+        Self::create(cx, value, 0)
+    }
+
+    /// Constructs a new `JsBox` containing `value` and reports `size` bytes of native
+    /// memory owned by `value` to the garbage collector. The size is subtracted when the
+    /// `JsBox` is garbage collected.
+    ///
+    /// The garbage collector is only aware of the small JavaScript object of a `JsBox`.
+    /// Reporting the size of large values, e.g., buffers, allows it to collect them sooner.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// struct Image(Vec<u8>);
+    ///
+    /// impl Finalize for Image {}
+    ///
+    /// fn load_image(mut cx: FunctionContext) -> JsResult<JsBox<Image>> {
+    ///     let pixels = vec![0; 1920 * 1080 * 4];
+    ///     let size = pixels.capacity();
+    ///
+    ///     Ok(JsBox::new_with_size(&mut cx, Image(pixels), size))
+    /// }
+    /// ```
+    pub fn new_with_size<'a, C>(cx: &mut C, value: T, size: usize) -> Handle<'a, JsBox<T>>
+    where
+        C: Context<'a>,
+        T: 'static,
+    {
+        let size = i64::try_from(size).unwrap_or(i64::MAX);
+
+        cx.adjust_external_memory(size);
+
+        Self::create(cx, value, size)
+    }
+
+    fn create<'a, C>(cx: &mut C, value: T, size: i64) -> Handle<'a, JsBox<T>>
+    where
+        C: Context<'a>,
     {
         // This function will execute immediately before the `JsBox` is garbage collected.
         // It unwraps the `napi_external`, downcasts the `BoxAny` and moves the type
         // out of the `Box`. Lastly, it calls the trait method `Finalize::fianlize` of the
         // contained value `T`.
-        fn finalizer<U: Finalize + 'static>(env: raw::Env, data: BoxAny) {
-            let data = *data.downcast::<U>().unwrap();
+        fn finalizer<U: Finalize + 'static>(env: raw::Env, data: BoxData) {
+            let BoxData { value, size } = data;
+            let value = *value.downcast::<U>().unwrap();
+
+            if size != 0 {
+                unsafe { sys::mem::adjust_external_memory(env, -size) };
+            }
+
             let env = unsafe { std::mem::transmute(env) };
 
-            FinalizeContext::with(env, move |mut cx| data.finalize(&mut cx));
+            FinalizeContext::with(env, move |mut cx| value.finalize(&mut cx));
         }
 
         let v = Box::new(value) as BoxAny;
         // Since this value was just constructed, we know it is `T`
         let raw_data = &*v as *const dyn Any as *const T;
-        let local = unsafe { external::create(cx.env().to_raw(), v, finalizer::<T>) };
+        let data = BoxData { value: v, size };
+        let local = unsafe { external::create(cx.env().to_raw(), data, finalizer::<T>) };
 
         Handle::new_internal(Self(JsBoxInner { local, raw_data }))
     }
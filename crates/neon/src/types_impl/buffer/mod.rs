@@ -21,7 +21,7 @@ use crate::{
 pub(crate) mod lock;
 pub(super) mod types;
 
-pub use types::Binary;
+pub use types::{Binary, U8Clamped};
 
 /// A trait allowing Rust to borrow binary data from the memory buffer of JavaScript
 /// [typed arrays][typed-arrays].
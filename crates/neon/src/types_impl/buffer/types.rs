@@ -4,7 +4,7 @@ use crate::{
     context::{internal::Env, Context},
     handle::{internal::TransparentNoCopyWrapper, Handle},
     object::Object,
-    result::{JsResult, Throw},
+    result::{JsResult, NeonResult, Throw},
     sys::{self, raw, TypedArrayType},
     types_impl::{
         buffer::{
@@ -92,6 +92,10 @@ impl JsBuffer {
     #[cfg_attr(docsrs, doc(cfg(feature = "external-buffers")))]
     /// Construct a new `Buffer` from bytes allocated by Rust.
     ///
+    /// The length of the data is accounted for by the JavaScript engine, as with buffers
+    /// allocated by JavaScript, and does not need to be reported with
+    /// [`Context::adjust_external_memory`].
+    ///
     /// # Compatibility Note
     ///
     /// Some Node environments are built using V8's _sandboxed pointers_ functionality, which
@@ -128,6 +132,10 @@ impl ValueInternal for JsBuffer {
         "Buffer".to_string()
     }
 
+    fn ts_type() -> String {
+        "Buffer".to_string()
+    }
+
     fn is_typeof<Other: Value>(env: Env, other: &Other) -> bool {
         unsafe { sys::tag::is_buffer(env.to_raw(), other.to_local()) }
     }
@@ -266,6 +274,10 @@ impl JsArrayBuffer {
     #[cfg_attr(docsrs, doc(cfg(feature = "external-buffers")))]
     /// Construct a new `JsArrayBuffer` from bytes allocated by Rust.
     ///
+    /// The length of the data is accounted for by the JavaScript engine, as with buffers
+    /// allocated by JavaScript, and does not need to be reported with
+    /// [`Context::adjust_external_memory`].
+    ///
     /// # Compatibility Note
     ///
     /// Some Node environments are built using V8's _sandboxed pointers_ functionality, which
@@ -345,6 +357,10 @@ impl ValueInternal for JsArrayBuffer {
         "JsArrayBuffer".to_string()
     }
 
+    fn ts_type() -> String {
+        "ArrayBuffer".to_string()
+    }
+
     fn is_typeof<Other: Value>(env: Env, other: &Other) -> bool {
         unsafe { sys::tag::is_arraybuffer(env.to_raw(), other.to_local()) }
     }
@@ -420,6 +436,324 @@ impl TypedArray for JsArrayBuffer {
     }
 }
 
+/// The type of JavaScript
+/// [`DataView`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/DataView)
+/// objects.
+///
+/// A `DataView` reads and writes numbers of any size and endianness at arbitrary
+/// byte offsets of an [`ArrayBuffer`](JsArrayBuffer), which makes it convenient for
+/// parsing binary protocols. Accessors throw a `RangeError` if the value does not fit
+/// within the view, like the methods of `DataView`.
+///
+/// The bytes of the view may also be borrowed with the [`TypedArray`] trait.
+///
+/// # Example
+///
+/// ```
+/// # use neon::prelude::*;
+/// // Reads a length-prefixed message: a big-endian `u16` length followed by
+/// // the UTF-8 bytes of the message
+/// fn read_message(mut cx: FunctionContext) -> JsResult<JsString> {
+///     let view: Handle<JsDataView> = cx.argument(0)?;
+///     let len = view.get_u16_be(&mut cx, 0)? as usize;
+///     let message = (0..len)
+///         .map(|i| view.get_u8(&mut cx, 2 + i))
+///         .collect::<NeonResult<Vec<_>>>()?;
+///
+///     Ok(cx.string(String::from_utf8_lossy(&message)))
+/// }
+/// ```
+#[derive(Debug)]
+#[repr(transparent)]
+pub struct JsDataView(raw::Local);
+
+impl JsDataView {
+    /// Constructs a new `DataView` of `size` bytes, with a newly allocated buffer that is
+    /// safely zero-filled.
+    pub fn new<'cx, C: Context<'cx>>(cx: &mut C, size: usize) -> JsResult<'cx, Self> {
+        let buffer = cx.array_buffer(size)?;
+
+        Self::from_buffer(cx, buffer)
+    }
+
+    /// Constructs a `JsDataView` from a slice by copying its contents.
+    ///
+    /// This method is defined on `JsDataView` as a convenience and delegates to
+    /// [`TypedArray::from_slice`][TypedArray::from_slice].
+    pub fn from_slice<'cx, C>(cx: &mut C, slice: &[u8]) -> JsResult<'cx, Self>
+    where
+        C: Context<'cx>,
+    {
+        <JsDataView as TypedArray>::from_slice(cx, slice)
+    }
+
+    /// Constructs a `DataView` of the entire `buffer`.
+    pub fn from_buffer<'cx, 'b: 'cx, C>(
+        cx: &mut C,
+        buffer: Handle<'b, JsArrayBuffer>,
+    ) -> JsResult<'cx, Self>
+    where
+        C: Context<'cx>,
+    {
+        let size = buffer.size(cx);
+
+        Self::from_region(cx, &buffer.region(0, size))
+    }
+
+    /// Constructs a `DataView` of the specified buffer region.
+    ///
+    /// Throws a `RangeError` if the region goes beyond the end of the buffer.
+    pub fn from_region<'c, 'r, C>(cx: &mut C, region: &Region<'r, u8>) -> JsResult<'c, Self>
+    where
+        C: Context<'c>,
+    {
+        let &Region {
+            buffer,
+            offset,
+            len,
+            ..
+        } = region;
+
+        let view = unsafe {
+            sys::dataview::new(cx.env().to_raw(), buffer.to_local(), offset, len)
+                .map_err(|_| Throw::new())?
+        };
+
+        Ok(Handle::new_internal(Self(view)))
+    }
+
+    /// Returns information about the backing buffer region for this `DataView`.
+    pub fn region<'cx, C>(&self, cx: &mut C) -> Region<'cx, u8>
+    where
+        C: Context<'cx>,
+    {
+        let info = unsafe { sys::dataview::info(cx.env().to_raw(), self.to_local()) };
+
+        Region {
+            buffer: Handle::new_internal(unsafe { JsArrayBuffer::from_local(cx.env(), info.buf) }),
+            offset: info.offset,
+            len: info.size,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Returns the [`JsArrayBuffer`](JsArrayBuffer) that owns the underlying storage buffer
+    /// for this `DataView`.
+    pub fn buffer<'cx, C>(&self, cx: &mut C) -> Handle<'cx, JsArrayBuffer>
+    where
+        C: Context<'cx>,
+    {
+        let info = unsafe { sys::dataview::info(cx.env().to_raw(), self.to_local()) };
+
+        Handle::new_internal(unsafe { JsArrayBuffer::from_local(cx.env(), info.buf) })
+    }
+
+    /// Returns the offset (in bytes) of the `DataView` from the start of its
+    /// [`JsArrayBuffer`](JsArrayBuffer).
+    pub fn offset<'cx, C>(&self, cx: &mut C) -> usize
+    where
+        C: Context<'cx>,
+    {
+        let info = unsafe { sys::dataview::info(cx.env().to_raw(), self.to_local()) };
+        info.offset
+    }
+
+    // Copies `N` bytes starting at `offset`
+    fn read<'cx, C, const N: usize>(&self, cx: &mut C, offset: usize) -> NeonResult<[u8; N]>
+    where
+        C: Context<'cx>,
+    {
+        let bytes = self.as_slice(cx);
+
+        match offset.checked_add(N).and_then(|end| bytes.get(offset..end)) {
+            Some(bytes) => {
+                let mut out = [0; N];
+                out.copy_from_slice(bytes);
+                Ok(out)
+            }
+            None => cx.throw_range_error(OUT_OF_BOUNDS),
+        }
+    }
+
+    // Overwrites `N` bytes starting at `offset`
+    fn write<'cx, C, const N: usize>(
+        &mut self,
+        cx: &mut C,
+        offset: usize,
+        bytes: [u8; N],
+    ) -> NeonResult<()>
+    where
+        C: Context<'cx>,
+    {
+        let target = offset
+            .checked_add(N)
+            .and_then(|end| self.as_mut_slice(cx).get_mut(offset..end));
+
+        match target {
+            Some(target) => {
+                target.copy_from_slice(&bytes);
+                Ok(())
+            }
+            None => cx.throw_range_error(OUT_OF_BOUNDS),
+        }
+    }
+}
+
+const OUT_OF_BOUNDS: &str = "Offset is outside the bounds of the DataView";
+
+macro_rules! impl_data_view_accessors {
+    ($($ty:ty, $get:ident, $set:ident, $from:ident, $to:ident, $desc:expr;)*) => {
+        impl JsDataView {
+            $(
+                #[doc = concat!("Reads ", $desc, " at the byte `offset` of the view.")]
+                pub fn $get<'cx, C>(&self, cx: &mut C, offset: usize) -> NeonResult<$ty>
+                where
+                    C: Context<'cx>,
+                {
+                    self.read(cx, offset).map(<$ty>::$from)
+                }
+
+                #[doc = concat!("Writes ", $desc, " at the byte `offset` of the view.")]
+                pub fn $set<'cx, C>(&mut self, cx: &mut C, offset: usize, value: $ty) -> NeonResult<()>
+                where
+                    C: Context<'cx>,
+                {
+                    self.write(cx, offset, value.$to())
+                }
+            )*
+        }
+    };
+}
+
+impl_data_view_accessors! {
+    u8, get_u8, set_u8, from_le_bytes, to_le_bytes, "an unsigned 8-bit integer";
+    i8, get_i8, set_i8, from_le_bytes, to_le_bytes, "a signed 8-bit integer";
+    u16, get_u16_le, set_u16_le, from_le_bytes, to_le_bytes, "a little-endian unsigned 16-bit integer";
+    u16, get_u16_be, set_u16_be, from_be_bytes, to_be_bytes, "a big-endian unsigned 16-bit integer";
+    i16, get_i16_le, set_i16_le, from_le_bytes, to_le_bytes, "a little-endian signed 16-bit integer";
+    i16, get_i16_be, set_i16_be, from_be_bytes, to_be_bytes, "a big-endian signed 16-bit integer";
+    u32, get_u32_le, set_u32_le, from_le_bytes, to_le_bytes, "a little-endian unsigned 32-bit integer";
+    u32, get_u32_be, set_u32_be, from_be_bytes, to_be_bytes, "a big-endian unsigned 32-bit integer";
+    i32, get_i32_le, set_i32_le, from_le_bytes, to_le_bytes, "a little-endian signed 32-bit integer";
+    i32, get_i32_be, set_i32_be, from_be_bytes, to_be_bytes, "a big-endian signed 32-bit integer";
+    u64, get_u64_le, set_u64_le, from_le_bytes, to_le_bytes, "a little-endian unsigned 64-bit integer";
+    u64, get_u64_be, set_u64_be, from_be_bytes, to_be_bytes, "a big-endian unsigned 64-bit integer";
+    i64, get_i64_le, set_i64_le, from_le_bytes, to_le_bytes, "a little-endian signed 64-bit integer";
+    i64, get_i64_be, set_i64_be, from_be_bytes, to_be_bytes, "a big-endian signed 64-bit integer";
+    f32, get_f32_le, set_f32_le, from_le_bytes, to_le_bytes, "a little-endian 32-bit float";
+    f32, get_f32_be, set_f32_be, from_be_bytes, to_be_bytes, "a big-endian 32-bit float";
+    f64, get_f64_le, set_f64_le, from_le_bytes, to_le_bytes, "a little-endian 64-bit float";
+    f64, get_f64_be, set_f64_be, from_be_bytes, to_be_bytes, "a big-endian 64-bit float";
+}
+
+unsafe impl TransparentNoCopyWrapper for JsDataView {
+    type Inner = raw::Local;
+
+    fn into_inner(self) -> Self::Inner {
+        self.0
+    }
+}
+
+impl ValueInternal for JsDataView {
+    fn name() -> String {
+        "DataView".to_string()
+    }
+
+    fn ts_type() -> String {
+        "DataView".to_string()
+    }
+
+    fn is_typeof<Other: Value>(env: Env, other: &Other) -> bool {
+        unsafe { sys::tag::is_dataview(env.to_raw(), other.to_local()) }
+    }
+
+    fn to_local(&self) -> raw::Local {
+        self.0
+    }
+
+    unsafe fn from_local(_env: Env, h: raw::Local) -> Self {
+        Self(h)
+    }
+}
+
+impl Value for JsDataView {}
+
+impl Object for JsDataView {}
+
+impl private::Sealed for JsDataView {}
+
+impl TypedArray for JsDataView {
+    type Item = u8;
+
+    fn as_slice<'cx, 'a, C>(&self, cx: &'a C) -> &'a [Self::Item]
+    where
+        C: Context<'cx>,
+    {
+        unsafe {
+            let info = sys::dataview::info(cx.env().to_raw(), self.to_local());
+
+            slice::from_raw_parts(info.data.cast(), info.size)
+        }
+    }
+
+    fn as_mut_slice<'cx, 'a, C>(&mut self, cx: &'a mut C) -> &'a mut [Self::Item]
+    where
+        C: Context<'cx>,
+    {
+        unsafe {
+            let info = sys::dataview::info(cx.env().to_raw(), self.to_local());
+
+            slice::from_raw_parts_mut(info.data.cast(), info.size)
+        }
+    }
+
+    fn try_borrow<'cx, 'a, C>(&self, lock: &'a Lock<C>) -> Result<Ref<'a, Self::Item>, BorrowError>
+    where
+        C: Context<'cx>,
+    {
+        unsafe {
+            let info = sys::dataview::info(lock.cx.env().to_raw(), self.to_local());
+
+            // The borrowed data must be guarded by `Ledger` before returning
+            Ledger::try_borrow(
+                &lock.ledger,
+                slice::from_raw_parts(info.data.cast(), info.size),
+            )
+        }
+    }
+
+    fn try_borrow_mut<'cx, 'a, C>(
+        &mut self,
+        lock: &'a Lock<C>,
+    ) -> Result<RefMut<'a, Self::Item>, BorrowError>
+    where
+        C: Context<'cx>,
+    {
+        unsafe {
+            let info = sys::dataview::info(lock.cx.env().to_raw(), self.to_local());
+
+            // The borrowed data must be guarded by `Ledger` before returning
+            Ledger::try_borrow_mut(
+                &lock.ledger,
+                slice::from_raw_parts_mut(info.data.cast(), info.size),
+            )
+        }
+    }
+
+    fn size<'cx, C: Context<'cx>>(&self, cx: &mut C) -> usize {
+        unsafe { sys::dataview::info(cx.env().to_raw(), self.to_local()).size }
+    }
+
+    fn from_slice<'cx, C>(cx: &mut C, slice: &[u8]) -> JsResult<'cx, Self>
+    where
+        C: Context<'cx>,
+    {
+        let buffer = JsArrayBuffer::from_slice(cx, slice)?;
+
+        Self::from_buffer(cx, buffer)
+    }
+}
+
 /// A marker trait for all possible element types of binary buffers.
 ///
 /// This trait can only be implemented within the Neon library.
@@ -428,6 +762,50 @@ pub trait Binary: private::Sealed + Copy {
     const TYPE_TAG: TypedArrayType;
 }
 
+/// An element of a [`JsUint8ClampedArray`](crate::types::JsUint8ClampedArray).
+///
+/// A `Uint8ClampedArray` stores bytes like a `Uint8Array`, but JavaScript clamps
+/// values to the range `0..=255` instead of wrapping them when they are written.
+/// It is commonly used for the pixels of images, e.g., `ImageData`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct U8Clamped(pub u8);
+
+impl U8Clamped {
+    /// Converts a number to a byte like writing to a `Uint8ClampedArray`, clamping
+    /// it to `0..=255` and rounding half to even. `NaN` is converted to `0`.
+    pub fn from_f64(n: f64) -> Self {
+        if n.is_nan() || n <= 0.0 {
+            return Self(0);
+        }
+
+        if n >= 255.0 {
+            return Self(255);
+        }
+
+        let floor = n.floor();
+        let rounded = match n - floor {
+            d if d > 0.5 => floor + 1.0,
+            d if d == 0.5 && floor % 2.0 != 0.0 => floor + 1.0,
+            _ => floor,
+        };
+
+        Self(rounded as u8)
+    }
+}
+
+impl From<u8> for U8Clamped {
+    fn from(n: u8) -> Self {
+        Self(n)
+    }
+}
+
+impl From<U8Clamped> for u8 {
+    fn from(n: U8Clamped) -> Self {
+        n.0
+    }
+}
+
 /// The family of JavaScript [typed array][typed-arrays] types.
 ///
 /// ## Typed Arrays
@@ -445,18 +823,19 @@ pub trait Binary: private::Sealed + Copy {
 /// The following table shows the complete set of typed array types, with both their
 /// JavaScript and Neon types:
 ///
-/// | Rust Type                      | Convenience Type                       | JavaScript Type                    |
-/// | ------------------------------ | -------------------------------------- | ---------------------------------- |
-/// | `JsTypedArray<`[`u8`][u8]`>`   | [`JsUint8Array`][JsUint8Array]         | [`Uint8Array`][Uint8Array]         |
-/// | `JsTypedArray<`[`i8`][i8]`>`   | [`JsInt8Array`][JsInt8Array]           | [`Int8Array`][Int8Array]           |
-/// | `JsTypedArray<`[`u16`][u16]`>` | [`JsUint16Array`][JsUint16Array]       | [`Uint16Array`][Uint16Array]       |
-/// | `JsTypedArray<`[`i16`][i16]`>` | [`JsInt16Array`][JsInt16Array]         | [`Int16Array`][Int16Array]         |
-/// | `JsTypedArray<`[`u32`][u32]`>` | [`JsUint32Array`][JsUint32Array]       | [`Uint32Array`][Uint32Array]       |
-/// | `JsTypedArray<`[`i32`][i32]`>` | [`JsInt32Array`][JsInt32Array]         | [`Int32Array`][Int32Array]         |
-/// | `JsTypedArray<`[`u64`][u64]`>` | [`JsBigUint64Array`][JsBigUint64Array] | [`BigUint64Array`][BigUint64Array] |
-/// | `JsTypedArray<`[`i64`][i64]`>` | [`JsBigInt64Array`][JsBigInt64Array]   | [`BigInt64Array`][BigInt64Array]   |
-/// | `JsTypedArray<`[`f32`][f32]`>` | [`JsFloat32Array`][JsFloat32Array]     | [`Float32Array`][Float32Array]     |
-/// | `JsTypedArray<`[`f64`][f64]`>` | [`JsFloat64Array`][JsFloat64Array]     | [`Float64Array`][Float64Array]     |
+/// | Rust Type                       | Convenience Type                             | JavaScript Type                          |
+/// | ------------------------------- | -------------------------------------------- | ---------------------------------------- |
+/// | `JsTypedArray<`[`u8`][u8]`>`    | [`JsUint8Array`][JsUint8Array]               | [`Uint8Array`][Uint8Array]               |
+/// | `JsTypedArray<`[`U8Clamped`]`>` | [`JsUint8ClampedArray`][JsUint8ClampedArray] | [`Uint8ClampedArray`][Uint8ClampedArray] |
+/// | `JsTypedArray<`[`i8`][i8]`>`    | [`JsInt8Array`][JsInt8Array]                 | [`Int8Array`][Int8Array]                 |
+/// | `JsTypedArray<`[`u16`][u16]`>`  | [`JsUint16Array`][JsUint16Array]             | [`Uint16Array`][Uint16Array]             |
+/// | `JsTypedArray<`[`i16`][i16]`>`  | [`JsInt16Array`][JsInt16Array]               | [`Int16Array`][Int16Array]               |
+/// | `JsTypedArray<`[`u32`][u32]`>`  | [`JsUint32Array`][JsUint32Array]             | [`Uint32Array`][Uint32Array]             |
+/// | `JsTypedArray<`[`i32`][i32]`>`  | [`JsInt32Array`][JsInt32Array]               | [`Int32Array`][Int32Array]               |
+/// | `JsTypedArray<`[`u64`][u64]`>`  | [`JsBigUint64Array`][JsBigUint64Array]       | [`BigUint64Array`][BigUint64Array]       |
+/// | `JsTypedArray<`[`i64`][i64]`>`  | [`JsBigInt64Array`][JsBigInt64Array]         | [`BigInt64Array`][BigInt64Array]         |
+/// | `JsTypedArray<`[`f32`][f32]`>`  | [`JsFloat32Array`][JsFloat32Array]           | [`Float32Array`][Float32Array]           |
+/// | `JsTypedArray<`[`f64`][f64]`>`  | [`JsFloat64Array`][JsFloat64Array]           | [`Float64Array`][Float64Array]           |
 ///
 /// ### Example: Creating an integer array
 ///
@@ -497,6 +876,7 @@ pub trait Binary: private::Sealed + Copy {
 /// [f32]: std::primitive::f32
 /// [f64]: std::primitive::f64
 /// [JsUint8Array]: crate::types::JsUint8Array
+/// [JsUint8ClampedArray]: crate::types::JsUint8ClampedArray
 /// [JsInt8Array]: crate::types::JsInt8Array
 /// [JsUint16Array]: crate::types::JsUint16Array
 /// [JsInt16Array]: crate::types::JsInt16Array
@@ -507,6 +887,7 @@ pub trait Binary: private::Sealed + Copy {
 /// [JsFloat32Array]: crate::types::JsFloat32Array
 /// [JsFloat64Array]: crate::types::JsFloat64Array
 /// [Uint8Array]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Uint8Array
+/// [Uint8ClampedArray]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Uint8ClampedArray
 /// [Int8Array]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Int8Array
 /// [Uint16Array]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Uint16Array
 /// [Int16Array]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Int16Array
@@ -780,6 +1161,41 @@ where
 
 macro_rules! impl_typed_array {
     ($typ:ident, $etyp:ty, $($pattern:pat)|+, $tag:ident, $alias:ident, $two:expr$(,)?) => {
+        impl_typed_array!(@impl $typ, $etyp, $($pattern)|+, $tag);
+
+        doc_comment! {
+            concat!(
+                "The type of JavaScript [`",
+                stringify!($typ),
+                "`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/",
+                stringify!($typ),
+                ") objects.
+
+# Example
+
+```
+# use neon::prelude::*;
+use neon::types::buffer::TypedArray;
+
+fn double(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let mut array: Handle<",
+                stringify!($alias),
+                "> = cx.argument(0)?;
+
+    for elem in array.as_mut_slice(&mut cx).iter_mut() {
+        *elem *= ",
+                stringify!($two),
+                ";
+    }
+
+    Ok(cx.undefined())
+}
+```",
+            ),
+            pub type $alias = JsTypedArray<$etyp>;
+        }
+    };
+    (@impl $typ:ident, $etyp:ty, $($pattern:pat)|+, $tag:ident) => {
         impl private::Sealed for $etyp {}
 
         impl Binary for $etyp {
@@ -795,6 +1211,10 @@ macro_rules! impl_typed_array {
                 stringify!($typ).to_string()
             }
 
+            fn ts_type() -> String {
+                stringify!($typ).to_string()
+            }
+
             fn is_typeof<Other: Value>(env: Env, other: &Other) -> bool {
                 let env = env.to_raw();
                 let other = other.to_local();
@@ -824,38 +1244,6 @@ macro_rules! impl_typed_array {
                 })
             }
         }
-
-        doc_comment! {
-            concat!(
-                "The type of JavaScript [`",
-                stringify!($typ),
-                "`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/",
-                stringify!($typ),
-                ") objects.
-
-# Example
-
-```
-# use neon::prelude::*;
-use neon::types::buffer::TypedArray;
-
-fn double(mut cx: FunctionContext) -> JsResult<JsUndefined> {
-    let mut array: Handle<",
-                stringify!($alias),
-                "> = cx.argument(0)?;
-
-    for elem in array.as_mut_slice(&mut cx).iter_mut() {
-        *elem *= ",
-                stringify!($two),
-                ";
-    }
-
-    Ok(cx.undefined())
-}
-```",
-            ),
-            pub type $alias = JsTypedArray<$etyp>;
-        }
     };
 }
 
@@ -868,6 +1256,30 @@ impl_typed_array!(
     JsUint8Array,
     2,
 );
+impl_typed_array!(@impl Uint8ClampedArray, U8Clamped, TypedArrayType::U8Clamped, U8Clamped);
+
+/// The type of JavaScript
+/// [`Uint8ClampedArray`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/Uint8ClampedArray)
+/// objects.
+///
+/// # Example
+///
+/// ```
+/// # use neon::prelude::*;
+/// use neon::types::buffer::{TypedArray, U8Clamped};
+///
+/// fn brighten(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+///     let mut pixels: Handle<JsUint8ClampedArray> = cx.argument(0)?;
+///
+///     for elem in pixels.as_mut_slice(&mut cx).iter_mut() {
+///         *elem = U8Clamped::from_f64(f64::from(elem.0) * 1.5);
+///     }
+///
+///     Ok(cx.undefined())
+/// }
+/// ```
+pub type JsUint8ClampedArray = JsTypedArray<U8Clamped>;
+
 impl_typed_array!(Int16Array, i16, TypedArrayType::I16, I16, JsInt16Array, 2);
 impl_typed_array!(Uint16Array, u16, TypedArrayType::U16, U16, JsUint16Array, 2);
 impl_typed_array!(Int32Array, i32, TypedArrayType::I32, I32, JsInt32Array, 2);
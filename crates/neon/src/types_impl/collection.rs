@@ -0,0 +1,733 @@
+use std::iter::FromIterator;
+
+use super::{private::ValueInternal, Value};
+
+use crate::{
+    context::{internal::Env, Context, TaskContext},
+    handle::{internal::TransparentNoCopyWrapper, Handle},
+    object::Object,
+    result::{JsResult, NeonResult},
+    sys::{self, raw},
+    types::{
+        extract::{TryFromJs, TryIntoJs},
+        JsArray, JsBoolean, JsFunction, JsNumber, JsObject, JsValue,
+    },
+};
+
+#[cfg(feature = "napi-6")]
+use crate::{handle::Root, thread::LocalKey};
+
+// Global constructors of the keyed collections
+#[derive(Clone, Copy)]
+enum Builtin {
+    Map,
+    Set,
+    WeakMap,
+}
+
+// Methods of the prototypes of the keyed collections. `Size` is the getter of
+// the `size` property.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Method {
+    Has,
+    Get,
+    Set,
+    Add,
+    Delete,
+    Clear,
+    Entries,
+    Values,
+    Size,
+}
+
+impl Method {
+    fn name(self) -> &'static str {
+        match self {
+            Method::Has => "has",
+            Method::Get => "get",
+            Method::Set => "set",
+            Method::Add => "add",
+            Method::Delete => "delete",
+            Method::Clear => "clear",
+            Method::Entries => "entries",
+            Method::Values => "values",
+            Method::Size => "size",
+        }
+    }
+}
+
+#[cfg(feature = "napi-6")]
+// The constructor and prototype methods of a collection
+struct Intrinsics {
+    constructor: Root<JsFunction>,
+    methods: Vec<(Method, Root<JsFunction>)>,
+}
+
+impl Builtin {
+    fn name(self) -> &'static str {
+        match self {
+            Builtin::Map => "Map",
+            Builtin::Set => "Set",
+            Builtin::WeakMap => "WeakMap",
+        }
+    }
+
+    #[cfg(feature = "napi-6")]
+    fn methods(self) -> &'static [Method] {
+        match self {
+            Builtin::Map => &[
+                Method::Has,
+                Method::Get,
+                Method::Set,
+                Method::Delete,
+                Method::Clear,
+                Method::Entries,
+                Method::Size,
+            ],
+            Builtin::Set => &[
+                Method::Has,
+                Method::Add,
+                Method::Delete,
+                Method::Clear,
+                Method::Values,
+                Method::Size,
+            ],
+            Builtin::WeakMap => &[Method::Has, Method::Get, Method::Set, Method::Delete],
+        }
+    }
+
+    // The constructor and methods are looked up once per instance of the module,
+    // instead of on every call, since `globalThis` and the prototypes may be
+    // modified by user code
+    #[cfg(feature = "napi-6")]
+    fn intrinsics<'cx, C: Context<'cx>>(self, cx: &mut C) -> NeonResult<&'cx Intrinsics> {
+        static MAP: LocalKey<Intrinsics> = LocalKey::new();
+        static SET: LocalKey<Intrinsics> = LocalKey::new();
+        static WEAK_MAP: LocalKey<Intrinsics> = LocalKey::new();
+
+        let key = match self {
+            Builtin::Map => &MAP,
+            Builtin::Set => &SET,
+            Builtin::WeakMap => &WEAK_MAP,
+        };
+
+        key.get_or_try_init(cx, |cx| {
+            let constructor = cx.global::<JsFunction>(self.name())?;
+            let mut methods = Vec::with_capacity(self.methods().len());
+
+            for &method in self.methods() {
+                let f = lookup_method(cx, constructor, method)?;
+
+                methods.push((method, f.root(cx)));
+            }
+
+            Ok(Intrinsics {
+                constructor: constructor.root(cx),
+                methods,
+            })
+        })
+    }
+
+    #[cfg(feature = "napi-6")]
+    fn constructor<'a, C: Context<'a>>(self, cx: &mut C) -> JsResult<'a, JsFunction> {
+        Ok(self.intrinsics(cx)?.constructor.to_inner(cx))
+    }
+
+    #[cfg(not(feature = "napi-6"))]
+    fn constructor<'a, C: Context<'a>>(self, cx: &mut C) -> JsResult<'a, JsFunction> {
+        cx.global(self.name())
+    }
+
+    #[cfg(feature = "napi-6")]
+    fn method<'a, C: Context<'a>>(self, cx: &mut C, method: Method) -> JsResult<'a, JsFunction> {
+        let (_, f) = self
+            .intrinsics(cx)?
+            .methods
+            .iter()
+            .find(|(m, _)| *m == method)
+            .expect("Method of the collection should be cached");
+
+        Ok(f.to_inner(cx))
+    }
+
+    #[cfg(not(feature = "napi-6"))]
+    fn method<'a, C: Context<'a>>(self, cx: &mut C, method: Method) -> JsResult<'a, JsFunction> {
+        let constructor = self.constructor(cx)?;
+
+        lookup_method(cx, constructor, method)
+    }
+
+    fn construct<'a, C: Context<'a>>(self, cx: &mut C) -> NeonResult<raw::Local> {
+        let obj = self
+            .constructor(cx)?
+            .construct_with(cx)
+            .apply::<JsObject, _>(cx)?;
+
+        Ok(obj.to_local())
+    }
+
+    // Calls a method of the prototype, e.g., `Map.prototype.get.call(this, ...args)`
+    fn call<'a, C: Context<'a>>(
+        self,
+        cx: &mut C,
+        this: raw::Local,
+        method: Method,
+        args: &[Handle<JsValue>],
+    ) -> JsResult<'a, JsValue> {
+        let method = self.method(cx, method)?;
+
+        method.call(cx, JsValue::new_internal(this), args)
+    }
+
+    // Methods of the prototype throw a `TypeError` if `this` is not an instance
+    // of the collection. Unlike `instanceof`, this works across realms. With
+    // `napi-6`, the method is cached when the collection is first used, so it
+    // cannot be spoofed by later modifications of the prototype.
+    fn is_instance(self, env: Env, value: raw::Local) -> bool {
+        if !unsafe { sys::tag::is_object(env.to_raw(), value) } {
+            return false;
+        }
+
+        TaskContext::with_context(env, |mut cx| {
+            cx.try_catch(|cx| {
+                let key = cx.undefined().upcast();
+
+                self.call(cx, value, Method::Has, &[key])
+            })
+            .is_ok()
+        })
+    }
+
+    // Converts an iterator of the collection to an array of its values
+    fn to_vec<'a, C: Context<'a>>(
+        self,
+        cx: &mut C,
+        this: raw::Local,
+        method: Method,
+    ) -> NeonResult<Vec<Handle<'a, JsValue>>> {
+        let iter = self.call(cx, this, method, &[])?;
+        let array_from = array_from(cx)?;
+        let undefined = cx.undefined();
+        let arr = array_from
+            .call(cx, undefined, [iter])?
+            .downcast_or_throw::<JsArray, _>(cx)?;
+
+        arr.to_vec(cx)
+    }
+
+    fn size<'a, C: Context<'a>>(self, cx: &mut C, this: raw::Local) -> NeonResult<usize> {
+        let size = self
+            .call(cx, this, Method::Size, &[])?
+            .downcast_or_throw::<JsNumber, _>(cx)?;
+
+        Ok(size.value(cx) as usize)
+    }
+}
+
+// Reads a method from the prototype of a collection
+fn lookup_method<'a, C: Context<'a>>(
+    cx: &mut C,
+    constructor: Handle<JsFunction>,
+    method: Method,
+) -> JsResult<'a, JsFunction> {
+    let prototype: Handle<JsObject> = constructor.get(cx, "prototype")?;
+
+    if method != Method::Size {
+        return prototype.get(cx, method.name());
+    }
+
+    let name = cx.string(method.name());
+    let descriptor: Handle<JsObject> = cx
+        .global::<JsFunction>("Object")?
+        .call_method_with(cx, "getOwnPropertyDescriptor")?
+        .arg(prototype)
+        .arg(name)
+        .apply(cx)?;
+
+    descriptor.get(cx, "get")
+}
+
+// `Array.from`, cached once per instance of the module like the collections
+#[cfg(feature = "napi-6")]
+fn array_from<'a, C: Context<'a>>(cx: &mut C) -> JsResult<'a, JsFunction> {
+    static ARRAY_FROM: LocalKey<Root<JsFunction>> = LocalKey::new();
+
+    let array_from = ARRAY_FROM.get_or_try_init(cx, |cx| {
+        cx.global::<JsFunction>("Array")?
+            .get::<JsFunction, _, _>(cx, "from")
+            .map(|from| from.root(cx))
+    })?;
+
+    Ok(array_from.to_inner(cx))
+}
+
+#[cfg(not(feature = "napi-6"))]
+fn array_from<'a, C: Context<'a>>(cx: &mut C) -> JsResult<'a, JsFunction> {
+    cx.global::<JsFunction>("Array")?.get(cx, "from")
+}
+
+/// The type of JavaScript
+/// [`Map`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map)
+/// objects.
+///
+/// Unlike a [`JsObject`], a `Map` may have keys of any type and keeps its
+/// entries in insertion order.
+///
+/// # Example
+///
+/// ```
+/// # use neon::prelude::*;
+/// use neon::types::JsMap;
+///
+/// // Counts the occurrences of each argument
+/// fn count(mut cx: FunctionContext) -> JsResult<JsMap> {
+///     let counts = JsMap::new(&mut cx)?;
+///
+///     for i in 0..cx.len() {
+///         let arg = cx.argument::<JsValue>(i)?;
+///         let n = match counts.get(&mut cx, arg)?.downcast::<JsNumber, _>(&mut cx) {
+///             Ok(n) => n.value(&mut cx),
+///             Err(_) => 0.0,
+///         };
+///         let n = cx.number(n + 1.0);
+///
+///         counts.set(&mut cx, arg, n)?;
+///     }
+///
+///     Ok(counts)
+/// }
+/// ```
+#[derive(Debug)]
+#[repr(transparent)]
+pub struct JsMap(raw::Local);
+
+impl JsMap {
+    /// Creates an empty `Map`.
+    pub fn new<'a, C: Context<'a>>(cx: &mut C) -> JsResult<'a, JsMap> {
+        let local = Builtin::Map.construct(cx)?;
+
+        Ok(Handle::new_internal(JsMap(local)))
+    }
+
+    /// Creates a `Map` from an iterator of key-value pairs, e.g., a
+    /// [`HashMap`](std::collections::HashMap).
+    pub fn from_entries<'a, C, K, V, I>(cx: &mut C, entries: I) -> JsResult<'a, JsMap>
+    where
+        C: Context<'a>,
+        K: TryIntoJs<'a>,
+        V: TryIntoJs<'a>,
+        I: IntoIterator<Item = (K, V)>,
+    {
+        let map = JsMap::new(cx)?;
+
+        for (k, v) in entries {
+            let k = k.try_into_js(cx)?;
+            let v = v.try_into_js(cx)?;
+
+            map.set(cx, k, v)?;
+        }
+
+        Ok(map)
+    }
+
+    /// Returns the value of `key`, or `undefined` if it is not in the `Map`.
+    pub fn get<'a, C: Context<'a>, K: Value>(
+        &self,
+        cx: &mut C,
+        key: Handle<K>,
+    ) -> JsResult<'a, JsValue> {
+        Builtin::Map.call(cx, self.0, Method::Get, &[key.upcast()])
+    }
+
+    /// Sets the value of `key`, replacing the existing value.
+    pub fn set<'a, C: Context<'a>, K: Value, V: Value>(
+        &self,
+        cx: &mut C,
+        key: Handle<K>,
+        value: Handle<V>,
+    ) -> NeonResult<()> {
+        Builtin::Map.call(cx, self.0, Method::Set, &[key.upcast(), value.upcast()])?;
+
+        Ok(())
+    }
+
+    /// Checks if `key` is in the `Map`.
+    pub fn has<'a, C: Context<'a>, K: Value>(
+        &self,
+        cx: &mut C,
+        key: Handle<K>,
+    ) -> NeonResult<bool> {
+        let has = Builtin::Map.call(cx, self.0, Method::Has, &[key.upcast()])?;
+
+        Ok(has.downcast_or_throw::<JsBoolean, _>(cx)?.value(cx))
+    }
+
+    /// Removes `key` from the `Map`. Returns `true` if it was in the `Map`.
+    pub fn delete<'a, C: Context<'a>, K: Value>(
+        &self,
+        cx: &mut C,
+        key: Handle<K>,
+    ) -> NeonResult<bool> {
+        let deleted = Builtin::Map.call(cx, self.0, Method::Delete, &[key.upcast()])?;
+
+        Ok(deleted.downcast_or_throw::<JsBoolean, _>(cx)?.value(cx))
+    }
+
+    /// Removes all entries from the `Map`.
+    pub fn clear<'a, C: Context<'a>>(&self, cx: &mut C) -> NeonResult<()> {
+        Builtin::Map.call(cx, self.0, Method::Clear, &[])?;
+
+        Ok(())
+    }
+
+    /// Returns the number of entries in the `Map`.
+    pub fn size<'a, C: Context<'a>>(&self, cx: &mut C) -> NeonResult<usize> {
+        Builtin::Map.size(cx, self.0)
+    }
+
+    /// Returns the entries of the `Map`, in insertion order.
+    pub fn entries<'a, C: Context<'a>>(
+        &self,
+        cx: &mut C,
+    ) -> NeonResult<Vec<(Handle<'a, JsValue>, Handle<'a, JsValue>)>> {
+        let entries = Builtin::Map.to_vec(cx, self.0, Method::Entries)?;
+        let mut pairs = Vec::with_capacity(entries.len());
+
+        for entry in entries {
+            let entry = entry.downcast_or_throw::<JsArray, _>(cx)?;
+            let k = entry.get_value(cx, 0)?;
+            let v = entry.get_value(cx, 1)?;
+
+            pairs.push((k, v));
+        }
+
+        Ok(pairs)
+    }
+
+    /// Converts the entries of the `Map` to a Rust collection, e.g., a
+    /// [`HashMap`](std::collections::HashMap). Throws a `TypeError` if a key
+    /// or a value cannot be converted, naming the entry and whether its key or
+    /// value failed, e.g., `[1].key: expected string`.
+    pub fn collect<'a, C, K, V, T>(&self, cx: &mut C) -> NeonResult<T>
+    where
+        C: Context<'a>,
+        K: TryFromJs<'a>,
+        V: TryFromJs<'a>,
+        T: FromIterator<(K, V)>,
+    {
+        self.entries(cx)?
+            .into_iter()
+            .enumerate()
+            .map(|(i, (k, v))| {
+                let k = match K::try_from_js(cx, k)? {
+                    Ok(k) => k,
+                    Err(err) => return err.field("key").index(i).throw(cx),
+                };
+
+                match V::try_from_js(cx, v)? {
+                    Ok(v) => Ok((k, v)),
+                    Err(err) => err.field("value").index(i).throw(cx),
+                }
+            })
+            .collect()
+    }
+}
+
+impl Value for JsMap {}
+
+unsafe impl TransparentNoCopyWrapper for JsMap {
+    type Inner = raw::Local;
+
+    fn into_inner(self) -> Self::Inner {
+        self.0
+    }
+}
+
+impl ValueInternal for JsMap {
+    fn name() -> String {
+        "Map".to_string()
+    }
+
+    fn ts_type() -> String {
+        "Map<unknown, unknown>".to_string()
+    }
+
+    fn is_typeof<Other: Value>(env: Env, other: &Other) -> bool {
+        Builtin::Map.is_instance(env, other.to_local())
+    }
+
+    fn to_local(&self) -> raw::Local {
+        self.0
+    }
+
+    unsafe fn from_local(_env: Env, h: raw::Local) -> Self {
+        JsMap(h)
+    }
+}
+
+impl Object for JsMap {}
+
+/// The type of JavaScript
+/// [`Set`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set)
+/// objects.
+///
+/// # Example
+///
+/// ```
+/// # use neon::prelude::*;
+/// use neon::types::JsSet;
+///
+/// // Checks if the argument is in a set of names
+/// fn is_reserved(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+///     let name = cx.argument::<JsString>(0)?;
+///     let reserved = JsSet::from_values(&mut cx, ["constructor", "prototype"])?;
+///     let is_reserved = reserved.has(&mut cx, name)?;
+///
+///     Ok(cx.boolean(is_reserved))
+/// }
+/// ```
+#[derive(Debug)]
+#[repr(transparent)]
+pub struct JsSet(raw::Local);
+
+impl JsSet {
+    /// Creates an empty `Set`.
+    pub fn new<'a, C: Context<'a>>(cx: &mut C) -> JsResult<'a, JsSet> {
+        let local = Builtin::Set.construct(cx)?;
+
+        Ok(Handle::new_internal(JsSet(local)))
+    }
+
+    /// Creates a `Set` from an iterator of values, e.g., a
+    /// [`HashSet`](std::collections::HashSet).
+    pub fn from_values<'a, C, T, I>(cx: &mut C, values: I) -> JsResult<'a, JsSet>
+    where
+        C: Context<'a>,
+        T: TryIntoJs<'a>,
+        I: IntoIterator<Item = T>,
+    {
+        let set = JsSet::new(cx)?;
+
+        for v in values {
+            let v = v.try_into_js(cx)?;
+
+            set.add(cx, v)?;
+        }
+
+        Ok(set)
+    }
+
+    /// Adds `value` to the `Set`.
+    pub fn add<'a, C: Context<'a>, V: Value>(
+        &self,
+        cx: &mut C,
+        value: Handle<V>,
+    ) -> NeonResult<()> {
+        Builtin::Set.call(cx, self.0, Method::Add, &[value.upcast()])?;
+
+        Ok(())
+    }
+
+    /// Checks if `value` is in the `Set`.
+    pub fn has<'a, C: Context<'a>, V: Value>(
+        &self,
+        cx: &mut C,
+        value: Handle<V>,
+    ) -> NeonResult<bool> {
+        let has = Builtin::Set.call(cx, self.0, Method::Has, &[value.upcast()])?;
+
+        Ok(has.downcast_or_throw::<JsBoolean, _>(cx)?.value(cx))
+    }
+
+    /// Removes `value` from the `Set`. Returns `true` if it was in the `Set`.
+    pub fn delete<'a, C: Context<'a>, V: Value>(
+        &self,
+        cx: &mut C,
+        value: Handle<V>,
+    ) -> NeonResult<bool> {
+        let deleted = Builtin::Set.call(cx, self.0, Method::Delete, &[value.upcast()])?;
+
+        Ok(deleted.downcast_or_throw::<JsBoolean, _>(cx)?.value(cx))
+    }
+
+    /// Removes all values from the `Set`.
+    pub fn clear<'a, C: Context<'a>>(&self, cx: &mut C) -> NeonResult<()> {
+        Builtin::Set.call(cx, self.0, Method::Clear, &[])?;
+
+        Ok(())
+    }
+
+    /// Returns the number of values in the `Set`.
+    pub fn size<'a, C: Context<'a>>(&self, cx: &mut C) -> NeonResult<usize> {
+        Builtin::Set.size(cx, self.0)
+    }
+
+    /// Returns the values of the `Set`, in insertion order.
+    pub fn values<'a, C: Context<'a>>(&self, cx: &mut C) -> NeonResult<Vec<Handle<'a, JsValue>>> {
+        Builtin::Set.to_vec(cx, self.0, Method::Values)
+    }
+
+    /// Converts the values of the `Set` to a Rust collection, e.g., a
+    /// [`HashSet`](std::collections::HashSet). Throws a `TypeError` if a value
+    /// cannot be converted.
+    pub fn collect<'a, C, V, T>(&self, cx: &mut C) -> NeonResult<T>
+    where
+        C: Context<'a>,
+        V: TryFromJs<'a>,
+        T: FromIterator<V>,
+    {
+        self.values(cx)?
+            .into_iter()
+            .map(|v| V::from_js(cx, v))
+            .collect()
+    }
+}
+
+impl Value for JsSet {}
+
+unsafe impl TransparentNoCopyWrapper for JsSet {
+    type Inner = raw::Local;
+
+    fn into_inner(self) -> Self::Inner {
+        self.0
+    }
+}
+
+impl ValueInternal for JsSet {
+    fn name() -> String {
+        "Set".to_string()
+    }
+
+    fn ts_type() -> String {
+        "Set<unknown>".to_string()
+    }
+
+    fn is_typeof<Other: Value>(env: Env, other: &Other) -> bool {
+        Builtin::Set.is_instance(env, other.to_local())
+    }
+
+    fn to_local(&self) -> raw::Local {
+        self.0
+    }
+
+    unsafe fn from_local(_env: Env, h: raw::Local) -> Self {
+        JsSet(h)
+    }
+}
+
+impl Object for JsSet {}
+
+/// The type of JavaScript
+/// [`WeakMap`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap)
+/// objects.
+///
+/// The keys of a `WeakMap` are objects, which are held weakly: an entry is
+/// removed when its key is garbage collected. Since its entries may be removed
+/// at any time, a `WeakMap` cannot be iterated.
+///
+/// # Example
+///
+/// ```
+/// # use neon::prelude::*;
+/// use neon::types::JsWeakMap;
+///
+/// // Associates metadata with an object without adding a property to it
+/// fn annotate(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+///     let annotations = cx.argument::<JsWeakMap>(0)?;
+///     let obj = cx.argument::<JsObject>(1)?;
+///     let note = cx.argument::<JsString>(2)?;
+///
+///     annotations.set(&mut cx, obj, note)?;
+///
+///     Ok(cx.undefined())
+/// }
+/// ```
+#[derive(Debug)]
+#[repr(transparent)]
+pub struct JsWeakMap(raw::Local);
+
+impl JsWeakMap {
+    /// Creates an empty `WeakMap`.
+    pub fn new<'a, C: Context<'a>>(cx: &mut C) -> JsResult<'a, JsWeakMap> {
+        let local = Builtin::WeakMap.construct(cx)?;
+
+        Ok(Handle::new_internal(JsWeakMap(local)))
+    }
+
+    /// Returns the value of `key`, or `undefined` if it is not in the `WeakMap`.
+    pub fn get<'a, C: Context<'a>, K: Object>(
+        &self,
+        cx: &mut C,
+        key: Handle<K>,
+    ) -> JsResult<'a, JsValue> {
+        Builtin::WeakMap.call(cx, self.0, Method::Get, &[key.upcast()])
+    }
+
+    /// Sets the value of `key`, replacing the existing value.
+    pub fn set<'a, C: Context<'a>, K: Object, V: Value>(
+        &self,
+        cx: &mut C,
+        key: Handle<K>,
+        value: Handle<V>,
+    ) -> NeonResult<()> {
+        Builtin::WeakMap.call(cx, self.0, Method::Set, &[key.upcast(), value.upcast()])?;
+
+        Ok(())
+    }
+
+    /// Checks if `key` is in the `WeakMap`.
+    pub fn has<'a, C: Context<'a>, K: Object>(
+        &self,
+        cx: &mut C,
+        key: Handle<K>,
+    ) -> NeonResult<bool> {
+        let has = Builtin::WeakMap.call(cx, self.0, Method::Has, &[key.upcast()])?;
+
+        Ok(has.downcast_or_throw::<JsBoolean, _>(cx)?.value(cx))
+    }
+
+    /// Removes `key` from the `WeakMap`. Returns `true` if it was in the `WeakMap`.
+    pub fn delete<'a, C: Context<'a>, K: Object>(
+        &self,
+        cx: &mut C,
+        key: Handle<K>,
+    ) -> NeonResult<bool> {
+        let deleted = Builtin::WeakMap.call(cx, self.0, Method::Delete, &[key.upcast()])?;
+
+        Ok(deleted.downcast_or_throw::<JsBoolean, _>(cx)?.value(cx))
+    }
+}
+
+impl Value for JsWeakMap {}
+
+unsafe impl TransparentNoCopyWrapper for JsWeakMap {
+    type Inner = raw::Local;
+
+    fn into_inner(self) -> Self::Inner {
+        self.0
+    }
+}
+
+impl ValueInternal for JsWeakMap {
+    fn name() -> String {
+        "WeakMap".to_string()
+    }
+
+    fn ts_type() -> String {
+        "WeakMap<object, unknown>".to_string()
+    }
+
+    fn is_typeof<Other: Value>(env: Env, other: &Other) -> bool {
+        Builtin::WeakMap.is_instance(env, other.to_local())
+    }
+
+    fn to_local(&self) -> raw::Local {
+        self.0
+    }
+
+    unsafe fn from_local(_env: Env, h: raw::Local) -> Self {
+        JsWeakMap(h)
+    }
+}
+
+impl Object for JsWeakMap {}
@@ -164,6 +164,10 @@ impl ValueInternal for JsDate {
         "object".to_string()
     }
 
+    fn ts_type() -> String {
+        "Date".to_string()
+    }
+
     fn is_typeof<Other: Value>(env: Env, other: &Other) -> bool {
         unsafe { sys::tag::is_date(env.to_raw(), other.to_local()) }
     }
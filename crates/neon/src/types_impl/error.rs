@@ -1,14 +1,18 @@
 //! Types and traits representing JavaScript error values.
 
-use std::panic::{catch_unwind, UnwindSafe};
+use std::{
+    error::Error,
+    panic::{catch_unwind, UnwindSafe},
+    ptr,
+};
 
 use crate::{
     context::{internal::Env, Context},
     handle::{internal::TransparentNoCopyWrapper, Handle},
     object::Object,
-    result::{NeonResult, Throw},
+    result::{JsResult, NeonResult, Throw},
     sys::{self, raw},
-    types::{build, private::ValueInternal, utf8::Utf8, Value},
+    types::{build, private::ValueInternal, utf8::Utf8, JsValue, Value},
 };
 
 /// The type of JavaScript
@@ -35,6 +39,21 @@ use crate::{
 /// # Ok(cx.undefined())
 /// # }
 /// ```
+///
+/// Errors with a `code`, a `cause` or other properties may also be created with
+/// [`ErrorOptions`]:
+///
+/// ```
+/// # use neon::prelude::*;
+/// # fn test(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+/// let expected = cx.string("number");
+///
+/// JsError::type_error_with(&cx, "expected a number")
+///     .code("ERR_INVALID_ARG_TYPE")
+///     .prop("expected", expected)
+///     .throw(&mut cx)
+/// # }
+/// ```
 #[repr(transparent)]
 #[derive(Debug)]
 pub struct JsError(raw::Local);
@@ -52,6 +71,10 @@ impl ValueInternal for JsError {
         "Error".to_string()
     }
 
+    fn ts_type() -> String {
+        "Error".to_string()
+    }
+
     fn is_typeof<Other: Value>(env: Env, other: &Other) -> bool {
         unsafe { sys::tag::is_error(env.to_raw(), other.to_local()) }
     }
@@ -111,13 +134,201 @@ impl JsError {
             true
         })
     }
+
+    /// Creates an [`ErrorOptions`] for building an instance of the
+    /// [`Error`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/Error) class.
+    pub fn error_with<'a, C: Context<'a>, S: AsRef<str>>(_cx: &C, msg: S) -> ErrorOptions<'a> {
+        ErrorOptions::new(ErrorKind::Error, msg.as_ref())
+    }
+
+    /// Creates an [`ErrorOptions`] for building an instance of the
+    /// [`TypeError`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/TypeError) class.
+    pub fn type_error_with<'a, C: Context<'a>, S: AsRef<str>>(_cx: &C, msg: S) -> ErrorOptions<'a> {
+        ErrorOptions::new(ErrorKind::TypeError, msg.as_ref())
+    }
+
+    /// Creates an [`ErrorOptions`] for building an instance of the
+    /// [`RangeError`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/RangeError) class.
+    pub fn range_error_with<'a, C: Context<'a>, S: AsRef<str>>(
+        _cx: &C,
+        msg: S,
+    ) -> ErrorOptions<'a> {
+        ErrorOptions::new(ErrorKind::RangeError, msg.as_ref())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum ErrorKind {
+    Error,
+    TypeError,
+    RangeError,
+}
+
+#[derive(Clone)]
+enum Cause<'a> {
+    Value(Handle<'a, JsValue>),
+    // Messages of a Rust error and each of its sources
+    Rust(Vec<String>),
+}
+
+/// A builder for creating a JavaScript error with a `code`, a `cause` and
+/// other properties.
+///
+/// ```
+/// # use neon::prelude::*;
+/// # fn test(mut cx: FunctionContext) -> JsResult<JsError> {
+/// # let io_error = std::io::Error::new(std::io::ErrorKind::Other, "disk full");
+/// let path = cx.string("data.json");
+/// let err = JsError::error_with(&cx, "failed to save")
+///     .code("ERR_SAVE")
+///     .cause_error(&io_error)
+///     .prop("path", path)
+///     .apply(&mut cx)?;
+/// # Ok(err)
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct ErrorOptions<'a> {
+    kind: ErrorKind,
+    message: String,
+    code: Option<String>,
+    cause: Option<Cause<'a>>,
+    props: Vec<(String, Handle<'a, JsValue>)>,
+}
+
+impl<'a> ErrorOptions<'a> {
+    pub(crate) fn new(kind: ErrorKind, message: &str) -> Self {
+        Self {
+            kind,
+            message: message.to_string(),
+            code: None,
+            cause: None,
+            props: Vec::new(),
+        }
+    }
+
+    /// Set the `code` property of the error, e.g., `ERR_INVALID_ARG_VALUE`.
+    pub fn code<S: AsRef<str>>(&mut self, code: S) -> &mut Self {
+        self.code = Some(code.as_ref().to_string());
+        self
+    }
+
+    /// Set the `cause` property of the error to a JavaScript value.
+    pub fn cause<V: Value>(&mut self, cause: Handle<'a, V>) -> &mut Self {
+        self.cause = Some(Cause::Value(cause.upcast()));
+        self
+    }
+
+    /// Set the `cause` property of the error to a Rust error. The error is
+    /// converted to an `Error` with its [`Display`](std::fmt::Display) message
+    /// and each of its [sources](Error::source) becomes the `cause` of the
+    /// previous error.
+    pub fn cause_error(&mut self, cause: &dyn Error) -> &mut Self {
+        self.cause_messages(error_chain(cause))
+    }
+
+    // Sets the cause to errors created from the messages of a Rust error and
+    // each of its sources
+    pub(crate) fn cause_messages(&mut self, messages: Vec<String>) -> &mut Self {
+        if !messages.is_empty() {
+            self.cause = Some(Cause::Rust(messages));
+        }
+
+        self
+    }
+
+    /// Add a property to the error.
+    pub fn prop<V: Value>(&mut self, key: &str, value: Handle<'a, V>) -> &mut Self {
+        self.props.push((key.to_string(), value.upcast()));
+        self
+    }
+
+    /// Create the error.
+    pub fn apply<'b: 'a, C: Context<'b>>(&self, cx: &mut C) -> JsResult<'b, JsError> {
+        let cause = match &self.cause {
+            Some(Cause::Value(v)) => Some(*v),
+            Some(Cause::Rust(messages)) => {
+                let mut cause: Option<Handle<JsValue>> = None;
+
+                // Errors are created from the innermost source outwards
+                for msg in messages.iter().rev() {
+                    let err = new_error(cx, ErrorKind::Error, None, msg)?;
+
+                    if let Some(cause) = cause {
+                        err.set(cx, "cause", cause)?;
+                    }
+
+                    cause = Some(err.upcast());
+                }
+
+                cause
+            }
+            None => None,
+        };
+
+        let err = new_error(cx, self.kind, self.code.as_deref(), &self.message)?;
+
+        if let Some(cause) = cause {
+            err.set(cx, "cause", cause)?;
+        }
+
+        for (key, value) in &self.props {
+            err.set(cx, key.as_str(), *value)?;
+        }
+
+        Ok(err)
+    }
+
+    /// Create and throw the error.
+    pub fn throw<'b: 'a, C: Context<'b>, T>(&self, cx: &mut C) -> NeonResult<T> {
+        let err = self.apply(cx)?;
+
+        cx.throw(err)
+    }
+}
+
+// Messages of an error and each of its sources
+pub(crate) fn error_chain(err: &dyn Error) -> Vec<String> {
+    let mut messages = vec![err.to_string()];
+    let mut source = err.source();
+
+    while let Some(err) = source {
+        messages.push(err.to_string());
+        source = err.source();
+    }
+
+    messages
+}
+
+fn new_error<'a, C: Context<'a>>(
+    cx: &mut C,
+    kind: ErrorKind,
+    code: Option<&str>,
+    msg: &str,
+) -> JsResult<'a, JsError> {
+    let code = code.map(|code| cx.string(code).to_local());
+    let msg = cx.string(msg);
+
+    build(cx.env(), |out| unsafe {
+        let env = cx.env().to_raw();
+        let code = code.unwrap_or(ptr::null_mut());
+        let msg = msg.to_local();
+
+        match kind {
+            ErrorKind::Error => sys::error::new_error_with_code(env, out, code, msg),
+            ErrorKind::TypeError => sys::error::new_type_error_with_code(env, out, code, msg),
+            ErrorKind::RangeError => sys::error::new_range_error_with_code(env, out, code, msg),
+        }
+
+        true
+    })
 }
 
 pub(crate) fn convert_panics<T, F: UnwindSafe + FnOnce() -> NeonResult<T>>(
     env: Env,
     f: F,
 ) -> NeonResult<T> {
-    match catch_unwind(f) {
+    match catch_unwind(|| crate::panic::boundary(f)) {
         Ok(result) => result,
         Err(panic) => {
             let msg = if let Some(string) = panic.downcast_ref::<String>() {
@@ -127,10 +338,19 @@ pub(crate) fn convert_panics<T, F: UnwindSafe + FnOnce() -> NeonResult<T>>(
             } else {
                 "internal error in Neon module".to_string()
             };
-            let (data, len) = Utf8::from(&msg[..]).truncate().lower();
+
             unsafe {
                 sys::error::clear_exception(env.to_raw());
-                sys::error::throw_error_from_utf8(env.to_raw(), data, len);
+
+                match crate::panic::create_error(env, &msg) {
+                    Some(err) => sys::error::throw(env.to_raw(), err),
+                    None => {
+                        let (data, len) = Utf8::from(&msg[..]).truncate().lower();
+
+                        sys::error::throw_error_from_utf8(env.to_raw(), data, len);
+                    }
+                }
+
                 Err(Throw::new())
             }
         }
@@ -0,0 +1,424 @@
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet},
+    hash::{BuildHasher, Hash},
+};
+
+use super::{downcast, ConversionError, TryFromJs, TryIntoJs};
+use crate::{
+    context::Context,
+    handle::Handle,
+    macro_internal::typescript,
+    object::{Object, PropertyDescriptor},
+    result::{JsResult, NeonResult, Throw},
+    types::{JsArray, JsNull, JsObject, JsSet, JsUndefined, JsValue},
+};
+
+#[cfg(feature = "napi-6")]
+use crate::types::JsString;
+
+/// `undefined` and `null` are converted to `None`
+impl<'cx, T: TryFromJs<'cx>> TryFromJs<'cx> for Option<T> {
+    fn try_from_js<C>(
+        cx: &mut C,
+        v: Handle<'cx, JsValue>,
+    ) -> NeonResult<Result<Self, ConversionError>>
+    where
+        C: Context<'cx>,
+    {
+        if v.is_a::<JsUndefined, _>(cx) || v.is_a::<JsNull, _>(cx) {
+            return Ok(Ok(None));
+        }
+
+        Ok(T::try_from_js(cx, v)?.map(Some))
+    }
+
+    fn ts_input() -> String {
+        typescript::optional(T::ts_input())
+    }
+}
+
+/// `None` is converted to `undefined`
+impl<'cx, T: TryIntoJs<'cx>> TryIntoJs<'cx> for Option<T> {
+    type Value = JsValue;
+
+    fn try_into_js<C>(self, cx: &mut C) -> JsResult<'cx, JsValue>
+    where
+        C: Context<'cx>,
+    {
+        match self {
+            Some(v) => Ok(v.try_into_js(cx)?.upcast()),
+            None => Ok(cx.undefined().upcast()),
+        }
+    }
+
+    fn ts_output() -> String {
+        typescript::optional(T::ts_output())
+    }
+}
+
+impl<'cx, T: TryFromJs<'cx>> TryFromJs<'cx> for Vec<T> {
+    fn try_from_js<C>(
+        cx: &mut C,
+        v: Handle<'cx, JsValue>,
+    ) -> NeonResult<Result<Self, ConversionError>>
+    where
+        C: Context<'cx>,
+    {
+        let arr = match downcast::<JsArray, _>(cx, v) {
+            Ok(arr) => arr,
+            Err(err) => return Ok(Err(err)),
+        };
+
+        let mut values = Vec::with_capacity(arr.len(cx) as usize);
+
+        for (i, v) in arr.to_vec(cx)?.into_iter().enumerate() {
+            match T::try_from_js(cx, v)? {
+                Ok(v) => values.push(v),
+                Err(err) => return Ok(Err(err.index(i))),
+            }
+        }
+
+        Ok(Ok(values))
+    }
+
+    fn ts_input() -> String {
+        typescript::array(T::ts_input())
+    }
+}
+
+impl<'cx, T: TryIntoJs<'cx>> TryIntoJs<'cx> for Vec<T> {
+    type Value = JsArray;
+
+    fn try_into_js<C>(self, cx: &mut C) -> JsResult<'cx, JsArray>
+    where
+        C: Context<'cx>,
+    {
+        let arr = JsArray::new(cx, self.len());
+
+        for (i, v) in self.into_iter().enumerate() {
+            let v = v.try_into_js(cx)?;
+
+            arr.set(cx, i as u32, v)?;
+        }
+
+        Ok(arr)
+    }
+
+    fn ts_output() -> String {
+        typescript::array(T::ts_output())
+    }
+}
+
+#[cfg(feature = "napi-6")]
+/// Converts the own string-keyed properties of an object
+impl<'cx, T, S> TryFromJs<'cx> for HashMap<String, T, S>
+where
+    T: TryFromJs<'cx>,
+    S: BuildHasher + Default,
+{
+    fn try_from_js<C>(
+        cx: &mut C,
+        v: Handle<'cx, JsValue>,
+    ) -> NeonResult<Result<Self, ConversionError>>
+    where
+        C: Context<'cx>,
+    {
+        let obj = match downcast::<JsObject, _>(cx, v) {
+            Ok(obj) => obj,
+            Err(err) => return Ok(Err(err)),
+        };
+
+        let keys = obj.get_own_property_names(cx)?.to_vec(cx)?;
+        let mut map = HashMap::with_capacity_and_hasher(keys.len(), S::default());
+
+        for key in keys {
+            let key = key.downcast_or_throw::<JsString, _>(cx)?;
+            let v = obj.get_value(cx, key)?;
+            let key = key.value(cx);
+
+            match T::try_from_js(cx, v)? {
+                Ok(v) => {
+                    map.insert(key, v);
+                }
+                Err(err) => return Ok(Err(err.field(key))),
+            }
+        }
+
+        Ok(Ok(map))
+    }
+
+    fn ts_input() -> String {
+        typescript::record(T::ts_input())
+    }
+}
+
+impl<'cx, K, T, S> TryIntoJs<'cx> for HashMap<K, T, S>
+where
+    K: AsRef<str> + Eq + Hash,
+    T: TryIntoJs<'cx>,
+    S: BuildHasher,
+{
+    type Value = JsObject;
+
+    fn try_into_js<C>(self, cx: &mut C) -> JsResult<'cx, JsObject>
+    where
+        C: Context<'cx>,
+    {
+        let obj = cx.empty_object();
+
+        for (k, v) in self {
+            let v = v.try_into_js(cx)?;
+            let descriptor = PropertyDescriptor::own_value(cx, k.as_ref(), v);
+
+            obj.define_property(cx, descriptor)?;
+        }
+
+        Ok(obj)
+    }
+
+    fn ts_output() -> String {
+        typescript::record(T::ts_output())
+    }
+}
+
+#[cfg(feature = "napi-6")]
+/// Converts the own string-keyed properties of an object, like [`HashMap`].
+/// A `Map` may be converted with [`JsMap::collect`](crate::types::JsMap::collect).
+impl<'cx, T> TryFromJs<'cx> for BTreeMap<String, T>
+where
+    T: TryFromJs<'cx>,
+{
+    fn try_from_js<C>(
+        cx: &mut C,
+        v: Handle<'cx, JsValue>,
+    ) -> NeonResult<Result<Self, ConversionError>>
+    where
+        C: Context<'cx>,
+    {
+        let obj = match downcast::<JsObject, _>(cx, v) {
+            Ok(obj) => obj,
+            Err(err) => return Ok(Err(err)),
+        };
+
+        let keys = obj.get_own_property_names(cx)?.to_vec(cx)?;
+        let mut map = BTreeMap::new();
+
+        for key in keys {
+            let key = key.downcast_or_throw::<JsString, _>(cx)?;
+            let v = obj.get_value(cx, key)?;
+            let key = key.value(cx);
+
+            match T::try_from_js(cx, v)? {
+                Ok(v) => {
+                    map.insert(key, v);
+                }
+                Err(err) => return Ok(Err(err.field(key))),
+            }
+        }
+
+        Ok(Ok(map))
+    }
+
+    fn ts_input() -> String {
+        typescript::record(T::ts_input())
+    }
+}
+
+/// Converted to an object, like [`HashMap`]. A `Map` may be created with
+/// [`JsMap::from_entries`](crate::types::JsMap::from_entries).
+impl<'cx, K, T> TryIntoJs<'cx> for BTreeMap<K, T>
+where
+    K: AsRef<str>,
+    T: TryIntoJs<'cx>,
+{
+    type Value = JsObject;
+
+    fn try_into_js<C>(self, cx: &mut C) -> JsResult<'cx, JsObject>
+    where
+        C: Context<'cx>,
+    {
+        let obj = cx.empty_object();
+
+        for (k, v) in self {
+            let v = v.try_into_js(cx)?;
+            let descriptor = PropertyDescriptor::own_value(cx, k.as_ref(), v);
+
+            obj.define_property(cx, descriptor)?;
+        }
+
+        Ok(obj)
+    }
+
+    fn ts_output() -> String {
+        typescript::record(T::ts_output())
+    }
+}
+
+// Sets are converted from and to a `Set`
+macro_rules! impl_set {
+    ($set:ident<T $(, $s:ident)?>: $($bound:tt)*) => {
+        impl<'cx, T $(, $s)?> TryFromJs<'cx> for $set<T $(, $s)?>
+        where
+            T: TryFromJs<'cx> + $($bound)*,
+            $($s: BuildHasher + Default,)?
+        {
+            fn try_from_js<C>(
+                cx: &mut C,
+                v: Handle<'cx, JsValue>,
+            ) -> NeonResult<Result<Self, ConversionError>>
+            where
+                C: Context<'cx>,
+            {
+                let set = match downcast::<JsSet, _>(cx, v) {
+                    Ok(set) => set,
+                    Err(err) => return Ok(Err(err)),
+                };
+
+                let mut values = $set::default();
+
+                for (i, v) in set.values(cx)?.into_iter().enumerate() {
+                    match T::try_from_js(cx, v)? {
+                        Ok(v) => {
+                            values.insert(v);
+                        }
+                        Err(err) => return Ok(Err(err.index(i))),
+                    }
+                }
+
+                Ok(Ok(values))
+            }
+
+            fn ts_input() -> String {
+                typescript::set(T::ts_input())
+            }
+        }
+
+        impl<'cx, T $(, $s)?> TryIntoJs<'cx> for $set<T $(, $s)?>
+        where
+            T: TryIntoJs<'cx>,
+        {
+            type Value = JsSet;
+
+            fn try_into_js<C>(self, cx: &mut C) -> JsResult<'cx, JsSet>
+            where
+                C: Context<'cx>,
+            {
+                JsSet::from_values(cx, self)
+            }
+
+            fn ts_output() -> String {
+                typescript::set(T::ts_output())
+            }
+        }
+    };
+}
+
+impl_set!(HashSet<T, S>: Eq + Hash);
+impl_set!(BTreeSet<T>: Ord);
+
+// Tuples are converted from and to arrays of the same length
+macro_rules! impl_tuple {
+    ($len:literal; $($t:ident: $i:tt),*) => {
+        impl<'cx, $($t: TryFromJs<'cx>),*> TryFromJs<'cx> for ($($t,)*) {
+            fn try_from_js<C>(
+                cx: &mut C,
+                v: Handle<'cx, JsValue>,
+            ) -> NeonResult<Result<Self, ConversionError>>
+            where
+                C: Context<'cx>,
+            {
+                let arr = match downcast::<JsArray, _>(cx, v) {
+                    Ok(arr) if arr.len(cx) == $len => arr,
+                    _ => return Ok(Err(ConversionError::new(concat!("Array of length ", $len)))),
+                };
+
+                Ok(Ok(($({
+                    let v = arr.get_value(cx, $i as u32)?;
+
+                    match $t::try_from_js(cx, v)? {
+                        Ok(v) => v,
+                        Err(err) => return Ok(Err(err.index($i))),
+                    }
+                },)*)))
+            }
+
+            fn ts_input() -> String {
+                typescript::tuple(vec![$($t::ts_input()),*])
+            }
+        }
+
+        impl<'cx, $($t: TryIntoJs<'cx>),*> TryIntoJs<'cx> for ($($t,)*) {
+            type Value = JsArray;
+
+            fn try_into_js<C>(self, cx: &mut C) -> JsResult<'cx, JsArray>
+            where
+                C: Context<'cx>,
+            {
+                let arr = JsArray::new(cx, $len);
+
+                $(
+                    let v = self.$i.try_into_js(cx)?;
+                    arr.set(cx, $i as u32, v)?;
+                )*
+
+                Ok(arr)
+            }
+
+            fn ts_output() -> String {
+                typescript::tuple(vec![$($t::ts_output()),*])
+            }
+        }
+    };
+}
+
+impl_tuple!(1; T0: 0);
+impl_tuple!(2; T0: 0, T1: 1);
+impl_tuple!(3; T0: 0, T1: 1, T2: 2);
+impl_tuple!(4; T0: 0, T1: 1, T2: 2, T3: 3);
+impl_tuple!(5; T0: 0, T1: 1, T2: 2, T3: 3, T4: 4);
+impl_tuple!(6; T0: 0, T1: 1, T2: 2, T3: 3, T4: 4, T5: 5);
+impl_tuple!(7; T0: 0, T1: 1, T2: 2, T3: 3, T4: 4, T5: 5, T6: 6);
+impl_tuple!(8; T0: 0, T1: 1, T2: 2, T3: 3, T4: 4, T5: 5, T6: 6, T7: 7);
+
+/// The error value of `Err` is converted and thrown
+impl<'cx, T, E> TryIntoJs<'cx> for Result<T, E>
+where
+    T: TryIntoJs<'cx>,
+    E: TryIntoJs<'cx>,
+{
+    type Value = JsValue;
+
+    fn try_into_js<C>(self, cx: &mut C) -> JsResult<'cx, JsValue>
+    where
+        C: Context<'cx>,
+    {
+        match self {
+            Ok(v) => Ok(v.try_into_js(cx)?.upcast()),
+            Err(err) => {
+                let err = err.try_into_js(cx)?;
+
+                cx.throw(err)
+            }
+        }
+    }
+
+    fn ts_output() -> String {
+        T::ts_output()
+    }
+}
+
+/// `Err(Throw)` propagates the pending exception
+impl<'cx, T: TryIntoJs<'cx>> TryIntoJs<'cx> for Result<T, Throw> {
+    type Value = T::Value;
+
+    fn try_into_js<C>(self, cx: &mut C) -> JsResult<'cx, T::Value>
+    where
+        C: Context<'cx>,
+    {
+        self?.try_into_js(cx)
+    }
+
+    fn ts_output() -> String {
+        T::ts_output()
+    }
+}
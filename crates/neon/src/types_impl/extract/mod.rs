@@ -0,0 +1,202 @@
+//! Traits for converting values between Rust and JavaScript.
+//!
+//! [`TryFromJs`] extracts a Rust value from a JavaScript value and
+//! [`TryIntoJs`] produces a JavaScript value from a Rust value. These traits
+//! drive the argument and return value conversions of
+//! [`#[neon::export]`](crate::export) functions, but they may also be used
+//! directly.
+//!
+//! ```
+//! # use neon::prelude::*;
+//! use neon::types::extract::{TryFromJs, TryIntoJs};
+//!
+//! fn greet(mut cx: FunctionContext) -> JsResult<JsValue> {
+//!     let arg = cx.argument::<JsValue>(0)?;
+//!     let name = String::from_js(&mut cx, arg)?;
+//!
+//!     format!("Hello, {}!", name).try_into_js(&mut cx).map(|v| v.upcast())
+//! }
+//! ```
+//!
+//! A value that is not of the expected type produces a [`ConversionError`]
+//! instead of throwing. This allows callers to fall back to another
+//! representation or to add context before throwing a `TypeError`.
+//!
+//! ## Deriving
+//!
+//! Both traits may be derived for structs with named fields, which are
+//! converted from and to objects with `camelCase` property names, and for
+//! enums. See [`macro@TryFromJs`] for the supported options.
+//!
+//! ```
+//! use neon::types::extract::{TryFromJs, TryIntoJs};
+//!
+//! #[derive(TryFromJs, TryIntoJs)]
+//! struct Options {
+//!     max_retries: u32,
+//!     #[neon(default)]
+//!     verbose: bool,
+//!     mode: Mode,
+//! }
+//!
+//! #[derive(TryFromJs, TryIntoJs)]
+//! enum Mode {
+//!     Fast,
+//!     Safe,
+//! }
+//!
+//! #[neon::export]
+//! fn configure(options: Options) -> u32 {
+//!     options.max_retries
+//! }
+//! ```
+
+use std::{borrow::Cow, error, fmt};
+
+use crate::{
+    context::Context,
+    handle::Handle,
+    result::{JsResult, NeonResult},
+    types::{JsValue, Value},
+};
+
+pub use neon_macros::{TryFromJs, TryIntoJs};
+
+pub use self::value::{ArrayBuffer, Buffer};
+
+mod container;
+mod number;
+mod value;
+
+/// Extract a Rust value from a JavaScript value
+pub trait TryFromJs<'cx>: Sized {
+    /// Attempt to convert a JavaScript value to `Self`.
+    ///
+    /// Returns `Ok(Err(_))` if the value is not of the expected type. An
+    /// `Err(Throw)` indicates that a JavaScript exception is pending.
+    fn try_from_js<C>(
+        cx: &mut C,
+        v: Handle<'cx, JsValue>,
+    ) -> NeonResult<Result<Self, ConversionError>>
+    where
+        C: Context<'cx>;
+
+    /// Convert a JavaScript value to `Self`, throwing a `TypeError` if the value
+    /// is not of the expected type.
+    fn from_js<C>(cx: &mut C, v: Handle<'cx, JsValue>) -> NeonResult<Self>
+    where
+        C: Context<'cx>,
+    {
+        match Self::try_from_js(cx, v)? {
+            Ok(v) => Ok(v),
+            Err(err) => err.throw(cx),
+        }
+    }
+
+    /// TypeScript type of the values accepted by [`try_from_js`](TryFromJs::try_from_js),
+    /// used to [generate declarations](crate::typescript). Defaults to `unknown`.
+    fn ts_input() -> String {
+        "unknown".to_string()
+    }
+}
+
+/// Convert a Rust value into a JavaScript value
+pub trait TryIntoJs<'cx> {
+    /// The type of JavaScript value produced
+    type Value: Value;
+
+    /// Convert `self` into a JavaScript value. Conversions may throw.
+    fn try_into_js<C>(self, cx: &mut C) -> JsResult<'cx, Self::Value>
+    where
+        C: Context<'cx>;
+
+    /// TypeScript type of the values produced by [`try_into_js`](TryIntoJs::try_into_js),
+    /// used to [generate declarations](crate::typescript). Defaults to `unknown`.
+    fn ts_output() -> String {
+        "unknown".to_string()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum PathSegment {
+    Field(Cow<'static, str>),
+    Index(usize),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// Error returned when a JavaScript value is not of the type expected by a
+/// [`TryFromJs`] conversion.
+///
+/// The error records the location of the value that failed to convert, for
+/// example, `options.retry.max: expected number`.
+pub struct ConversionError {
+    expected: Cow<'static, str>,
+    path: Vec<PathSegment>,
+}
+
+impl ConversionError {
+    /// Create an error describing the expected type of a value.
+    pub fn new<S: Into<Cow<'static, str>>>(expected: S) -> Self {
+        Self {
+            expected: expected.into(),
+            path: Vec::new(),
+        }
+    }
+
+    /// Description of the expected type
+    pub fn expected(&self) -> &str {
+        &self.expected
+    }
+
+    /// Record that the failing value was found at the property `name` of
+    /// its parent. Conversions of nested values should call this while
+    /// propagating an error so the message names the full path.
+    pub fn field<S: Into<Cow<'static, str>>>(mut self, name: S) -> Self {
+        self.path.insert(0, PathSegment::Field(name.into()));
+        self
+    }
+
+    /// Record that the failing value was found at index `i` of its parent.
+    pub fn index(mut self, i: usize) -> Self {
+        self.path.insert(0, PathSegment::Index(i));
+        self
+    }
+
+    /// Throw the error as a JavaScript `TypeError`.
+    pub fn throw<'cx, C: Context<'cx>, T>(&self, cx: &mut C) -> NeonResult<T> {
+        cx.throw_type_error(self.to_string())
+    }
+}
+
+impl fmt::Display for ConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, segment) in self.path.iter().enumerate() {
+            match segment {
+                PathSegment::Field(name) if i == 0 => write!(f, "{}", name)?,
+                PathSegment::Field(name) => write!(f, ".{}", name)?,
+                PathSegment::Index(i) => write!(f, "[{}]", i)?,
+            }
+        }
+
+        if !self.path.is_empty() {
+            f.write_str(": ")?;
+        }
+
+        write!(f, "expected {}", self.expected)
+    }
+}
+
+impl error::Error for ConversionError {}
+
+// Downcasts a value, producing a `ConversionError` with the name of the
+// expected type on failure
+fn downcast<'cx, V, C>(
+    cx: &mut C,
+    v: Handle<'cx, JsValue>,
+) -> Result<Handle<'cx, V>, ConversionError>
+where
+    V: Value,
+    C: Context<'cx>,
+{
+    v.downcast(cx).map_err(|_| ConversionError::new(V::name()))
+}
@@ -0,0 +1,137 @@
+use super::{downcast, ConversionError, TryFromJs, TryIntoJs};
+use crate::{
+    context::Context,
+    handle::Handle,
+    result::{JsResult, NeonResult},
+    types::{JsNumber, JsValue},
+};
+
+// Largest integer that can be represented exactly by a JavaScript number
+const MAX_SAFE_INTEGER: f64 = 9_007_199_254_740_991.0;
+
+impl<'cx> TryFromJs<'cx> for f64 {
+    fn try_from_js<C>(
+        cx: &mut C,
+        v: Handle<'cx, JsValue>,
+    ) -> NeonResult<Result<Self, ConversionError>>
+    where
+        C: Context<'cx>,
+    {
+        Ok(downcast::<JsNumber, _>(cx, v).map(|v| v.value(cx)))
+    }
+
+    fn ts_input() -> String {
+        "number".to_string()
+    }
+}
+
+impl<'cx> TryIntoJs<'cx> for f64 {
+    type Value = JsNumber;
+
+    fn try_into_js<C>(self, cx: &mut C) -> JsResult<'cx, JsNumber>
+    where
+        C: Context<'cx>,
+    {
+        Ok(cx.number(self))
+    }
+
+    fn ts_output() -> String {
+        "number".to_string()
+    }
+}
+
+/// Converts a number to the nearest `f32`
+impl<'cx> TryFromJs<'cx> for f32 {
+    fn try_from_js<C>(
+        cx: &mut C,
+        v: Handle<'cx, JsValue>,
+    ) -> NeonResult<Result<Self, ConversionError>>
+    where
+        C: Context<'cx>,
+    {
+        Ok(f64::try_from_js(cx, v)?.map(|v| v as f32))
+    }
+
+    fn ts_input() -> String {
+        "number".to_string()
+    }
+}
+
+impl<'cx> TryIntoJs<'cx> for f32 {
+    type Value = JsNumber;
+
+    fn try_into_js<C>(self, cx: &mut C) -> JsResult<'cx, JsNumber>
+    where
+        C: Context<'cx>,
+    {
+        Ok(cx.number(self))
+    }
+
+    fn ts_output() -> String {
+        "number".to_string()
+    }
+}
+
+// Integers are converted from numbers that are integral and in range of both
+// the integer type and the safe integers of JavaScript. Converting to a number
+// throws a `RangeError` if it cannot be represented exactly.
+macro_rules! impl_integer {
+    ($($t:ty),* $(,)?) => {$(
+        impl<'cx> TryFromJs<'cx> for $t {
+            fn try_from_js<C>(
+                cx: &mut C,
+                v: Handle<'cx, JsValue>,
+            ) -> NeonResult<Result<Self, ConversionError>>
+            where
+                C: Context<'cx>,
+            {
+                let min = (<$t>::MIN as f64).max(-MAX_SAFE_INTEGER);
+                let max = (<$t>::MAX as f64).min(MAX_SAFE_INTEGER);
+                let v = match downcast::<JsNumber, _>(cx, v) {
+                    Ok(v) => v.value(cx),
+                    Err(_) => return Ok(Err(expected_integer(min, max))),
+                };
+
+                if v.fract() == 0.0 && v >= min && v <= max {
+                    Ok(Ok(v as $t))
+                } else {
+                    Ok(Err(expected_integer(min, max)))
+                }
+            }
+
+            fn ts_input() -> String {
+                "number".to_string()
+            }
+        }
+
+        impl<'cx> TryIntoJs<'cx> for $t {
+            type Value = JsNumber;
+
+            fn try_into_js<C>(self, cx: &mut C) -> JsResult<'cx, JsNumber>
+            where
+                C: Context<'cx>,
+            {
+                let v = self as f64;
+
+                if v.abs() > MAX_SAFE_INTEGER {
+                    return cx.throw_range_error(format!(
+                        "{} cannot be represented exactly as a number",
+                        self,
+                    ));
+                }
+
+                Ok(cx.number(v))
+            }
+
+            fn ts_output() -> String {
+                "number".to_string()
+            }
+        }
+    )*};
+}
+
+impl_integer!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+fn expected_integer(min: f64, max: f64) -> ConversionError {
+    ConversionError::new(format!("integer between {} and {}", min, max))
+}
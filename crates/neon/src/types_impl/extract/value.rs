@@ -0,0 +1,351 @@
+use super::{downcast, ConversionError, TryFromJs, TryIntoJs};
+use crate::{
+    context::Context,
+    handle::Handle,
+    result::{JsResult, NeonResult},
+    types::{
+        buffer::TypedArray, JsArrayBuffer, JsBoolean, JsBuffer, JsString, JsTypedArray,
+        JsUndefined, JsValue, Value,
+    },
+};
+
+#[cfg(feature = "napi-5")]
+use {
+    crate::{result::ResultExt, types::JsDate},
+    std::time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+#[cfg(feature = "napi-6")]
+use crate::types::JsBigInt;
+
+impl<'cx, V: Value> TryFromJs<'cx> for Handle<'cx, V> {
+    fn try_from_js<C>(
+        cx: &mut C,
+        v: Handle<'cx, JsValue>,
+    ) -> NeonResult<Result<Self, ConversionError>>
+    where
+        C: Context<'cx>,
+    {
+        Ok(downcast(cx, v))
+    }
+
+    fn ts_input() -> String {
+        V::ts_type()
+    }
+}
+
+impl<'cx, V: Value> TryIntoJs<'cx> for Handle<'cx, V> {
+    type Value = V;
+
+    fn try_into_js<C>(self, _cx: &mut C) -> JsResult<'cx, V>
+    where
+        C: Context<'cx>,
+    {
+        Ok(self)
+    }
+
+    fn ts_output() -> String {
+        V::ts_type()
+    }
+}
+
+impl<'cx> TryFromJs<'cx> for bool {
+    fn try_from_js<C>(
+        cx: &mut C,
+        v: Handle<'cx, JsValue>,
+    ) -> NeonResult<Result<Self, ConversionError>>
+    where
+        C: Context<'cx>,
+    {
+        Ok(downcast::<JsBoolean, _>(cx, v).map(|v| v.value(cx)))
+    }
+
+    fn ts_input() -> String {
+        "boolean".to_string()
+    }
+}
+
+impl<'cx> TryIntoJs<'cx> for bool {
+    type Value = JsBoolean;
+
+    fn try_into_js<C>(self, cx: &mut C) -> JsResult<'cx, JsBoolean>
+    where
+        C: Context<'cx>,
+    {
+        Ok(cx.boolean(self))
+    }
+
+    fn ts_output() -> String {
+        "boolean".to_string()
+    }
+}
+
+impl<'cx> TryFromJs<'cx> for String {
+    fn try_from_js<C>(
+        cx: &mut C,
+        v: Handle<'cx, JsValue>,
+    ) -> NeonResult<Result<Self, ConversionError>>
+    where
+        C: Context<'cx>,
+    {
+        Ok(downcast::<JsString, _>(cx, v).map(|v| v.value(cx)))
+    }
+
+    fn ts_input() -> String {
+        "string".to_string()
+    }
+}
+
+impl<'cx> TryIntoJs<'cx> for String {
+    type Value = JsString;
+
+    fn try_into_js<C>(self, cx: &mut C) -> JsResult<'cx, JsString>
+    where
+        C: Context<'cx>,
+    {
+        Ok(cx.string(self))
+    }
+
+    fn ts_output() -> String {
+        "string".to_string()
+    }
+}
+
+impl<'cx> TryIntoJs<'cx> for &str {
+    type Value = JsString;
+
+    fn try_into_js<C>(self, cx: &mut C) -> JsResult<'cx, JsString>
+    where
+        C: Context<'cx>,
+    {
+        Ok(cx.string(self))
+    }
+
+    fn ts_output() -> String {
+        "string".to_string()
+    }
+}
+
+impl<'cx> TryIntoJs<'cx> for () {
+    type Value = JsUndefined;
+
+    fn try_into_js<C>(self, cx: &mut C) -> JsResult<'cx, JsUndefined>
+    where
+        C: Context<'cx>,
+    {
+        Ok(cx.undefined())
+    }
+
+    fn ts_output() -> String {
+        "undefined".to_string()
+    }
+}
+
+#[cfg(feature = "napi-6")]
+impl<'cx> TryFromJs<'cx> for i128 {
+    fn try_from_js<C>(
+        cx: &mut C,
+        v: Handle<'cx, JsValue>,
+    ) -> NeonResult<Result<Self, ConversionError>>
+    where
+        C: Context<'cx>,
+    {
+        let v = match downcast::<JsBigInt, _>(cx, v) {
+            Ok(v) => v,
+            Err(err) => return Ok(Err(err)),
+        };
+
+        Ok(v.to_i128(cx)
+            .map_err(|_| ConversionError::new("bigint in the range of i128")))
+    }
+
+    fn ts_input() -> String {
+        "bigint".to_string()
+    }
+}
+
+#[cfg(feature = "napi-6")]
+impl<'cx> TryIntoJs<'cx> for i128 {
+    type Value = JsBigInt;
+
+    fn try_into_js<C>(self, cx: &mut C) -> JsResult<'cx, JsBigInt>
+    where
+        C: Context<'cx>,
+    {
+        Ok(JsBigInt::from_i128(cx, self))
+    }
+
+    fn ts_output() -> String {
+        "bigint".to_string()
+    }
+}
+
+#[cfg(feature = "napi-6")]
+impl<'cx> TryFromJs<'cx> for u128 {
+    fn try_from_js<C>(
+        cx: &mut C,
+        v: Handle<'cx, JsValue>,
+    ) -> NeonResult<Result<Self, ConversionError>>
+    where
+        C: Context<'cx>,
+    {
+        let v = match downcast::<JsBigInt, _>(cx, v) {
+            Ok(v) => v,
+            Err(err) => return Ok(Err(err)),
+        };
+
+        Ok(v.to_u128(cx)
+            .map_err(|_| ConversionError::new("bigint in the range of u128")))
+    }
+
+    fn ts_input() -> String {
+        "bigint".to_string()
+    }
+}
+
+#[cfg(feature = "napi-6")]
+impl<'cx> TryIntoJs<'cx> for u128 {
+    type Value = JsBigInt;
+
+    fn try_into_js<C>(self, cx: &mut C) -> JsResult<'cx, JsBigInt>
+    where
+        C: Context<'cx>,
+    {
+        Ok(JsBigInt::from_u128(cx, self))
+    }
+
+    fn ts_output() -> String {
+        "bigint".to_string()
+    }
+}
+
+#[cfg(feature = "napi-5")]
+/// Converts a `Date` to the time it represents. Invalid dates fail to convert.
+impl<'cx> TryFromJs<'cx> for SystemTime {
+    fn try_from_js<C>(
+        cx: &mut C,
+        v: Handle<'cx, JsValue>,
+    ) -> NeonResult<Result<Self, ConversionError>>
+    where
+        C: Context<'cx>,
+    {
+        let v = match downcast::<JsDate, _>(cx, v) {
+            Ok(v) => v.value(cx),
+            Err(err) => return Ok(Err(err)),
+        };
+
+        if v.is_nan() {
+            return Ok(Err(ConversionError::new("valid Date")));
+        }
+
+        // Time values are an integral number of milliseconds
+        let offset = Duration::from_millis(v.abs() as u64);
+        let time = if v < 0.0 {
+            UNIX_EPOCH.checked_sub(offset)
+        } else {
+            UNIX_EPOCH.checked_add(offset)
+        };
+
+        Ok(time.ok_or_else(|| ConversionError::new("Date in the range of SystemTime")))
+    }
+
+    fn ts_input() -> String {
+        "Date".to_string()
+    }
+}
+
+#[cfg(feature = "napi-5")]
+/// Converts a time to a `Date`, truncating to millisecond precision. Throws a
+/// `RangeError` if the time is outside the range of a `Date`.
+impl<'cx> TryIntoJs<'cx> for SystemTime {
+    type Value = JsDate;
+
+    fn try_into_js<C>(self, cx: &mut C) -> JsResult<'cx, JsDate>
+    where
+        C: Context<'cx>,
+    {
+        let v = match self.duration_since(UNIX_EPOCH) {
+            Ok(offset) => offset.as_millis() as f64,
+            Err(err) => -(err.duration().as_millis() as f64),
+        };
+
+        JsDate::new(cx, v).or_throw(cx)
+    }
+
+    fn ts_output() -> String {
+        "Date".to_string()
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+/// Bytes converted from and to a Node.js [`Buffer`](JsBuffer)
+///
+/// Converting from JavaScript copies the contents of any `Uint8Array`,
+/// including a `Buffer`.
+pub struct Buffer(pub Vec<u8>);
+
+impl<'cx> TryFromJs<'cx> for Buffer {
+    fn try_from_js<C>(
+        cx: &mut C,
+        v: Handle<'cx, JsValue>,
+    ) -> NeonResult<Result<Self, ConversionError>>
+    where
+        C: Context<'cx>,
+    {
+        Ok(downcast::<JsTypedArray<u8>, _>(cx, v).map(|v| Buffer(v.as_slice(cx).to_vec())))
+    }
+
+    fn ts_input() -> String {
+        "Uint8Array".to_string()
+    }
+}
+
+impl<'cx> TryIntoJs<'cx> for Buffer {
+    type Value = JsBuffer;
+
+    fn try_into_js<C>(self, cx: &mut C) -> JsResult<'cx, JsBuffer>
+    where
+        C: Context<'cx>,
+    {
+        JsBuffer::from_slice(cx, &self.0)
+    }
+
+    fn ts_output() -> String {
+        "Buffer".to_string()
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+/// Bytes converted from and to an [`ArrayBuffer`](JsArrayBuffer)
+pub struct ArrayBuffer(pub Vec<u8>);
+
+impl<'cx> TryFromJs<'cx> for ArrayBuffer {
+    fn try_from_js<C>(
+        cx: &mut C,
+        v: Handle<'cx, JsValue>,
+    ) -> NeonResult<Result<Self, ConversionError>>
+    where
+        C: Context<'cx>,
+    {
+        Ok(downcast::<JsArrayBuffer, _>(cx, v).map(|v| ArrayBuffer(v.as_slice(cx).to_vec())))
+    }
+
+    fn ts_input() -> String {
+        "ArrayBuffer".to_string()
+    }
+}
+
+impl<'cx> TryIntoJs<'cx> for ArrayBuffer {
+    type Value = JsArrayBuffer;
+
+    fn try_into_js<C>(self, cx: &mut C) -> JsResult<'cx, JsArrayBuffer>
+    where
+        C: Context<'cx>,
+    {
+        JsArrayBuffer::from_slice(cx, &self.0)
+    }
+
+    fn ts_output() -> String {
+        "ArrayBuffer".to_string()
+    }
+}
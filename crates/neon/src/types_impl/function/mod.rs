@@ -1,13 +1,16 @@
 //! Types and traits for working with JavaScript functions.
 
+use std::{panic::AssertUnwindSafe, ptr};
+
 use smallvec::smallvec;
 
 use crate::{
-    context::Context,
+    context::{CallbackInfo, Context, FunctionContext},
     handle::Handle,
     object::Object,
-    result::{JsResult, NeonResult},
-    types::{JsFunction, JsObject, JsValue, Value},
+    result::{JsResult, NeonResult, Throw},
+    sys::{self, raw},
+    types::{error::convert_panics, extract::TryIntoJs, JsFunction, JsObject, JsValue, Value},
 };
 
 pub(crate) mod private;
@@ -215,3 +218,85 @@ impl_arguments! {
         (V32, v32),
     ];
 }
+
+/// The trait for specifying arguments that are converted with [`TryIntoJs`] before
+/// a function call. This trait is sealed and cannot be implemented by types outside
+/// of the Neon crate.
+///
+/// This trait is implemented for tuples of up to 8 values.
+pub trait TryIntoArguments<'cx>: private::TryIntoArgumentsInternal<'cx> {}
+
+impl<'cx> private::TryIntoArgumentsInternal<'cx> for () {
+    fn try_into_args_vec<C: Context<'cx>>(self, _cx: &mut C) -> NeonResult<private::ArgsVec<'cx>> {
+        Ok(smallvec![])
+    }
+}
+
+impl<'cx> TryIntoArguments<'cx> for () {}
+
+macro_rules! impl_try_into_arguments {
+    ($(($t:ident, $v:ident)),*) => {
+        impl<'cx, $($t: TryIntoJs<'cx>),*> private::TryIntoArgumentsInternal<'cx> for ($($t,)*) {
+            fn try_into_args_vec<C: Context<'cx>>(
+                self,
+                cx: &mut C,
+            ) -> NeonResult<private::ArgsVec<'cx>> {
+                let ($($v,)*) = self;
+
+                Ok(smallvec![$($v.try_into_js(cx)?.upcast(),)*])
+            }
+        }
+
+        impl<'cx, $($t: TryIntoJs<'cx>),*> TryIntoArguments<'cx> for ($($t,)*) {}
+    };
+}
+
+impl_try_into_arguments!((T1, v1));
+impl_try_into_arguments!((T1, v1), (T2, v2));
+impl_try_into_arguments!((T1, v1), (T2, v2), (T3, v3));
+impl_try_into_arguments!((T1, v1), (T2, v2), (T3, v3), (T4, v4));
+impl_try_into_arguments!((T1, v1), (T2, v2), (T3, v3), (T4, v4), (T5, v5));
+impl_try_into_arguments!((T1, v1), (T2, v2), (T3, v3), (T4, v4), (T5, v5), (T6, v6));
+impl_try_into_arguments!(
+    (T1, v1),
+    (T2, v2),
+    (T3, v3),
+    (T4, v4),
+    (T5, v5),
+    (T6, v6),
+    (T7, v7)
+);
+impl_try_into_arguments!(
+    (T1, v1),
+    (T2, v2),
+    (T3, v3),
+    (T4, v4),
+    (T5, v5),
+    (T6, v6),
+    (T7, v7),
+    (T8, v8)
+);
+
+// Adapts a Neon function to a Node-API callback. Panics are converted to
+// JavaScript exceptions.
+pub(crate) fn callback<F, V>(f: F) -> impl Fn(raw::Env, sys::CallbackInfo) -> raw::Local + 'static
+where
+    F: Fn(FunctionContext) -> JsResult<V> + 'static,
+    V: Value,
+{
+    move |env: raw::Env, info| {
+        let env = env.into();
+        let info = unsafe { CallbackInfo::new(info) };
+
+        FunctionContext::with(env, &info, |cx| {
+            convert_panics(env, AssertUnwindSafe(|| f(cx)))
+                .map(|v| v.to_local())
+                // We do not have a Js Value to return, most likely due to an exception.
+                // If we are in a throwing state, constructing a Js Value would be invalid.
+                // While not explicitly written, the Node-API documentation includes many examples
+                // of returning `NULL` when a native function does not return a value.
+                // https://nodejs.org/api/n-api.html#n_api_napi_create_function
+                .unwrap_or_else(|_: Throw| ptr::null_mut())
+        })
+    }
+}
@@ -1,6 +1,6 @@
 use smallvec::SmallVec;
 
-use crate::{handle::Handle, types::JsValue};
+use crate::{context::Context, handle::Handle, result::NeonResult, types::JsValue};
 
 pub type ArgsVec<'a> = SmallVec<[Handle<'a, JsValue>; 8]>;
 
@@ -8,3 +8,8 @@ pub type ArgsVec<'a> = SmallVec<[Handle<'a, JsValue>; 8]>;
 pub trait ArgumentsInternal<'a> {
     fn into_args_vec(self) -> ArgsVec<'a>;
 }
+
+/// This type marks the `TryIntoArguments` trait as sealed.
+pub trait TryIntoArgumentsInternal<'cx> {
+    fn try_into_args_vec<C: Context<'cx>>(self, cx: &mut C) -> NeonResult<ArgsVec<'cx>>;
+}
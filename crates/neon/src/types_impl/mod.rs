@@ -5,13 +5,18 @@
 pub mod bigint;
 pub(crate) mod boxed;
 pub mod buffer;
+pub(crate) mod collection;
 #[cfg(feature = "napi-5")]
 pub(crate) mod date;
 pub(crate) mod error;
+pub mod extract;
 pub mod function;
 pub(crate) mod promise;
 
 pub(crate) mod private;
+pub(crate) mod symbol;
+#[cfg(feature = "napi-8")]
+pub(crate) mod tag;
 pub(crate) mod utf8;
 
 use std::{
@@ -31,7 +36,7 @@ use crate::{
     result::{JsResult, NeonResult, ResultExt, Throw},
     sys::{self, raw},
     types::{
-        function::{CallOptions, ConstructOptions},
+        function::{CallOptions, ConstructOptions, TryIntoArguments},
         private::ValueInternal,
         utf8::Utf8,
     },
@@ -40,14 +45,19 @@ use crate::{
 pub use self::{
     boxed::{Finalize, JsBox},
     buffer::types::{
-        JsArrayBuffer, JsBigInt64Array, JsBigUint64Array, JsBuffer, JsFloat32Array, JsFloat64Array,
-        JsInt16Array, JsInt32Array, JsInt8Array, JsTypedArray, JsUint16Array, JsUint32Array,
-        JsUint8Array,
+        JsArrayBuffer, JsBigInt64Array, JsBigUint64Array, JsBuffer, JsDataView, JsFloat32Array,
+        JsFloat64Array, JsInt16Array, JsInt32Array, JsInt8Array, JsTypedArray, JsUint16Array,
+        JsUint32Array, JsUint8Array, JsUint8ClampedArray,
     },
-    error::JsError,
+    collection::{JsMap, JsSet, JsWeakMap},
+    error::{ErrorOptions, JsError},
+    extract::{TryFromJs, TryIntoJs},
     promise::{Deferred, JsPromise},
+    symbol::JsSymbol,
 };
 
+pub use neon_macros::JsError;
+
 #[cfg(feature = "napi-5")]
 pub use self::date::{DateError, DateErrorKind, JsDate};
 
@@ -55,6 +65,10 @@ pub use self::date::{DateError, DateErrorKind, JsDate};
 #[cfg_attr(docsrs, doc(cfg(all(feature = "napi-5", feature = "futures"))))]
 pub use self::promise::JsFuture;
 
+#[cfg(feature = "napi-8")]
+#[cfg_attr(docsrs, doc(cfg(feature = "napi-8")))]
+pub use self::tag::TypeTag;
+
 // This should be considered deprecated and will be removed:
 // https://github.com/neon-bindings/neon/issues/983
 pub(crate) fn build<'a, T: Value, F: FnOnce(&mut raw::Local) -> bool>(
@@ -249,6 +263,10 @@ impl ValueInternal for JsUndefined {
         "undefined".to_string()
     }
 
+    fn ts_type() -> String {
+        "undefined".to_string()
+    }
+
     fn is_typeof<Other: Value>(env: Env, other: &Other) -> bool {
         unsafe { sys::tag::is_undefined(env.to_raw(), other.to_local()) }
     }
@@ -317,6 +335,10 @@ impl ValueInternal for JsNull {
         "null".to_string()
     }
 
+    fn ts_type() -> String {
+        "null".to_string()
+    }
+
     fn is_typeof<Other: Value>(env: Env, other: &Other) -> bool {
         unsafe { sys::tag::is_null(env.to_raw(), other.to_local()) }
     }
@@ -394,6 +416,10 @@ impl ValueInternal for JsBoolean {
         "boolean".to_string()
     }
 
+    fn ts_type() -> String {
+        "boolean".to_string()
+    }
+
     fn is_typeof<Other: Value>(env: Env, other: &Other) -> bool {
         unsafe { sys::tag::is_boolean(env.to_raw(), other.to_local()) }
     }
@@ -469,6 +495,10 @@ impl ValueInternal for JsString {
         "string".to_string()
     }
 
+    fn ts_type() -> String {
+        "string".to_string()
+    }
+
     fn is_typeof<Other: Value>(env: Env, other: &Other) -> bool {
         unsafe { sys::tag::is_string(env.to_raw(), other.to_local()) }
     }
@@ -747,6 +777,10 @@ impl ValueInternal for JsNumber {
         "number".to_string()
     }
 
+    fn ts_type() -> String {
+        "number".to_string()
+    }
+
     fn is_typeof<Other: Value>(env: Env, other: &Other) -> bool {
         unsafe { sys::tag::is_number(env.to_raw(), other.to_local()) }
     }
@@ -806,6 +840,10 @@ impl ValueInternal for JsObject {
         "object".to_string()
     }
 
+    fn ts_type() -> String {
+        "object".to_string()
+    }
+
     fn is_typeof<Other: Value>(env: Env, other: &Other) -> bool {
         unsafe { sys::tag::is_object(env.to_raw(), other.to_local()) }
     }
@@ -948,6 +986,10 @@ impl ValueInternal for JsArray {
         "Array".to_string()
     }
 
+    fn ts_type() -> String {
+        "unknown[]".to_string()
+    }
+
     fn is_typeof<Other: Value>(env: Env, other: &Other) -> bool {
         unsafe { sys::tag::is_array(env.to_raw(), other.to_local()) }
     }
@@ -1084,35 +1126,48 @@ impl JsFunction {
         Self::new_internal(cx, f)
     }
 
+    #[cfg(all(feature = "napi-5", feature = "futures"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "napi-5", feature = "futures"))))]
+    /// Returns a new `JsFunction` that calls `f` and returns a [`JsPromise`]
+    /// settled with the output of the returned future.
+    ///
+    /// `f` is called on the JavaScript thread and may use the [`FunctionContext`],
+    /// for example, to extract arguments. The future is spawned on the
+    /// [executor](crate::executor) and its output is converted with [`TryIntoJs`];
+    /// an `Err` output rejects the promise.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// # fn example(mut cx: FunctionContext) -> JsResult<JsFunction> {
+    /// JsFunction::new_async(&mut cx, |cx| {
+    ///     let n: f64 = cx.argument_as(0)?;
+    ///
+    ///     Ok(async move { n * 2.0 })
+    /// })
+    /// # }
+    /// ```
+    pub fn new_async<'a, C, F, Fut, V>(cx: &mut C, f: F) -> JsResult<'a, JsFunction>
+    where
+        C: Context<'a>,
+        F: Fn(&mut FunctionContext) -> NeonResult<Fut> + 'static,
+        Fut: std::future::Future<Output = V> + Send + 'static,
+        V: for<'b> TryIntoJs<'b> + Send + 'static,
+    {
+        Self::new_internal(cx, move |mut cx| {
+            let fut = f(&mut cx)?;
+
+            cx.spawn(fut)
+        })
+    }
+
     fn new_internal<'a, C, F, V>(cx: &mut C, f: F) -> JsResult<'a, JsFunction>
     where
         C: Context<'a>,
         F: Fn(FunctionContext) -> JsResult<V> + 'static,
         V: Value,
     {
-        use std::any;
-        use std::panic::AssertUnwindSafe;
-        use std::ptr;
-
-        use crate::context::CallbackInfo;
-        use crate::types::error::convert_panics;
-
-        let name = any::type_name::<F>();
-        let f = move |env: raw::Env, info| {
-            let env = env.into();
-            let info = unsafe { CallbackInfo::new(info) };
-
-            FunctionContext::with(env, &info, |cx| {
-                convert_panics(env, AssertUnwindSafe(|| f(cx)))
-                    .map(|v| v.to_local())
-                    // We do not have a Js Value to return, most likely due to an exception.
-                    // If we are in a throwing state, constructing a Js Value would be invalid.
-                    // While not explicitly written, the Node-API documentation includes many examples
-                    // of returning `NULL` when a native function does not return a value.
-                    // https://nodejs.org/api/n-api.html#n_api_napi_create_function
-                    .unwrap_or_else(|_: Throw| ptr::null_mut())
-            })
-        };
+        let name = std::any::type_name::<F>();
+        let f = function::callback(f);
 
         unsafe {
             if let Ok(raw) = sys::fun::new(cx.env().to_raw(), name, f) {
@@ -1145,6 +1200,33 @@ impl JsFunction {
         })
     }
 
+    /// Calls this function, converting `this`, the arguments and the result with
+    /// [`TryIntoJs`] and [`TryFromJs`].
+    ///
+    /// Throws a `TypeError` if the result is not of the expected type.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// # fn foo(mut cx: FunctionContext) -> NeonResult<f64> {
+    /// let parse_float: Handle<JsFunction> = cx.global("parseFloat")?;
+    /// let n: f64 = parse_float.call_as(&mut cx, (), ("4.5",))?;
+    /// # Ok(n)
+    /// # }
+    /// ```
+    pub fn call_as<'a, C, T, A, R>(&self, cx: &mut C, this: T, args: A) -> NeonResult<R>
+    where
+        C: Context<'a>,
+        T: TryIntoJs<'a>,
+        A: TryIntoArguments<'a>,
+        R: TryFromJs<'a>,
+    {
+        let this = this.try_into_js(cx)?;
+        let args = args.try_into_args_vec(cx)?;
+        let v = self.call(cx, this, args)?;
+
+        R::from_js(cx, v)
+    }
+
     /// Calls this function for side effect, discarding its result.
     ///
     /// **See also:** [`JsFunction::call_with`].
@@ -1230,6 +1312,10 @@ impl ValueInternal for JsFunction {
         "function".to_string()
     }
 
+    fn ts_type() -> String {
+        "Function".to_string()
+    }
+
     fn is_typeof<Other: Value>(env: Env, other: &Other) -> bool {
         unsafe { sys::tag::is_function(env.to_raw(), other.to_local()) }
     }
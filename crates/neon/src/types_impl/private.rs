@@ -8,6 +8,11 @@ use crate::{
 pub trait ValueInternal: TransparentNoCopyWrapper + 'static {
     fn name() -> String;
 
+    // TypeScript type of values of this type
+    fn ts_type() -> String {
+        "unknown".to_string()
+    }
+
     fn is_typeof<Other: Value>(env: Env, other: &Other) -> bool;
 
     fn downcast<Other: Value>(env: Env, other: &Other) -> Option<Self> {
@@ -39,7 +39,6 @@ use std::sync::Arc;
 
 const BOUNDARY: FailureBoundary = FailureBoundary {
     both: "A panic and exception occurred while resolving a `neon::types::Deferred`",
-    exception: "An exception occurred while resolving a `neon::types::Deferred`",
     panic: "A panic occurred while resolving a `neon::types::Deferred`",
 };
 
@@ -176,6 +175,9 @@ impl JsPromise {
     /// A callback must be provided that maps a `Result` representing the resolution or rejection of
     /// the `Promise` and returns a value as the `Future` output.
     ///
+    /// If the callback throws, e.g., by throwing the rejection, the `Future` outputs a
+    /// [`JoinError`] with a copy of the [exception](JoinError::exception).
+    ///
     /// _Note_: Unlike `Future`, `Promise` are eagerly evaluated and so are `JsFuture`.
     pub fn to_future<'a, O, C, F>(&self, cx: &mut C, f: F) -> NeonResult<JsFuture<O>>
     where
@@ -186,7 +188,6 @@ impl JsPromise {
             + 'static,
     {
         let then = self.get::<JsFunction, _, _>(cx, "then")?;
-        let catch = self.get::<JsFunction, _, _>(cx, "catch")?;
 
         let (tx, rx) = oneshot::channel();
         let take_state = {
@@ -213,8 +214,9 @@ impl JsPromise {
                 let v = cx.argument::<JsValue>(0)?;
 
                 TaskContext::with_context(cx.env(), move |cx| {
-                    // Error indicates that the `Future` has already dropped; ignore
-                    let _ = tx.send(f(cx, Ok(v)).map_err(Into::into));
+                    let env = cx.env();
+
+                    SendThrow::send(env, f(cx, Ok(v)), |result| tx.send(result));
                 });
 
                 Ok(cx.undefined())
@@ -227,16 +229,22 @@ impl JsPromise {
                 let v = cx.argument::<JsValue>(0)?;
 
                 TaskContext::with_context(cx.env(), move |cx| {
-                    // Error indicates that the `Future` has already dropped; ignore
-                    let _ = tx.send(f(cx, Err(v)).map_err(Into::into));
+                    let env = cx.env();
+
+                    SendThrow::send(env, f(cx, Err(v)), |result| tx.send(result));
                 });
 
                 Ok(cx.undefined())
             }
         })?;
 
-        then.exec(cx, Handle::new_internal(Self(self.0)), [resolve.upcast()])?;
-        catch.exec(cx, Handle::new_internal(Self(self.0)), [reject.upcast()])?;
+        // Both handlers are passed to a single `then` so that the derived promise does
+        // not reject with an unhandled rejection
+        then.exec(
+            cx,
+            Handle::new_internal(Self(self.0)),
+            [resolve.upcast(), reject.upcast()],
+        )?;
 
         Ok(JsFuture { rx })
     }
@@ -255,6 +263,10 @@ impl ValueInternal for JsPromise {
         "Promise".to_string()
     }
 
+    fn ts_type() -> String {
+        "Promise<unknown>".to_string()
+    }
+
     fn is_typeof<Other: Value>(env: Env, other: &Other) -> bool {
         unsafe { sys::tag::is_promise(env.to_raw(), other.to_local()) }
     }
@@ -515,7 +527,8 @@ impl Drop for Deferred {
 /// }
 /// ```
 pub struct JsFuture<T> {
-    // `Err` is always `Throw`, but `Throw` cannot be sent across threads
+    // `Err` is always `Throw`, but `Throw` cannot be sent across threads, so a
+    // copy of the exception is sent instead
     rx: oneshot::Receiver<Result<T, SendThrow>>,
 }
 
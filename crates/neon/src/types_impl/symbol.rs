@@ -0,0 +1,216 @@
+use super::{private::ValueInternal, Value};
+
+use crate::{
+    context::{internal::Env, Context},
+    handle::{internal::TransparentNoCopyWrapper, Handle},
+    object::Object,
+    result::{JsResult, NeonResult},
+    sys::{self, raw},
+    types::{JsFunction, JsObject, JsString, JsUndefined, JsValue},
+};
+
+#[cfg(feature = "napi-6")]
+use crate::{handle::Root, object::PropertyDescriptor, thread::LocalKey};
+
+/// The type of JavaScript
+/// [symbol](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Symbol)
+/// primitives.
+///
+/// A symbol is a unique value, often used as a property key that cannot collide
+/// with the keys of other code. A [`Handle<JsSymbol>`](Handle) may be used as a
+/// [`PropertyKey`](crate::object::PropertyKey) to get and set properties of an object.
+///
+/// # Example
+///
+/// ```
+/// # use neon::prelude::*;
+/// use neon::types::JsSymbol;
+///
+/// // Creates an object with a symbol-keyed property and a custom `toString` tag
+/// fn tagged(mut cx: FunctionContext) -> JsResult<JsObject> {
+///     let obj = cx.empty_object();
+///     let secret = JsSymbol::new(&mut cx, Some("secret"));
+///     let value = cx.number(42);
+///
+///     obj.set(&mut cx, secret, value)?;
+///
+///     let tag = JsSymbol::to_string_tag(&mut cx)?;
+///     let name = cx.string("Tagged");
+///
+///     // `Object.prototype.toString.call(obj)` is `[object Tagged]`
+///     obj.set(&mut cx, tag, name)?;
+///
+///     Ok(obj)
+/// }
+/// ```
+#[derive(Debug)]
+#[repr(transparent)]
+pub struct JsSymbol(raw::Local);
+
+impl JsSymbol {
+    /// Creates a new unique symbol, equivalent to `Symbol(description)`.
+    pub fn new<'a, C: Context<'a>>(cx: &mut C, description: Option<&str>) -> Handle<'a, JsSymbol> {
+        let description = match description {
+            Some(description) => cx.string(description).to_local(),
+            None => std::ptr::null_mut(),
+        };
+
+        unsafe {
+            let mut local: raw::Local = std::mem::zeroed();
+            sys::primitive::symbol(&mut local, cx.env().to_raw(), description);
+            Handle::new_internal(JsSymbol(local))
+        }
+    }
+
+    /// Gets the symbol for `key` from the global symbol registry, creating it if it
+    /// does not exist. Equivalent to `Symbol.for(key)`.
+    #[cfg(feature = "napi-9")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "napi-9")))]
+    pub fn for_key<'a, C: Context<'a>>(cx: &mut C, key: &str) -> Handle<'a, JsSymbol> {
+        unsafe {
+            let mut local: raw::Local = std::mem::zeroed();
+            sys::primitive::symbol_for(&mut local, cx.env().to_raw(), key);
+            Handle::new_internal(JsSymbol(local))
+        }
+    }
+
+    /// Gets the well-known symbol `Symbol.iterator`.
+    pub fn iterator<'a, C: Context<'a>>(cx: &mut C) -> JsResult<'a, JsSymbol> {
+        well_known(cx, "iterator")
+    }
+
+    /// Gets the well-known symbol `Symbol.asyncIterator`.
+    pub fn async_iterator<'a, C: Context<'a>>(cx: &mut C) -> JsResult<'a, JsSymbol> {
+        well_known(cx, "asyncIterator")
+    }
+
+    /// Gets the well-known symbol `Symbol.toStringTag`.
+    pub fn to_string_tag<'a, C: Context<'a>>(cx: &mut C) -> JsResult<'a, JsSymbol> {
+        well_known(cx, "toStringTag")
+    }
+
+    /// Returns the description of the symbol, or `None` if it was created without one.
+    ///
+    /// The description is read with the `Symbol.prototype.description` getter. With
+    /// `napi-6` or later, the getter is cached the first time it is used and later
+    /// modifications of `Symbol.prototype` are not observed.
+    pub fn description<'a, C: Context<'a>>(&self, cx: &mut C) -> NeonResult<Option<String>> {
+        let getter = description_getter(cx)?;
+        let description = getter.call(cx, JsValue::new_internal(self.0), [])?;
+
+        if description.is_a::<JsUndefined, _>(cx) {
+            return Ok(None);
+        }
+
+        let description = description.downcast_or_throw::<JsString, _>(cx)?;
+
+        Ok(Some(description.value(cx)))
+    }
+}
+
+// `Symbol.prototype.description` is an accessor. The getter is called directly,
+// instead of reading the property of the symbol, since the prototype may be
+// modified by user code.
+#[cfg(feature = "napi-6")]
+fn description_getter<'a, C: Context<'a>>(cx: &mut C) -> JsResult<'a, JsFunction> {
+    static GETTER: LocalKey<Root<JsFunction>> = LocalKey::new();
+
+    let getter = GETTER.get_or_try_init(cx, |cx| {
+        let getter = lookup_description_getter(cx)?;
+
+        Ok(getter.root(cx))
+    })?;
+
+    Ok(getter.to_inner(cx))
+}
+
+#[cfg(not(feature = "napi-6"))]
+fn description_getter<'a, C: Context<'a>>(cx: &mut C) -> JsResult<'a, JsFunction> {
+    lookup_description_getter(cx)
+}
+
+fn lookup_description_getter<'a, C: Context<'a>>(cx: &mut C) -> JsResult<'a, JsFunction> {
+    let object: Handle<JsFunction> = cx.global("Object")?;
+    let get_own_property_descriptor: Handle<JsFunction> =
+        object.get(cx, "getOwnPropertyDescriptor")?;
+    let symbol: Handle<JsFunction> = cx.global("Symbol")?;
+    let prototype: Handle<JsObject> = symbol.get(cx, "prototype")?;
+    let name = cx.string("description");
+    let descriptor = get_own_property_descriptor
+        .call(cx, object, [prototype.upcast(), name.upcast()])?
+        .downcast_or_throw::<JsObject, _>(cx)?;
+
+    descriptor.get(cx, "get")
+}
+
+// Names of the well-known symbols available from `JsSymbol`
+#[cfg(feature = "napi-6")]
+const WELL_KNOWN: [&str; 3] = ["iterator", "asyncIterator", "toStringTag"];
+
+// Well-known symbols are properties of the global `Symbol` constructor. They are
+// copied once per instance of the module, instead of read on every call, since
+// `globalThis` may be modified by user code. Symbols cannot be rooted, so they are
+// kept as properties of an object that is not reachable from JavaScript.
+#[cfg(feature = "napi-6")]
+fn well_known<'a, C: Context<'a>>(cx: &mut C, name: &str) -> JsResult<'a, JsSymbol> {
+    static SYMBOLS: LocalKey<Root<JsObject>> = LocalKey::new();
+
+    let symbols = SYMBOLS.get_or_try_init(cx, |cx| {
+        let symbol: Handle<JsFunction> = cx.global("Symbol")?;
+        let symbols = cx.empty_object();
+        let mut descriptors = Vec::with_capacity(WELL_KNOWN.len());
+
+        for name in WELL_KNOWN {
+            let value: Handle<JsSymbol> = symbol.get(cx, name)?;
+
+            descriptors.push(PropertyDescriptor::value(cx, name, value));
+        }
+
+        // Defining, instead of setting, the properties ignores setters on
+        // `Object.prototype`
+        symbols.define_properties(cx, descriptors)?;
+
+        Ok(symbols.root(cx))
+    })?;
+
+    symbols.to_inner(cx).get(cx, name)
+}
+
+#[cfg(not(feature = "napi-6"))]
+fn well_known<'a, C: Context<'a>>(cx: &mut C, name: &str) -> JsResult<'a, JsSymbol> {
+    let symbol: Handle<JsFunction> = cx.global("Symbol")?;
+
+    symbol.get(cx, name)
+}
+
+impl Value for JsSymbol {}
+
+unsafe impl TransparentNoCopyWrapper for JsSymbol {
+    type Inner = raw::Local;
+
+    fn into_inner(self) -> Self::Inner {
+        self.0
+    }
+}
+
+impl ValueInternal for JsSymbol {
+    fn name() -> String {
+        "symbol".to_string()
+    }
+
+    fn ts_type() -> String {
+        "symbol".to_string()
+    }
+
+    fn is_typeof<Other: Value>(env: Env, other: &Other) -> bool {
+        unsafe { sys::tag::is_symbol(env.to_raw(), other.to_local()) }
+    }
+
+    fn to_local(&self) -> raw::Local {
+        self.0
+    }
+
+    unsafe fn from_local(_env: Env, h: raw::Local) -> Self {
+        JsSymbol(h)
+    }
+}
@@ -0,0 +1,115 @@
+use std::any;
+
+use crate::sys;
+
+/// A 128-bit tag that identifies the type of a JavaScript object, e.g., objects
+/// created by an addon.
+///
+/// An object tagged with [`Object::type_tag`](crate::object::Object::type_tag)
+/// can be distinguished from look-alike objects with
+/// [`Object::check_type_tag`](crate::object::Object::check_type_tag). Unlike a
+/// property or a prototype, a type tag cannot be added, changed or observed
+/// from JavaScript.
+///
+/// Tags are usually created from a randomly generated UUID, which should never
+/// change, since objects may be passed between different versions of an addon:
+///
+/// ```
+/// use neon::types::TypeTag;
+///
+/// const DATABASE: TypeTag = TypeTag::from_u128(0x1edf75a3_8c41_4d24_a4d2_57e2c1b4dc7f);
+/// ```
+///
+/// # Example
+///
+/// ```
+/// # use neon::prelude::*;
+/// use neon::types::TypeTag;
+///
+/// const CONNECTION: TypeTag = TypeTag::from_name("my-addon::Connection");
+///
+/// fn connect(mut cx: FunctionContext) -> JsResult<JsObject> {
+///     let conn = cx.empty_object();
+///
+///     conn.type_tag(&mut cx, &CONNECTION)?;
+///
+///     Ok(conn)
+/// }
+///
+/// fn is_connection(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+///     let value = cx.argument::<JsObject>(0)?;
+///     let is_connection = value.check_type_tag(&mut cx, &CONNECTION);
+///
+///     Ok(cx.boolean(is_connection))
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TypeTag(u128);
+
+impl TypeTag {
+    /// Creates a tag from a 128-bit integer, e.g., a UUID.
+    ///
+    /// **Note:** Some versions of Node-API fail to detect tags with the upper 64
+    /// bits set to zero, so they should be avoided.
+    pub const fn from_u128(tag: u128) -> Self {
+        Self(tag)
+    }
+
+    /// Creates a tag from the 16 bytes of a UUID, in big-endian order.
+    pub const fn from_bytes(bytes: [u8; 16]) -> Self {
+        Self(u128::from_be_bytes(bytes))
+    }
+
+    /// Creates a tag from the 128-bit [FNV-1a](http://www.isthe.com/chongo/tech/comp/fnv/)
+    /// hash of `name`. The same name always produces the same tag.
+    pub const fn from_name(name: &str) -> Self {
+        const OFFSET_BASIS: u128 = 0x6c62272e_07bb0142_62b82175_6295c58d;
+        const PRIME: u128 = 0x00000000_01000000_00000000_0000013b;
+
+        let bytes = name.as_bytes();
+        let mut hash = OFFSET_BASIS;
+        let mut i = 0;
+
+        while i < bytes.len() {
+            hash ^= bytes[i] as u128;
+            hash = hash.wrapping_mul(PRIME);
+            i += 1;
+        }
+
+        // Ensure the upper 64 bits are non-zero
+        Self(hash | (1 << 64))
+    }
+
+    /// Creates a tag from the name of a Rust type with [`TypeTag::from_name`].
+    ///
+    /// The tag is stable across builds of an addon, but the name of a type may
+    /// change between versions of the Rust compiler. Use
+    /// [`TypeTag::from_u128`] if objects are shared with addons built separately.
+    pub fn of<T: ?Sized + 'static>() -> Self {
+        Self::from_name(any::type_name::<T>())
+    }
+
+    /// Returns the tag as a 128-bit integer.
+    pub const fn to_u128(self) -> u128 {
+        self.0
+    }
+
+    pub(crate) fn to_raw(self) -> sys::TypeTag {
+        sys::TypeTag {
+            lower: self.0 as u64,
+            upper: (self.0 >> 64) as u64,
+        }
+    }
+}
+
+impl From<u128> for TypeTag {
+    fn from(tag: u128) -> Self {
+        Self::from_u128(tag)
+    }
+}
+
+impl From<TypeTag> for u128 {
+    fn from(tag: TypeTag) -> Self {
+        tag.to_u128()
+    }
+}
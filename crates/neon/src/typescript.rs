@@ -0,0 +1,71 @@
+//! Generate TypeScript declarations for a module.
+//!
+//! Functions exported with [`#[neon::export]`](crate::export) and classes
+//! defined with [`#[neon::class]`](crate::class) record their signatures when
+//! compiled. [`generate`] renders the declarations of every export linked into
+//! the module as the contents of an `index.d.ts`.
+//!
+//! Types are mapped from the [`TryFromJs`](crate::types::extract::TryFromJs)
+//! conversion of each argument and the [`TryIntoJs`](crate::types::extract::TryIntoJs)
+//! conversion of the return value. For example, `f64` is declared as `number`,
+//! `Option<T>` as `T | undefined` and `Vec<T>` as `T[]`. Async functions and
+//! functions that return a [`JsPromise`](crate::types::JsPromise) return a
+//! `Promise`. Types without a more specific declaration are `unknown`.
+//!
+//! ```
+//! # use neon::prelude::*;
+//! #[neon::export]
+//! fn add(a: f64, b: Option<f64>) -> f64 {
+//!     a + b.unwrap_or(0.0)
+//! }
+//!
+//! // export function add(a: number, b?: number): number;
+//! let dts = neon::typescript::generate();
+//! ```
+//!
+//! Since declarations are collected from the compiled module, they are
+//! usually written from a test in the crate of the module. Checking in the
+//! file and comparing it in CI prevents it from becoming out of date:
+//!
+//! ```no_run
+//! #[test]
+//! fn typescript() {
+//!     neon::typescript::write("index.d.ts").unwrap();
+//! }
+//! ```
+
+use std::{fs, io, path::Path};
+
+use crate::macro_internal::typescript::DECLARATIONS;
+
+/// Renders the declarations of all exports in the module, sorted by name.
+pub fn generate() -> String {
+    let mut decls = DECLARATIONS.iter().map(|decl| decl()).collect::<Vec<_>>();
+
+    decls.sort_by(|a, b| name(a).cmp(name(b)).then_with(|| a.cmp(b)));
+
+    let mut dts = String::from("// This file is generated by Neon. Do not edit.\n");
+
+    for decl in decls {
+        dts.push('\n');
+        dts.push_str(&decl);
+        dts.push('\n');
+    }
+
+    dts
+}
+
+/// Writes the result of [`generate`] to `path`.
+pub fn write(path: impl AsRef<Path>) -> io::Result<()> {
+    fs::write(path, generate())
+}
+
+// Name of the function or class in a declaration
+fn name(decl: &str) -> &str {
+    decl.trim_start_matches("export ")
+        .trim_start_matches("function ")
+        .trim_start_matches("class ")
+        .split(['(', ' '])
+        .next()
+        .unwrap_or("")
+}
@@ -72,4 +72,21 @@ describe("boxed", function () {
 
     assert.throws(() => addon.person_greet(unit), /failed to downcast/);
   });
+
+  it("should report the size of boxed values as external memory", function () {
+    const size = 16 * 1024 * 1024;
+    const before = addon.adjust_external_memory(0);
+    const person = addon.person_new_with_size("World", size);
+    const after = addon.adjust_external_memory(0);
+
+    assert.isAtLeast(after - before, size);
+    assert.strictEqual(addon.person_greet(person), "Hello, World!");
+  });
+
+  it("should adjust external memory", function () {
+    const before = addon.adjust_external_memory(1024);
+    const after = addon.adjust_external_memory(-1024);
+
+    assert.strictEqual(after, before - 1024);
+  });
 });
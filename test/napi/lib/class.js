@@ -0,0 +1,107 @@
+const addon = require("..");
+const assert = require("chai").assert;
+const util = require("util");
+
+describe("class", function () {
+  it("should construct instances", function () {
+    const counter = new addon.Counter(1);
+
+    assert.instanceOf(counter, addon.Counter);
+    assert.strictEqual(addon.Counter.name, "Counter");
+    assert.strictEqual(util.inspect(counter), "Counter {}");
+  });
+
+  it("should require `new`", function () {
+    assert.throws(
+      () => addon.Counter(1),
+      TypeError,
+      "Class constructor Counter cannot be invoked without 'new'"
+    );
+  });
+
+  it("should call prototype methods", function () {
+    const counter = new addon.Counter(1);
+
+    assert.strictEqual(counter.increment(), 2);
+    assert.strictEqual(counter.add(3), 5);
+    assert.throws(() => counter.add(-1), RangeError, "expected a positive number");
+    assert.strictEqual(counter.describe(), "Counter: 5");
+    assert.strictEqual(counter.label, undefined);
+    assert.strictEqual(
+      Object.getPrototypeOf(counter).increment,
+      addon.Counter.prototype.increment
+    );
+  });
+
+  it("should keep state per instance", function () {
+    const a = new addon.Counter(0);
+    const b = new addon.Counter(10);
+
+    a.increment();
+
+    assert.strictEqual(a.count, 1);
+    assert.strictEqual(b.count, 10);
+  });
+
+  it("should define getters and setters", function () {
+    const counter = new addon.Counter(0);
+
+    assert.strictEqual(counter.isZero, true);
+    counter.count = 42;
+    assert.strictEqual(counter.count, 42);
+    assert.strictEqual(counter.isZero, false);
+    assert.throws(() => (counter.count = "42"), TypeError, "arguments[0]: expected number");
+  });
+
+  it("should define static methods", function () {
+    assert.strictEqual(addon.Counter.zero(), 0);
+  });
+
+  it("should throw when `this` is not an instance", function () {
+    const counter = new addon.Counter(0);
+
+    assert.throws(() => counter.increment.call({}), TypeError, "Illegal invocation");
+    assert.throws(() => addon.Counter.prototype.increment(), TypeError);
+    assert.throws(
+      () => Object.create(addon.Counter.prototype).increment(),
+      TypeError
+    );
+    assert.throws(
+      () => new addon.Point2D(1, 1).length.call(counter),
+      TypeError,
+      "Illegal invocation"
+    );
+  });
+
+  it("should throw when re-entrantly borrowed", function () {
+    const counter = new addon.Counter(0);
+
+    assert.throws(
+      () => counter.with_callback(() => counter.count),
+      Error,
+      "already mutably borrowed"
+    );
+  });
+
+  it("should support subclasses", function () {
+    class Sub extends addon.Counter {
+      double() {
+        return this.count * 2;
+      }
+    }
+
+    const sub = new Sub(2);
+
+    assert.instanceOf(sub, addon.Counter);
+    assert.strictEqual(sub.increment(), 3);
+    assert.strictEqual(sub.double(), 6);
+  });
+
+  it("should rename classes and constructors", function () {
+    const point = new addon.Point2D(3, 4);
+
+    assert.instanceOf(point, addon.Point2D);
+    assert.strictEqual(point.length(), 5);
+    assert.throws(() => new addon.Point2D(NaN, 1), RangeError);
+  });
+});
@@ -0,0 +1,203 @@
+const addon = require("..");
+const { assert } = require("chai");
+
+describe("JsMap", () => {
+  it("should create a map with keys of any type", () => {
+    const map = addon.create_map();
+
+    assert.instanceOf(map, Map);
+    assert.strictEqual(map.size, 2);
+    assert.strictEqual(map.get("a"), 1);
+    assert.deepEqual([...map.values()], [1, 2]);
+  });
+
+  it("should get, set, check and delete keys", () => {
+    const key = {};
+    const map = new Map([[key, "value"]]);
+
+    assert.strictEqual(addon.map_get(map, key), "value");
+    assert.strictEqual(addon.map_get(map, {}), undefined);
+    assert.isTrue(addon.map_has(map, key));
+    assert.isFalse(addon.map_has(map, {}));
+    assert.isTrue(addon.map_delete(map, key));
+    assert.isFalse(addon.map_delete(map, key));
+    assert.strictEqual(map.size, 0);
+  });
+
+  it("should get the size and clear a map", () => {
+    const map = new Map([
+      [1, "a"],
+      [2, "b"],
+    ]);
+
+    assert.strictEqual(addon.map_size(map), 2);
+    addon.map_clear(map);
+    assert.strictEqual(addon.map_size(map), 0);
+  });
+
+  it("should get the entries in insertion order", () => {
+    const key = {};
+    const map = new Map([
+      ["b", 1],
+      [key, 2],
+      ["a", 3],
+    ]);
+
+    assert.deepEqual(addon.map_entries(map), [
+      ["b", 1],
+      [key, 2],
+      ["a", 3],
+    ]);
+  });
+
+  it("should convert between Rust maps and a Map", () => {
+    const map = addon.map_from_hash_map();
+
+    assert.instanceOf(map, Map);
+    assert.deepEqual([...map].sort(), [
+      ["a", 1],
+      ["b", 2],
+    ]);
+
+    assert.strictEqual(addon.map_sum_values(map), 3);
+    assert.throws(
+      () => addon.map_sum_values(new Map([["a", "b"]])),
+      TypeError,
+      "[0].value: expected number"
+    );
+    assert.throws(
+      () =>
+        addon.map_sum_values(
+          new Map([
+            ["a", 1],
+            [2, 2],
+          ])
+        ),
+      TypeError,
+      "[1].key: expected string"
+    );
+  });
+
+  it("should convert a BTreeMap from and to an object", () => {
+    const result = addon.roundtrip_btree_map({ b: 2, a: 1 });
+
+    assert.notInstanceOf(result, Map);
+    assert.deepEqual(Object.entries(result), [
+      ["a", 1],
+      ["b", 2],
+    ]);
+
+    assert.throws(
+      () => addon.roundtrip_btree_map({ a: "1" }),
+      TypeError,
+      "arguments[0].a: expected number"
+    );
+  });
+});
+
+describe("JsSet", () => {
+  it("should create a set from values", () => {
+    const set = addon.create_set();
+
+    assert.instanceOf(set, Set);
+    assert.deepEqual([...set], ["a", "b"]);
+  });
+
+  it("should add, check and delete values", () => {
+    const value = {};
+    const set = new Set();
+
+    addon.set_add(set, value);
+    assert.isTrue(set.has(value));
+    assert.isTrue(addon.set_has(set, value));
+    assert.isFalse(addon.set_has(set, {}));
+    assert.isTrue(addon.set_delete(set, value));
+    assert.isFalse(addon.set_delete(set, value));
+  });
+
+  it("should get the size, values and clear a set", () => {
+    const set = new Set([3, 1, 2]);
+
+    assert.strictEqual(addon.set_size(set), 3);
+    assert.deepEqual(addon.set_values(set), [3, 1, 2]);
+    addon.set_clear(set);
+    assert.strictEqual(addon.set_size(set), 0);
+  });
+
+  it("should convert a Set to Rust sets", () => {
+    assert.strictEqual(addon.set_count_unique(new Set(["a", "b"])), 2);
+    assert.throws(() => addon.set_count_unique(new Set([1])), TypeError);
+
+    const result = addon.roundtrip_hash_set(new Set(["b", "a"]));
+
+    assert.instanceOf(result, Set);
+    assert.deepEqual([...result], ["a", "b"]);
+  });
+});
+
+describe("JsWeakMap", () => {
+  it("should get, set, check and delete keys", () => {
+    const key = {};
+    const map = addon.create_weak_map();
+
+    assert.instanceOf(map, WeakMap);
+    assert.strictEqual(addon.weak_map_get(map, key), undefined);
+    addon.weak_map_set(map, key, "value");
+    assert.strictEqual(map.get(key), "value");
+    assert.strictEqual(addon.weak_map_get(map, key), "value");
+    assert.isTrue(addon.weak_map_has(map, key));
+    assert.isTrue(addon.weak_map_delete(map, key));
+    assert.isFalse(addon.weak_map_has(map, key));
+  });
+});
+
+describe("Collection brand checks", () => {
+  it("should downcast maps, sets and weak maps", () => {
+    class SubMap extends Map {}
+
+    assert.isTrue(addon.is_map(new Map()));
+    assert.isTrue(addon.is_map(new SubMap()));
+    assert.isFalse(addon.is_map(new Set()));
+    assert.isFalse(addon.is_map(new WeakMap()));
+    assert.isFalse(addon.is_map({}));
+    assert.isFalse(addon.is_map("Map"));
+
+    assert.isTrue(addon.is_set(new Set()));
+    assert.isFalse(addon.is_set(new Map()));
+    assert.isFalse(addon.is_set([]));
+
+    assert.isTrue(addon.is_weak_map(new WeakMap()));
+    assert.isFalse(addon.is_weak_map(new Map()));
+    assert.isFalse(addon.is_weak_map(null));
+  });
+
+  it("should not be fooled by objects that look like a map", () => {
+    const fake = Object.create(Map.prototype);
+
+    assert.instanceOf(fake, Map);
+    assert.isFalse(addon.is_map(fake));
+    assert.throws(() => addon.map_size(fake), TypeError);
+  });
+
+  it("should not use methods replaced after the first use", () => {
+    const map = new Map([["a", 1]]);
+    const { has, entries } = Map.prototype;
+    const { from } = Array;
+
+    addon.map_entries(map);
+
+    Map.prototype.has = () => true;
+    Map.prototype.entries = () => [].values();
+    Array.from = () => [];
+
+    try {
+      assert.isFalse(addon.is_map({}));
+      assert.isFalse(addon.map_has(map, "b"));
+      assert.deepEqual(addon.map_entries(map), [["a", 1]]);
+    } finally {
+      Map.prototype.has = has;
+      Map.prototype.entries = entries;
+      Array.from = from;
+    }
+  });
+});
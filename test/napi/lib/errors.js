@@ -34,6 +34,106 @@ describe("errors", function () {
     assert.throws(() => addon.throw_error(msg), msg);
   });
 
+  it("should be able to throw an error with a code", function () {
+    try {
+      addon.throw_error_with_code("ERR_INVALID_ARG", "bad argument");
+      assert.fail("expected an exception");
+    } catch (err) {
+      assert.instanceOf(err, TypeError);
+      assert.strictEqual(err.message, "bad argument");
+      assert.strictEqual(err.code, "ERR_INVALID_ARG");
+    }
+  });
+
+  it("should be able to create an error with options", function () {
+    const cause = new Error("inner");
+    const err = addon.new_error_with_options(cause);
+
+    assert.instanceOf(err, RangeError);
+    assert.strictEqual(err.message, "outer");
+    assert.strictEqual(err.code, "ERR_OUTER");
+    assert.strictEqual(err.cause, cause);
+    assert.strictEqual(err.extra, 42);
+  });
+
+  it("should convert the sources of a Rust error to causes", function () {
+    try {
+      addon.throw_error_with_rust_cause();
+      assert.fail("expected an exception");
+    } catch (err) {
+      assert.strictEqual(err.message, "failed to start");
+      assert.strictEqual(err.code, "ERR_START");
+      assert.instanceOf(err.cause, Error);
+      assert.strictEqual(err.cause.message, "failed to read config");
+      assert.strictEqual(err.cause.cause.message, "config.json not found");
+      assert.strictEqual(err.cause.cause.cause, undefined);
+    }
+  });
+
+  it("should convert errors with a derived conversion", function () {
+    const thrown = (kind) => {
+      try {
+        addon.derive_error(kind);
+      } catch (err) {
+        return err;
+      }
+
+      assert.fail("expected an exception");
+    };
+
+    const missing = thrown("missing");
+    assert.instanceOf(missing, Error);
+    assert.strictEqual(missing.message, "missing config: config.json");
+    assert.strictEqual(missing.code, "ERR_CONFIG_MISSING");
+    assert.strictEqual(missing.path, "config.json");
+
+    const invalid = thrown("invalid");
+    assert.instanceOf(invalid, TypeError);
+    assert.strictEqual(invalid.code, "ERR_INVALID");
+    assert.strictEqual(invalid.key, "port");
+    assert.strictEqual(invalid.expectedType, "number");
+    assert.strictEqual(invalid.value, undefined);
+
+    const large = thrown("large");
+    assert.instanceOf(large, RangeError);
+    assert.strictEqual(large.code, "ERR_TOO_LARGE");
+    assert.strictEqual(large.size, 4096);
+
+    const read = thrown("read");
+    assert.strictEqual(read.message, "failed to read config");
+    assert.strictEqual(read.code, "ERR_READ");
+    assert.strictEqual(read.cause.message, "permission denied");
+  });
+
+  it("should throw Rust errors converted with `?`", function () {
+    const thrown = (kind) => {
+      try {
+        addon.result_error(kind);
+      } catch (err) {
+        return err;
+      }
+
+      assert.fail("expected an exception");
+    };
+
+    const parse = thrown("parse");
+    assert.instanceOf(parse, Error);
+    assert.strictEqual(parse.message, "invalid float literal");
+    assert.strictEqual(parse.cause, undefined);
+
+    const code = thrown("code");
+    assert.strictEqual(code.message, "custom error");
+    assert.strictEqual(code.code, "ERR_CUSTOM");
+
+    const source = thrown("source");
+    assert.strictEqual(source.message, "failed to read config");
+    assert.strictEqual(source.cause.message, "permission denied");
+
+    const range = thrown("throw");
+    assert.instanceOf(range, RangeError);
+    assert.strictEqual(range.message, "thrown error");
+  });
+
   it("should be able to stringify a downcast error", function () {
     let msg = addon.downcast_error();
     assert.strictEqual(msg, "failed to downcast string to number");
@@ -0,0 +1,81 @@
+const addon = require("..");
+const assert = require("chai").assert;
+
+describe("export", function () {
+  it("should convert arguments and return values", function () {
+    assert.strictEqual(addon.export_add(1, 2), 3);
+    assert.strictEqual(addon.export_concat("foo", "bar"), "foobar");
+    assert.strictEqual(addon.export_negate(true), false);
+    assert.strictEqual(addon.export_no_return(), undefined);
+  });
+
+  it("should export with a different name", function () {
+    assert.strictEqual(addon.exportRenamed(), "renamed");
+    assert.strictEqual(addon.export_renamed, undefined);
+  });
+
+  it("should throw a TypeError naming the invalid argument", function () {
+    assert.throws(
+      () => addon.export_add(1, "2"),
+      TypeError,
+      "arguments[1]: expected number"
+    );
+    assert.throws(
+      () => addon.export_concat("foo"),
+      TypeError,
+      "arguments[1]: expected string"
+    );
+  });
+
+  it("should pass the function context", function () {
+    assert.strictEqual(addon.export_handle({ value: 42 }), 42);
+    assert.strictEqual(addon.export_with_context(9), 3);
+    assert.throws(
+      () => addon.export_with_context(-1),
+      RangeError,
+      "expected a positive number"
+    );
+  });
+
+  it("should convert panics to exceptions", function () {
+    assert.throws(() => addon.export_panic(), Error, "exported panic");
+  });
+
+  it("should default missing optional arguments", function () {
+    assert.deepEqual(addon.export_scale([1, 2], 2), [2, 4]);
+    assert.deepEqual(addon.export_scale([1, 2]), [1, 2]);
+  });
+
+  it("should call init hooks after registering exports", function () {
+    assert.strictEqual(addon.exportInitSawExports, true);
+  });
+
+  it("should generate TypeScript declarations", function () {
+    const dts = addon.export_typescript();
+    const expected = [
+      "export function export_add(a: number, b: number): number;",
+      "export function exportRenamed(): string;",
+      "export function export_no_return(): void;",
+      "export function export_handle(obj: object): unknown;",
+      "export function export_scale(values: number[], scale?: number): " +
+        "number[];",
+      "export function async_add(a: number, b: number): Promise<number>;",
+      "export function async_sum(nums: Float64Array): Promise<number>;",
+      "export class Counter {\n  constructor(count: number);\n",
+      "  add(n: number): number;\n",
+      "  get count(): number;\n  set count(value: number);\n",
+      "  static zero(): number;\n",
+      "export class Point2D {\n  constructor(x: number, y: number);\n",
+      "export function extract_derive_area(shape: " +
+        '{ type: "circle"; radius: number } | ' +
+        '{ type: "rect"; width: number; height: number } | ' +
+        '{ type: "point" }): number;',
+    ];
+
+    for (const decl of expected) {
+      assert.include(dts, decl);
+    }
+
+    assert.notInclude(dts, "label");
+  });
+});
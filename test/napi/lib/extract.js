@@ -0,0 +1,241 @@
+const addon = require("..");
+const assert = require("chai").assert;
+
+describe("extract", function () {
+  it("should convert integers", function () {
+    assert.strictEqual(addon.extract_u8(255), 255);
+    assert.strictEqual(addon.extract_i32(-42), -42);
+    assert.strictEqual(
+      addon.extract_u64(Number.MAX_SAFE_INTEGER),
+      Number.MAX_SAFE_INTEGER
+    );
+  });
+
+  it("should reject integers out of range", function () {
+    assert.throws(
+      () => addon.extract_u8(256),
+      TypeError,
+      "arguments[0]: expected integer between 0 and 255"
+    );
+    assert.throws(() => addon.extract_u8(-1), TypeError);
+    assert.throws(() => addon.extract_i32(1.5), TypeError);
+    assert.throws(
+      () => addon.extract_u64(Number.MAX_SAFE_INTEGER + 1),
+      TypeError
+    );
+    assert.throws(() => addon.extract_unsafe_integer(), RangeError);
+  });
+
+  it("should convert floats", function () {
+    assert.strictEqual(addon.extract_f32(0.5), 0.5);
+  });
+
+  it("should convert options", function () {
+    assert.strictEqual(addon.extract_option(2), 4);
+    assert.strictEqual(addon.extract_option(null), undefined);
+    assert.strictEqual(addon.extract_option(), undefined);
+  });
+
+  it("should convert arrays", function () {
+    assert.deepEqual(addon.extract_vec(["a", "b", "c"]), ["c", "b", "a"]);
+    assert.strictEqual(
+      addon.extract_nested([
+        [1, 2],
+        [3, 4],
+      ]),
+      10
+    );
+    assert.throws(
+      () => addon.extract_nested([[1], [2, "3"]]),
+      TypeError,
+      "arguments[0][1][1]: expected integer"
+    );
+  });
+
+  it("should convert objects to maps", function () {
+    assert.deepEqual(addon.extract_map({ a: 1, b: 2 }), { a: 2, b: 3 });
+    assert.throws(
+      () => addon.extract_map({ a: 1, b: "2" }),
+      TypeError,
+      "arguments[0].b: expected number"
+    );
+  });
+
+  it("should convert a __proto__ key of a map to an own property", function () {
+    const map = addon.extract_map(JSON.parse('{"__proto__":1}'));
+
+    assert.strictEqual(Object.getPrototypeOf(map), Object.prototype);
+    assert.deepEqual(Object.keys(map), ["__proto__"]);
+    assert.strictEqual(
+      Object.getOwnPropertyDescriptor(map, "__proto__").value,
+      2
+    );
+  });
+
+  it("should convert tuples", function () {
+    assert.deepEqual(addon.extract_tuple(["a", 1, true]), [true, 1, "a"]);
+    assert.throws(
+      () => addon.extract_tuple(["a", 1]),
+      TypeError,
+      "arguments[0]: expected Array of length 3"
+    );
+  });
+
+  it("should throw the error of a result", function () {
+    assert.strictEqual(addon.extract_result(true), "ok");
+
+    try {
+      addon.extract_result(false);
+      assert.fail("expected an exception");
+    } catch (err) {
+      assert.strictEqual(err, "failed");
+    }
+  });
+
+  it("should convert bigints", function () {
+    assert.strictEqual(addon.extract_bigint(2n ** 64n), 2n ** 65n);
+    assert.throws(() => addon.extract_bigint(2n ** 127n), TypeError);
+  });
+
+  it("should convert dates", function () {
+    const date = new Date(-1000);
+
+    assert.strictEqual(addon.extract_date(date).getTime(), 0);
+    assert.throws(
+      () => addon.extract_date(new Date(NaN)),
+      TypeError,
+      "expected valid Date"
+    );
+  });
+
+  it("should convert buffers", function () {
+    assert.deepEqual(
+      addon.extract_buffer(Buffer.from([1, 2, 3])),
+      Buffer.from([3, 2, 1])
+    );
+    assert.strictEqual(addon.extract_array_buffer(new ArrayBuffer(4)), 4);
+  });
+
+  it("should convert any Uint8Array to a buffer", function () {
+    assert.deepEqual(
+      addon.extract_buffer(new Uint8Array([1, 2, 3])),
+      Buffer.from([3, 2, 1])
+    );
+    assert.throws(() => addon.extract_buffer(new Uint16Array(2)), TypeError);
+    assert.throws(
+      () => addon.extract_buffer(new DataView(new ArrayBuffer(2))),
+      TypeError
+    );
+  });
+
+  it("should convert with argument_as", function () {
+    assert.strictEqual(addon.extract_argument_as(1), 1);
+    assert.strictEqual(addon.extract_argument_as(1, 2), 3);
+    assert.throws(
+      () => addon.extract_argument_as(1, "2"),
+      TypeError,
+      "arguments[1]: expected number"
+    );
+  });
+
+  it("should convert with call_as", function () {
+    assert.strictEqual(
+      addon.extract_call_as((a, b, c) => `${a} ${b} ${c[0]}`),
+      "1 two 3"
+    );
+    assert.throws(() => addon.extract_call_as(() => 1), TypeError);
+  });
+
+  it("should convert with get_as and set_as", function () {
+    assert.deepEqual(addon.extract_get_set_as({ count: 1 }), {
+      count: 2,
+      tags: ["a", "b"],
+    });
+    assert.throws(() => addon.extract_get_set_as({}), TypeError);
+  });
+
+  it("should derive conversions for structs", function () {
+    assert.deepEqual(
+      addon.extract_derive_options({
+        retry: { max: 1, backoff: "exponential" },
+        URL: "https://example.com",
+      }),
+      {
+        retry: { max: 2, backoff: "exponential" },
+        URL: "https://example.com",
+        verbose: false,
+        timeoutMs: 1000,
+      }
+    );
+    assert.strictEqual(
+      addon.extract_derive_options({
+        retry: { max: 1, backoff: "none" },
+        URL: "",
+        verbose: true,
+        timeoutMs: 5,
+      }).timeoutMs,
+      5
+    );
+  });
+
+  it("should name the path of an invalid property", function () {
+    assert.throws(
+      () =>
+        addon.extract_derive_options({
+          retry: { max: "1", backoff: "constant" },
+          URL: "",
+        }),
+      TypeError,
+      "arguments[0].retry.max: expected integer"
+    );
+    assert.throws(
+      () =>
+        addon.extract_derive_options({
+          retry: { max: 1, backoff: "linear" },
+          URL: "",
+        }),
+      TypeError,
+      'arguments[0].retry.backoff: expected one of "constant", "exponential", "none"'
+    );
+    assert.throws(
+      () => addon.extract_derive_options(null),
+      TypeError,
+      "arguments[0]: expected object"
+    );
+  });
+
+  it("should derive conversions for tagged enums", function () {
+    assert.strictEqual(
+      addon.extract_derive_area({ type: "rect", width: 2, height: 3 }),
+      6
+    );
+    assert.strictEqual(addon.extract_derive_area({ type: "point" }), 0);
+    assert.deepEqual(
+      addon.extract_derive_scale({ type: "circle", radius: 1 }, 2),
+      { type: "circle", radius: 2 }
+    );
+    assert.deepEqual(addon.extract_derive_scale({ type: "point" }, 2), {
+      type: "point",
+    });
+    assert.throws(
+      () => addon.extract_derive_area({ type: "triangle" }),
+      TypeError,
+      'arguments[0].type: expected one of "circle", "rect", "point"'
+    );
+    assert.throws(
+      () => addon.extract_derive_area({ type: "circle" }),
+      TypeError,
+      "arguments[0].radius: expected number"
+    );
+  });
+
+  it("should derive conversions for generic enums", function () {
+    assert.deepEqual(
+      addon.extract_derive_generic({ kind: "value", value: "a" }),
+      { kind: "value", value: "A" }
+    );
+    assert.deepEqual(addon.extract_derive_generic({ kind: "empty" }), {
+      kind: "empty",
+    });
+  });
+});
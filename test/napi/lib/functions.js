@@ -108,6 +108,19 @@ describe("JsFunction", function () {
     );
   });
 
+  it("reports a panic with the panic policy of the module", function () {
+    try {
+      addon.panic();
+    } catch (err) {
+      assert.instanceOf(err, Error);
+      assert.strictEqual(err.isPanic, true);
+      assert.match(err.rustBacktrace, /^panicked at .*functions\.rs:\d+:\d+$/);
+      return;
+    }
+
+    assert.fail("expected a panic to throw");
+  });
+
   it("lets panic override a throw", function () {
     assert.throws(
       function () {
@@ -1,4 +1,5 @@
 const assert = require("assert");
+const { Worker } = require("worker_threads");
 
 const addon = require("..");
 
@@ -37,6 +38,22 @@ describe("Futures", () => {
         );
       }, /exception/i);
     });
+
+    it("should include the exception as the cause", async () => {
+      try {
+        await addon.lazy_async_add(
+          () => 1,
+          () => {
+            throw new Error("Failed to get Y");
+          }
+        );
+      } catch (err) {
+        assert.strictEqual(err.cause.message, "Error: Failed to get Y");
+        return;
+      }
+
+      assert.fail("expected promise to reject");
+    });
   });
 
   describe("JsFuture", () => {
@@ -54,5 +71,119 @@ describe("Futures", () => {
         });
       }, /exception/i);
     });
+
+    it("should include the rejection in the `JoinError`", async () => {
+      const exception = await addon.lazy_async_exception(async () => {
+        const err = new TypeError("Oh, no!", {
+          cause: new RangeError("Too big"),
+        });
+
+        err.code = "ERR_OH_NO";
+
+        throw err;
+      });
+
+      assert.deepStrictEqual(exception, {
+        name: "TypeError",
+        message: "Oh, no!",
+        code: "ERR_OH_NO",
+        hasStack: true,
+        cause: "RangeError: Too big",
+        display: "TypeError: Oh, no!",
+      });
+    });
+
+    it("should convert a rejection that is not an `Error`", async () => {
+      const exception = await addon.lazy_async_exception(async () => {
+        throw "Oh, no!";
+      });
+
+      assert.strictEqual(exception.message, "Oh, no!");
+      assert.strictEqual(exception.display, "Oh, no!");
+      assert.strictEqual(exception.hasStack, false);
+    });
+  });
+
+  describe("Async functions", () => {
+    it("should resolve with the output of an async fn", async () => {
+      assert.strictEqual(await addon.async_add(1, 2), 3);
+    });
+
+    it("should return a promise", () => {
+      const promise = addon.async_add(1, 2);
+
+      assert.ok(promise instanceof Promise);
+
+      return promise;
+    });
+
+    it("should reject with an `Err` output", async () => {
+      try {
+        await addon.async_reject("Oh, no!");
+      } catch (err) {
+        assert.strictEqual(err, "Oh, no!");
+        return;
+      }
+
+      assert.fail("expected promise to reject");
+    });
+
+    it("should reject if the future panics", async () => {
+      await assertRejects(() => addon.async_panic(), /panic.*Oh, no!/);
+    });
+
+    it("should throw synchronously for invalid arguments", () => {
+      assert.throws(() => addon.async_add("1", 2), /arguments\[0\]/);
+    });
+
+    it("should call an `async` export on the JavaScript thread", async () => {
+      const nums = new Float64Array([1, 2, 3, 4]);
+
+      assert.strictEqual(await addon.async_sum(nums), 10);
+    });
+
+    it("should create a function with `JsFunction::new_async`", async () => {
+      assert.strictEqual(await addon.async_double(21), 42);
+    });
+  });
+
+  describe("Executor", () => {
+    it("should spawn a future with `cx.spawn`", async () => {
+      assert.strictEqual(await addon.spawn_add(1, 2), 3);
+    });
+
+    it("should not replace the executor of an instance", async () => {
+      await addon.async_add(1, 2);
+
+      assert.strictEqual(addon.set_counting_executor(), false);
+    });
+
+    it("should shut down the executor of an instance", async () => {
+      const count = addon.executor_shutdown_count();
+      const worker = new Worker(
+        `
+        const { parentPort, workerData } = require("worker_threads");
+        const addon = require(workerData);
+
+        if (!addon.set_counting_executor()) {
+          throw new Error("Expected executor to be set");
+        }
+
+        addon.async_add(1, 2).then((sum) => parentPort.postMessage(sum));
+        `,
+        { eval: true, workerData: require.resolve("..") }
+      );
+
+      const sum = await new Promise((resolve, reject) => {
+        worker.once("message", resolve);
+        worker.once("error", reject);
+      });
+
+      assert.strictEqual(sum, 3);
+
+      await worker.terminate();
+
+      assert.strictEqual(addon.executor_shutdown_count(), count + 1);
+    });
   });
 });
@@ -124,4 +124,196 @@ describe("JsObject", function () {
 
     assert.strictEqual(addon.call_symbol_method(obj, sym), "hello");
   });
+
+  it("wraps and unwraps a Rust value", function () {
+    class Point {}
+
+    const obj = new Point();
+
+    assert.strictEqual(addon.unwrap_string(obj), undefined);
+
+    addon.wrap_string(obj, "hello");
+
+    assert.instanceOf(obj, Point);
+    assert.strictEqual(addon.unwrap_string(obj), "hello");
+    assert.strictEqual(addon.unwrap_number(obj), undefined);
+    assert.throws(
+      () => addon.wrap_string(obj, "world"),
+      TypeError,
+      "Object has already been wrapped"
+    );
+  });
+
+  it("removes a wrapped Rust value", function () {
+    const obj = {};
+
+    addon.wrap_string(obj, "hello");
+
+    assert.strictEqual(addon.remove_wrap_string(obj), "hello");
+    assert.strictEqual(addon.unwrap_string(obj), undefined);
+    assert.strictEqual(addon.remove_wrap_string(obj), undefined);
+
+    // The object may be wrapped again
+    addon.wrap_string(obj, "world");
+    assert.strictEqual(addon.unwrap_string(obj), "world");
+  });
+
+  it("does not unwrap the instance of a class", function () {
+    const counter = new addon.Counter(1);
+
+    assert.strictEqual(addon.unwrap_string(counter), undefined);
+    assert.strictEqual(addon.remove_wrap_string(counter), undefined);
+    assert.throws(() => addon.wrap_string(counter, "hello"), TypeError);
+    assert.strictEqual(counter.increment(), 2);
+  });
+
+  it("finalizes a wrapped Rust value", function (cb) {
+    // IIFE to allow GC
+    (function () {
+      addon.wrap_with_finalizer({}, () => cb());
+    })();
+
+    global.gc();
+  });
+
+  it("tags objects with a type tag", function () {
+    const obj = {};
+
+    assert.isFalse(addon.check_object_type_tag(obj, "a"));
+
+    addon.type_tag_object(obj, "a");
+
+    assert.isTrue(addon.check_object_type_tag(obj, "a"));
+    assert.isFalse(addon.check_object_type_tag(obj, "b"));
+    assert.isFalse(addon.check_object_type_tag({}, "a"));
+    assert.throws(
+      () => addon.type_tag_object(obj, "b"),
+      TypeError,
+      "Object already has a type tag"
+    );
+
+    addon.type_tag_of_rust_type();
+  });
+
+  it("cannot tag a wrapped object", function () {
+    const obj = {};
+
+    addon.wrap_string(obj, "hello");
+
+    assert.throws(() => addon.type_tag_object(obj, "a"), TypeError);
+    assert.strictEqual(addon.unwrap_string(obj), "hello");
+  });
+
+  it("cannot wrap a tagged object", function () {
+    const obj = {};
+
+    addon.type_tag_object(obj, "a");
+
+    assert.throws(
+      () => addon.wrap_string(obj, "hello"),
+      TypeError,
+      "Object already has a type tag"
+    );
+    assert.strictEqual(addon.unwrap_string(obj), undefined);
+    assert.isTrue(addon.check_object_type_tag(obj, "a"));
+  });
+
+  it("calls the finalizers of an object", function (cb) {
+    let count = 0;
+
+    // IIFE to allow GC
+    (function () {
+      const obj = {};
+      const finalize = () => {
+        if (++count === 2) {
+          cb();
+        }
+      };
+
+      addon.add_finalizer(obj, finalize);
+      addon.add_finalizer(obj, finalize);
+    })();
+
+    global.gc();
+  });
+
+  it("defines properties with attributes and accessors", function () {
+    const obj = addon.define_properties();
+
+    assert.deepEqual(Object.keys(obj), ["version", "count"]);
+    assert.strictEqual(obj.version, "1.0.0");
+    assert.strictEqual(obj.secret, "hunter2");
+    assert.throws(() => {
+      "use strict";
+      obj.version = "2.0.0";
+    }, TypeError);
+    assert.strictEqual(obj.version, "1.0.0");
+
+    assert.strictEqual(obj.count, 0);
+    obj.count = 42;
+    assert.strictEqual(obj.count, 42);
+  });
+
+  it("keeps accessors alive after the object is collected", async function () {
+    const { ref, get, set } = (() => {
+      const obj = addon.define_properties();
+      const { get, set } = Object.getOwnPropertyDescriptor(obj, "count");
+
+      return { ref: new WeakRef(obj), get, set };
+    })();
+
+    // Finalizers run after the garbage collection completes
+    for (let i = 0; i < 2; i++) {
+      global.gc();
+      await new Promise((resolve) => setImmediate(resolve));
+    }
+
+    assert.strictEqual(ref.deref(), undefined);
+
+    set(42);
+    assert.strictEqual(get(), 42);
+  });
+
+  it("defines accessors with modified Object builtins", function () {
+    const { defineProperty } = Object;
+
+    // Look up the builtins before they are modified
+    addon.define_properties();
+
+    Object.prototype.value = 1;
+    Object.prototype.writable = true;
+    Object.defineProperty = () => {
+      throw new Error("Object.defineProperty was called");
+    };
+
+    try {
+      const obj = addon.define_properties();
+
+      assert.strictEqual(obj.count, 0);
+    } finally {
+      Object.defineProperty = defineProperty;
+      delete Object.prototype.value;
+      delete Object.prototype.writable;
+    }
+  });
+
+  it("defines a property with a symbol key", function () {
+    const obj = {};
+    const key = Symbol("key");
+
+    addon.define_property(obj, key, "hello");
+
+    assert.deepEqual(Object.getOwnPropertyDescriptor(obj, key), {
+      value: "hello",
+      writable: true,
+      enumerable: false,
+      configurable: true,
+    });
+  });
+
+  it("throws when a property cannot be defined", function () {
+    const obj = Object.freeze({});
+
+    assert.throws(() => addon.define_property(obj, "a", 1), TypeError);
+  });
 });
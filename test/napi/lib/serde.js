@@ -0,0 +1,99 @@
+const addon = require("..");
+const assert = require("chai").assert;
+
+describe("serde", function () {
+  const user = {
+    name: "Ada",
+    age: 36,
+    email: null,
+    tags: ["admin", "math"],
+    avatar: Buffer.from([1, 2, 3]),
+    role: { Admin: { level: 2 } },
+    scores: { a: 1.5, b: 2 },
+  };
+
+  it("should round-trip structs", function () {
+    assert.deepEqual(addon.serde_round_trip_user(user), user);
+  });
+
+  it("should round-trip enums", function () {
+    for (const role of ["Guest", { Member: 7 }, { Admin: { level: 1 } }]) {
+      assert.deepEqual(
+        addon.serde_round_trip_user({ ...user, role }).role,
+        role
+      );
+    }
+  });
+
+  it("should treat undefined as None", function () {
+    const { email, ...rest } = user;
+
+    assert.strictEqual(addon.serde_round_trip_user(rest).email, null);
+  });
+
+  it("should deserialize typed arrays as sequences", function () {
+    assert.deepEqual(
+      addon.serde_round_trip_vec(new Uint8Array([4, 5])),
+      [4, 5]
+    );
+    assert.deepEqual(addon.serde_round_trip_vec(Buffer.from([6])), [6]);
+    assert.deepEqual(addon.serde_round_trip_vec(new ArrayBuffer(2)), [0, 0]);
+    assert.deepEqual(addon.serde_round_trip_vec([7, 8]), [7, 8]);
+  });
+
+  it("should throw a TypeError for invalid values", function () {
+    assert.throws(
+      () => addon.serde_round_trip_user({ ...user, age: 300 }),
+      TypeError,
+      "invalid value"
+    );
+    assert.throws(
+      () => addon.serde_round_trip_user({ ...user, role: "Owner" }),
+      TypeError,
+      "unknown variant"
+    );
+    assert.throws(
+      () => addon.serde_round_trip_user({ ...user, name: undefined }),
+      TypeError
+    );
+  });
+
+  it("should round-trip serde_json::Value", function () {
+    const value = {
+      null: null,
+      bool: true,
+      int: -42,
+      float: 1.5,
+      string: "hello",
+      array: [1, "two", [3]],
+      object: { nested: { deep: [] } },
+    };
+
+    assert.deepEqual(addon.serde_round_trip_json(value), value);
+    assert.deepEqual(JSON.parse(addon.serde_json_to_string(value)), value);
+  });
+
+  it("should round trip a __proto__ key as an own property", function () {
+    const json = '{"__proto__":{"polluted":true},"a":1}';
+    const value = addon.serde_json_from_string(json);
+
+    assert.strictEqual(Object.getPrototypeOf(value), Object.prototype);
+    assert.deepEqual(Object.keys(value), ["__proto__", "a"]);
+    assert.strictEqual(value.polluted, undefined);
+    assert.strictEqual(addon.serde_json_to_string(value), json);
+
+    const copy = addon.serde_round_trip_json(value);
+
+    assert.strictEqual(Object.getPrototypeOf(copy), Object.prototype);
+    assert.deepEqual(Object.keys(copy), ["__proto__", "a"]);
+  });
+
+  it("should convert large integers to BigInt", function () {
+    const json = '{"big":18446744073709551615,"small":-9223372036854775808}';
+    const value = addon.serde_json_from_string(json);
+
+    assert.strictEqual(value.big, 18446744073709551615n);
+    assert.strictEqual(value.small, -9223372036854775808n);
+    assert.strictEqual(addon.serde_json_to_string(value), json);
+  });
+});
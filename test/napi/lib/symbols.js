@@ -0,0 +1,108 @@
+const addon = require("..");
+const { assert } = require("chai");
+
+describe("JsSymbol", () => {
+  it("should create a unique symbol with a description", () => {
+    const a = addon.create_symbol("neon");
+    const b = addon.create_symbol("neon");
+
+    assert.typeOf(a, "symbol");
+    assert.strictEqual(a.description, "neon");
+    assert.notStrictEqual(a, b);
+    assert.strictEqual(Symbol.keyFor(a), undefined);
+  });
+
+  it("should create a symbol without a description", () => {
+    const symbol = addon.create_symbol();
+
+    assert.typeOf(symbol, "symbol");
+    assert.strictEqual(symbol.description, undefined);
+  });
+
+  // Requires the `napi-9` feature
+  (addon.symbol_for ? it : it.skip)(
+    "should get a symbol from the global registry",
+    () => {
+      const symbol = addon.symbol_for("neon.symbols");
+
+      assert.strictEqual(symbol, Symbol.for("neon.symbols"));
+      assert.strictEqual(addon.symbol_for("neon.symbols"), symbol);
+      assert.strictEqual(Symbol.keyFor(symbol), "neon.symbols");
+    }
+  );
+
+  it("should read the description of a symbol", () => {
+    assert.strictEqual(addon.symbol_description(Symbol("neon")), "neon");
+    assert.strictEqual(addon.symbol_description(Symbol("")), "");
+    assert.strictEqual(addon.symbol_description(Symbol()), undefined);
+    assert.strictEqual(
+      addon.symbol_description(Symbol.iterator),
+      "Symbol.iterator"
+    );
+  });
+
+  it("should read the description with a modified Symbol.prototype", () => {
+    const descriptor = Object.getOwnPropertyDescriptor(
+      Symbol.prototype,
+      "description"
+    );
+
+    // Caches the original getter
+    addon.symbol_description(Symbol());
+
+    Object.defineProperty(Symbol.prototype, "description", {
+      get: () => "patched",
+      configurable: true,
+    });
+
+    try {
+      assert.strictEqual(addon.symbol_description(Symbol("neon")), "neon");
+    } finally {
+      Object.defineProperty(Symbol.prototype, "description", descriptor);
+    }
+  });
+
+  it("should only downcast symbols", () => {
+    assert.strictEqual(addon.is_symbol(Symbol()), true);
+    assert.strictEqual(addon.is_symbol(Symbol.for("neon")), true);
+    assert.strictEqual(addon.is_symbol("neon"), false);
+    assert.strictEqual(addon.is_symbol(Object(Symbol())), false);
+    assert.strictEqual(addon.is_symbol({}), false);
+  });
+
+  it("should throw when a symbol is expected", () => {
+    assert.throws(() => addon.symbol_description("neon"), TypeError);
+  });
+
+  it("should get a symbol-keyed property", () => {
+    const key = Symbol("key");
+    const obj = { [key]: 42, key: "string" };
+
+    assert.strictEqual(addon.get_symbol_property(obj, key), 42);
+    assert.strictEqual(
+      addon.get_symbol_property(obj, Symbol("key")),
+      undefined
+    );
+  });
+
+  it("should set well-known symbols", () => {
+    const range = addon.range_iterable(3);
+
+    assert.deepEqual([...range], [0, 1, 2]);
+    assert.strictEqual(Object.prototype.toString.call(range), "[object Range]");
+    assert.strictEqual(addon.async_iterator_symbol(), Symbol.asyncIterator);
+  });
+
+  it("should not read well-known symbols from a replaced `Symbol`", () => {
+    const { Symbol: original } = globalThis;
+
+    addon.async_iterator_symbol();
+    globalThis.Symbol = { asyncIterator: "spoofed" };
+
+    try {
+      assert.strictEqual(addon.async_iterator_symbol(), original.asyncIterator);
+    } finally {
+      globalThis.Symbol = original;
+    }
+  });
+});
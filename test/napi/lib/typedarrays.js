@@ -551,4 +551,102 @@ describe("Typed arrays", function () {
       assert.fail("region overrun should be validated when instantiating");
     } catch (expected) {}
   });
+
+  it("constructs a DataView over a region of an ArrayBuffer", function () {
+    var buf = new ArrayBuffer(16);
+    var view = addon.return_data_view_from_arraybuffer_region(buf, 4, 8);
+
+    assert.instanceOf(view, DataView);
+    assert.strictEqual(view.buffer, buf);
+    assert.strictEqual(view.byteOffset, 4);
+    assert.strictEqual(view.byteLength, 8);
+    assert.deepEqual(addon.get_data_view_info(view), {
+      byteOffset: 4,
+      byteLength: 8,
+      buffer: buf,
+    });
+
+    assert.throws(
+      () => addon.return_data_view_from_arraybuffer_region(buf, 12, 8),
+      RangeError
+    );
+  });
+
+  it("reads numbers from a DataView", function () {
+    var view = new DataView(new ArrayBuffer(15));
+    view.setUint8(0, 200);
+    view.setInt16(1, -1234, true);
+    view.setUint32(3, 0xdeadbeef, false);
+    view.setFloat64(7, Math.PI, true);
+
+    assert.deepEqual(addon.read_data_view(view), [
+      200,
+      -1234,
+      0xdeadbeef,
+      Math.PI,
+    ]);
+  });
+
+  it("writes numbers to a DataView", function () {
+    var view = new DataView(new ArrayBuffer(11));
+    addon.write_data_view(view);
+
+    assert.strictEqual(view.getInt8(0), -2);
+    assert.strictEqual(view.getUint16(1, false), 0x1234);
+    assert.strictEqual(view.getInt32(3, true), -100000);
+    assert.strictEqual(view.getFloat32(7, false), 1.5);
+  });
+
+  it("reads 64-bit integers from a DataView with an offset", function () {
+    var buf = new ArrayBuffer(16);
+    new DataView(buf).setBigUint64(4, 2n ** 64n - 2n, true);
+    var view = new DataView(buf, 2, 12);
+
+    assert.strictEqual(
+      addon.read_data_view_u64(view, 2),
+      "18446744073709551614"
+    );
+  });
+
+  it("throws a RangeError for accesses outside of a DataView", function () {
+    var view = new DataView(new ArrayBuffer(16), 8, 4);
+
+    assert.throws(() => addon.read_data_view_u64(view, 0), RangeError);
+    assert.throws(
+      () => addon.read_data_view(new DataView(new ArrayBuffer(14))),
+      RangeError
+    );
+    assert.throws(
+      () => addon.write_data_view(new DataView(new ArrayBuffer(10))),
+      RangeError
+    );
+  });
+
+  it("writes to a DataView using the lock API", function () {
+    var buf = new ArrayBuffer(8);
+    var view = new DataView(buf, 2, 4);
+    addon.write_data_view_with_lock(view, 1, 42);
+
+    assert.deepEqual(
+      new Uint8Array(buf),
+      new Uint8Array([0, 0, 0, 42, 0, 0, 0, 0])
+    );
+  });
+
+  it("gets a Uint8ClampedArray copied from a slice", function () {
+    var arr = addon.return_uint8_clamped_array_from_slice();
+
+    assert.instanceOf(arr, Uint8ClampedArray);
+    assert.deepEqual(
+      arr,
+      Uint8ClampedArray.from([-1, 0.5, 1.5, 2.5, 254.5, 300, NaN])
+    );
+  });
+
+  it("correctly writes to a Uint8ClampedArray", function () {
+    var arr = new Uint8ClampedArray([0, 100, 200]);
+    addon.double_uint8_clamped_array(arr);
+
+    assert.deepEqual(arr, new Uint8ClampedArray([0, 200, 255]));
+  });
 });
@@ -37,6 +37,18 @@ describe("type checks", function () {
     assert(!addon.is_buffer(new ArrayBuffer(64)));
   });
 
+  it("is_data_view", function () {
+    assert(addon.is_data_view(new DataView(new ArrayBuffer(8))));
+    assert(!addon.is_data_view(new Uint8Array(8)));
+    assert(!addon.is_data_view(new ArrayBuffer(8)));
+  });
+
+  it("is_uint8_clamped_array", function () {
+    assert(addon.is_uint8_clamped_array(new Uint8ClampedArray(8)));
+    assert(!addon.is_uint8_clamped_array(new Uint8Array(8)));
+    assert(!addon.is_uint8_clamped_array(Buffer.alloc(8)));
+  });
+
   it("is_error", function () {
     assert(addon.is_error(new Error()));
     assert(addon.is_error(new TypeError()));
@@ -212,4 +212,54 @@ describe("Instance-local storage", () => {
       setTimeout(cb, 200);
     });
   });
+
+  it("should run cleanup hooks when a worker exits", async () => {
+    const worker = new Worker(
+      `
+      const { parentPort, workerData } = require("worker_threads");
+      const addon = require(workerData);
+
+      addon.on_cleanup_log("first");
+      addon.on_cleanup_async_log("async");
+      addon.on_cleanup_log("last");
+
+      parentPort.postMessage("ready");
+      `,
+      { eval: true, workerData: require.resolve("..") }
+    );
+
+    await new Promise((resolve, reject) => {
+      worker.once("message", resolve);
+      worker.once("error", reject);
+    });
+
+    await worker.terminate();
+
+    // Hooks run in reverse order and the worker waits for the async hook
+    assert.strictEqual(addon.take_cleanup_log(), "last,first,async");
+  });
+
+  it("should not create resources in finalizers after teardown", async () => {
+    const worker = new Worker(
+      `
+      const { parentPort, workerData } = require("worker_threads");
+      const addon = require(workerData);
+
+      globalThis.box = addon.box_finalize_log("finalized");
+
+      parentPort.postMessage("ready");
+      `,
+      { eval: true, workerData: require.resolve("..") }
+    );
+
+    await new Promise((resolve, reject) => {
+      worker.once("message", resolve);
+      worker.once("error", reject);
+    });
+
+    await worker.terminate();
+
+    // The finalizer ran after teardown with a closed channel
+    assert.strictEqual(addon.take_cleanup_log(), "finalized");
+  });
 });
@@ -31,6 +31,14 @@ pub fn person_new(mut cx: FunctionContext) -> JsResult<JsBox<Person>> {
     Ok(cx.boxed(person))
 }
 
+pub fn person_new_with_size(mut cx: FunctionContext) -> JsResult<JsBox<Person>> {
+    let name = cx.argument::<JsString>(0)?.value(&mut cx);
+    let size = cx.argument::<JsNumber>(1)?.value(&mut cx) as usize;
+    let person = Person::new(name);
+
+    Ok(JsBox::new_with_size(&mut cx, person, size))
+}
+
 pub fn person_greet(mut cx: FunctionContext) -> JsResult<JsString> {
     let person = cx.argument::<JsBox<Person>>(0)?;
     let greeting = cx.string(person.greet());
@@ -72,3 +80,10 @@ pub fn ref_person_fail(mut cx: FunctionContext) -> JsResult<JsUndefined> {
 pub fn external_unit(mut cx: FunctionContext) -> JsResult<JsBox<()>> {
     Ok(cx.boxed(()))
 }
+
+pub fn adjust_external_memory(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let delta = cx.argument::<JsNumber>(0)?.value(&mut cx) as i64;
+    let total = cx.adjust_external_memory(delta);
+
+    Ok(cx.number(total as f64))
+}
@@ -0,0 +1,85 @@
+use neon::prelude::*;
+
+pub struct Counter {
+    count: f64,
+}
+
+#[neon::class]
+impl Counter {
+    fn new(count: f64) -> Self {
+        Self { count }
+    }
+
+    fn increment(&mut self) -> f64 {
+        self.count += 1.0;
+        self.count
+    }
+
+    fn add<'cx>(&mut self, cx: &mut FunctionContext<'cx>, n: f64) -> JsResult<'cx, JsNumber> {
+        if n < 0.0 {
+            return cx.throw_range_error("expected a positive number");
+        }
+
+        self.count += n;
+
+        Ok(cx.number(self.count))
+    }
+
+    fn with_callback(&mut self, cx: &mut FunctionContext, f: Handle<JsFunction>) -> NeonResult<()> {
+        let undefined = cx.undefined();
+
+        f.call(cx, undefined, [])?;
+
+        Ok(())
+    }
+
+    #[neon(getter)]
+    fn count(&self) -> f64 {
+        self.count
+    }
+
+    #[neon(setter)]
+    fn set_count(&mut self, count: f64) {
+        self.count = count;
+    }
+
+    #[neon(getter, name = "isZero")]
+    fn is_zero(&self) -> bool {
+        self.count == 0.0
+    }
+
+    #[neon(name = "describe")]
+    fn to_description(&self) -> String {
+        self.label(": ")
+    }
+
+    #[neon(skip)]
+    fn label(&self, sep: &str) -> String {
+        format!("Counter{}{}", sep, self.count)
+    }
+
+    fn zero() -> f64 {
+        0.0
+    }
+}
+
+pub struct Point {
+    x: f64,
+    y: f64,
+}
+
+#[neon::class(name = "Point2D")]
+impl Point {
+    #[neon(constructor)]
+    fn create(cx: &mut FunctionContext, x: f64, y: f64) -> NeonResult<Self> {
+        if x.is_nan() || y.is_nan() {
+            return cx.throw_range_error("coordinates must be numbers");
+        }
+
+        Ok(Self { x, y })
+    }
+
+    fn length(&self) -> f64 {
+        self.x.hypot(self.y)
+    }
+}
@@ -0,0 +1,213 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+use neon::{
+    prelude::*,
+    types::{JsMap, JsSet, JsWeakMap},
+};
+
+#[neon::export]
+fn create_map<'cx>(cx: &mut FunctionContext<'cx>) -> JsResult<'cx, JsMap> {
+    let map = JsMap::new(cx)?;
+    let obj = cx.empty_object();
+    let a = cx.string("a");
+    let one = cx.number(1);
+    let two = cx.number(2);
+
+    map.set(cx, a, one)?;
+    map.set(cx, obj, two)?;
+
+    Ok(map)
+}
+
+#[neon::export]
+fn map_get<'cx>(
+    cx: &mut FunctionContext<'cx>,
+    map: Handle<'cx, JsMap>,
+    key: Handle<'cx, JsValue>,
+) -> JsResult<'cx, JsValue> {
+    map.get(cx, key)
+}
+
+#[neon::export]
+fn map_has(cx: &mut FunctionContext, map: Handle<JsMap>, key: Handle<JsValue>) -> NeonResult<bool> {
+    map.has(cx, key)
+}
+
+#[neon::export]
+fn map_delete(
+    cx: &mut FunctionContext,
+    map: Handle<JsMap>,
+    key: Handle<JsValue>,
+) -> NeonResult<bool> {
+    map.delete(cx, key)
+}
+
+#[neon::export]
+fn map_clear(cx: &mut FunctionContext, map: Handle<JsMap>) -> NeonResult<()> {
+    map.clear(cx)
+}
+
+#[neon::export]
+fn map_size(cx: &mut FunctionContext, map: Handle<JsMap>) -> NeonResult<f64> {
+    Ok(map.size(cx)? as f64)
+}
+
+#[neon::export]
+fn map_entries<'cx>(
+    cx: &mut FunctionContext<'cx>,
+    map: Handle<'cx, JsMap>,
+) -> JsResult<'cx, JsArray> {
+    let entries = map.entries(cx)?;
+    let arr = cx.empty_array();
+
+    for (i, (k, v)) in entries.into_iter().enumerate() {
+        let entry = cx.empty_array();
+
+        entry.set(cx, 0, k)?;
+        entry.set(cx, 1, v)?;
+        arr.set(cx, i as u32, entry)?;
+    }
+
+    Ok(arr)
+}
+
+#[neon::export]
+fn map_from_hash_map<'cx>(cx: &mut FunctionContext<'cx>) -> JsResult<'cx, JsMap> {
+    let map: HashMap<&str, f64> = [("a", 1.0), ("b", 2.0)].iter().copied().collect();
+
+    JsMap::from_entries(cx, map)
+}
+
+#[neon::export]
+fn map_sum_values(cx: &mut FunctionContext, map: Handle<JsMap>) -> NeonResult<f64> {
+    let map: HashMap<String, f64> = map.collect(cx)?;
+
+    Ok(map.values().sum())
+}
+
+#[neon::export]
+fn roundtrip_btree_map(map: BTreeMap<String, f64>) -> BTreeMap<String, f64> {
+    map
+}
+
+#[neon::export]
+fn create_set<'cx>(cx: &mut FunctionContext<'cx>) -> JsResult<'cx, JsSet> {
+    JsSet::from_values(cx, ["a", "b", "a"])
+}
+
+#[neon::export]
+fn set_add(cx: &mut FunctionContext, set: Handle<JsSet>, value: Handle<JsValue>) -> NeonResult<()> {
+    set.add(cx, value)
+}
+
+#[neon::export]
+fn set_has(
+    cx: &mut FunctionContext,
+    set: Handle<JsSet>,
+    value: Handle<JsValue>,
+) -> NeonResult<bool> {
+    set.has(cx, value)
+}
+
+#[neon::export]
+fn set_delete(
+    cx: &mut FunctionContext,
+    set: Handle<JsSet>,
+    value: Handle<JsValue>,
+) -> NeonResult<bool> {
+    set.delete(cx, value)
+}
+
+#[neon::export]
+fn set_clear(cx: &mut FunctionContext, set: Handle<JsSet>) -> NeonResult<()> {
+    set.clear(cx)
+}
+
+#[neon::export]
+fn set_size(cx: &mut FunctionContext, set: Handle<JsSet>) -> NeonResult<f64> {
+    Ok(set.size(cx)? as f64)
+}
+
+#[neon::export]
+fn set_values<'cx>(
+    cx: &mut FunctionContext<'cx>,
+    set: Handle<'cx, JsSet>,
+) -> JsResult<'cx, JsArray> {
+    let values = set.values(cx)?;
+    let arr = cx.empty_array();
+
+    for (i, v) in values.into_iter().enumerate() {
+        arr.set(cx, i as u32, v)?;
+    }
+
+    Ok(arr)
+}
+
+#[neon::export]
+fn set_count_unique(cx: &mut FunctionContext, set: Handle<JsSet>) -> NeonResult<f64> {
+    let values: HashSet<String> = set.collect(cx)?;
+
+    Ok(values.len() as f64)
+}
+
+#[neon::export]
+fn roundtrip_hash_set(set: HashSet<String>) -> BTreeSet<String> {
+    set.into_iter().collect()
+}
+
+#[neon::export]
+fn create_weak_map<'cx>(cx: &mut FunctionContext<'cx>) -> JsResult<'cx, JsWeakMap> {
+    JsWeakMap::new(cx)
+}
+
+#[neon::export]
+fn weak_map_get<'cx>(
+    cx: &mut FunctionContext<'cx>,
+    map: Handle<'cx, JsWeakMap>,
+    key: Handle<'cx, JsObject>,
+) -> JsResult<'cx, JsValue> {
+    map.get(cx, key)
+}
+
+#[neon::export]
+fn weak_map_set(
+    cx: &mut FunctionContext,
+    map: Handle<JsWeakMap>,
+    key: Handle<JsObject>,
+    value: Handle<JsValue>,
+) -> NeonResult<()> {
+    map.set(cx, key, value)
+}
+
+#[neon::export]
+fn weak_map_has(
+    cx: &mut FunctionContext,
+    map: Handle<JsWeakMap>,
+    key: Handle<JsObject>,
+) -> NeonResult<bool> {
+    map.has(cx, key)
+}
+
+#[neon::export]
+fn weak_map_delete(
+    cx: &mut FunctionContext,
+    map: Handle<JsWeakMap>,
+    key: Handle<JsObject>,
+) -> NeonResult<bool> {
+    map.delete(cx, key)
+}
+
+#[neon::export]
+fn is_map(cx: &mut FunctionContext, value: Handle<JsValue>) -> bool {
+    value.is_a::<JsMap, _>(cx)
+}
+
+#[neon::export]
+fn is_set(cx: &mut FunctionContext, value: Handle<JsValue>) -> bool {
+    value.is_a::<JsSet, _>(cx)
+}
+
+#[neon::export]
+fn is_weak_map(cx: &mut FunctionContext, value: Handle<JsValue>) -> bool {
+    value.is_a::<JsWeakMap, _>(cx)
+}
@@ -1,3 +1,5 @@
+use std::{error::Error, fmt};
+
 use neon::prelude::*;
 
 pub fn new_error(mut cx: FunctionContext) -> JsResult<JsError> {
@@ -24,6 +26,51 @@ pub fn throw_error(mut cx: FunctionContext) -> JsResult<JsUndefined> {
     cx.throw_error(msg)
 }
 
+pub fn throw_error_with_code(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let code = cx.argument::<JsString>(0)?.value(&mut cx);
+    let msg = cx.argument::<JsString>(1)?.value(&mut cx);
+
+    cx.throw_type_error_with_code(code, msg)
+}
+
+pub fn new_error_with_options(mut cx: FunctionContext) -> JsResult<JsError> {
+    let cause = cx.argument::<JsValue>(0)?;
+    let extra = cx.number(42);
+
+    JsError::range_error_with(&cx, "outer")
+        .code("ERR_OUTER")
+        .cause(cause)
+        .prop("extra", extra)
+        .apply(&mut cx)
+}
+
+#[derive(Debug)]
+struct ReadConfigError(std::io::Error);
+
+impl fmt::Display for ReadConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("failed to read config")
+    }
+}
+
+impl Error for ReadConfigError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.0)
+    }
+}
+
+pub fn throw_error_with_rust_cause(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let err = ReadConfigError(std::io::Error::new(
+        std::io::ErrorKind::NotFound,
+        "config.json not found",
+    ));
+
+    JsError::error_with(&cx, "failed to start")
+        .code("ERR_START")
+        .cause_error(&err)
+        .throw(&mut cx)
+}
+
 pub fn downcast_error(mut cx: FunctionContext) -> JsResult<JsString> {
     let s = cx.string("hi");
     if let Err(e) = s.downcast::<JsNumber, _>(&mut cx) {
@@ -32,3 +79,76 @@ pub fn downcast_error(mut cx: FunctionContext) -> JsResult<JsString> {
         panic!()
     }
 }
+
+#[derive(Debug, JsError)]
+enum ConfigError {
+    #[neon(code = "ERR_CONFIG_MISSING")]
+    Missing {
+        path: String,
+    },
+    #[neon(type_error)]
+    Invalid {
+        key: String,
+        expected_type: String,
+        #[neon(skip)]
+        #[allow(dead_code)]
+        value: f64,
+    },
+    #[neon(range_error)]
+    TooLarge {
+        size: u32,
+    },
+    Read(std::io::Error),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Missing { path } => write!(f, "missing config: {}", path),
+            Self::Invalid { key, .. } => write!(f, "invalid config key: {}", key),
+            Self::TooLarge { size } => write!(f, "config too large: {}", size),
+            Self::Read(_) => f.write_str("failed to read config"),
+        }
+    }
+}
+
+impl Error for ConfigError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Read(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+#[neon::export]
+fn derive_error(kind: String) -> Result<(), ConfigError> {
+    Err(match kind.as_str() {
+        "missing" => ConfigError::Missing {
+            path: "config.json".to_string(),
+        },
+        "invalid" => ConfigError::Invalid {
+            key: "port".to_string(),
+            expected_type: "number".to_string(),
+            value: 0.0,
+        },
+        "large" => ConfigError::TooLarge { size: 4096 },
+        _ => ConfigError::Read(std::io::Error::new(
+            std::io::ErrorKind::PermissionDenied,
+            "permission denied",
+        )),
+    })
+}
+
+#[neon::export]
+fn result_error(cx: &mut FunctionContext, kind: String) -> Result<f64, neon::result::Error> {
+    match kind.as_str() {
+        "parse" => Ok("not a number".parse::<f64>()?),
+        "code" => Err(neon::result::Error::new("custom error").code("ERR_CUSTOM")),
+        "source" => Err(ConfigError::Read(std::io::Error::new(
+            std::io::ErrorKind::PermissionDenied,
+            "permission denied",
+        )))?,
+        _ => Ok(cx.throw_range_error("thrown error")?),
+    }
+}
@@ -0,0 +1,70 @@
+use neon::prelude::*;
+
+#[neon::export]
+fn export_add(a: f64, b: f64) -> f64 {
+    a + b
+}
+
+#[neon::export(name = "exportRenamed")]
+fn export_renamed() -> &'static str {
+    "renamed"
+}
+
+#[neon::export]
+fn export_concat(a: String, b: String) -> String {
+    a + &b
+}
+
+#[neon::export]
+fn export_negate(b: bool) -> bool {
+    !b
+}
+
+#[neon::export]
+fn export_no_return() {}
+
+#[neon::export]
+fn export_handle<'cx>(
+    cx: &mut FunctionContext<'cx>,
+    obj: Handle<'cx, JsObject>,
+) -> JsResult<'cx, JsValue> {
+    obj.get_value(cx, "value")
+}
+
+#[neon::export]
+fn export_with_context(cx: &mut FunctionContext, n: f64) -> NeonResult<f64> {
+    if n < 0.0 {
+        return cx.throw_range_error("expected a positive number");
+    }
+
+    Ok(n.sqrt())
+}
+
+#[neon::export]
+fn export_panic() {
+    panic!("exported panic");
+}
+
+#[neon::export]
+fn export_scale(values: Vec<f64>, scale: Option<f64>) -> Vec<f64> {
+    let scale = scale.unwrap_or(1.0);
+
+    values.into_iter().map(|v| v * scale).collect()
+}
+
+// Init hooks are called after exports are registered
+#[neon::init]
+fn export_init(cx: &mut ModuleContext) -> NeonResult<()> {
+    let exports = cx.exports_object()?;
+    let registered = exports
+        .get_value(cx, "export_add")?
+        .is_a::<JsFunction, _>(cx);
+    let registered = cx.boolean(registered);
+
+    cx.export_value("exportInitSawExports", registered)
+}
+
+#[neon::export]
+fn export_typescript() -> String {
+    neon::typescript::generate()
+}
@@ -0,0 +1,195 @@
+use std::{collections::HashMap, time::SystemTime};
+
+use neon::{
+    prelude::*,
+    types::extract::{ArrayBuffer, Buffer, TryFromJs, TryIntoJs},
+};
+
+#[neon::export]
+fn extract_u8(n: u8) -> u8 {
+    n
+}
+
+#[neon::export]
+fn extract_i32(n: i32) -> i32 {
+    n
+}
+
+#[neon::export]
+fn extract_u64(n: u64) -> u64 {
+    n
+}
+
+#[neon::export]
+fn extract_f32(n: f32) -> f32 {
+    n
+}
+
+#[neon::export]
+fn extract_unsafe_integer() -> i64 {
+    i64::MAX
+}
+
+#[neon::export]
+fn extract_option(n: Option<f64>) -> Option<f64> {
+    n.map(|n| n * 2.0)
+}
+
+#[neon::export]
+fn extract_vec(v: Vec<String>) -> Vec<String> {
+    v.into_iter().rev().collect()
+}
+
+#[neon::export]
+fn extract_nested(v: Vec<Vec<u32>>) -> u32 {
+    v.into_iter().flatten().sum()
+}
+
+#[neon::export]
+fn extract_map(map: HashMap<String, f64>) -> HashMap<String, f64> {
+    map.into_iter().map(|(k, v)| (k, v + 1.0)).collect()
+}
+
+#[neon::export]
+fn extract_tuple(t: (String, f64, bool)) -> (bool, f64, String) {
+    (t.2, t.1, t.0)
+}
+
+#[neon::export]
+fn extract_result(ok: bool) -> Result<&'static str, String> {
+    if ok {
+        Ok("ok")
+    } else {
+        Err("failed".to_string())
+    }
+}
+
+#[neon::export]
+fn extract_bigint(n: i128) -> i128 {
+    n * 2
+}
+
+#[neon::export]
+fn extract_date(d: SystemTime) -> SystemTime {
+    d + std::time::Duration::from_secs(1)
+}
+
+#[neon::export]
+fn extract_buffer(b: Buffer) -> Buffer {
+    Buffer(b.0.into_iter().rev().collect())
+}
+
+#[neon::export]
+fn extract_array_buffer(b: ArrayBuffer) -> usize {
+    b.0.len()
+}
+
+#[neon::export]
+fn extract_argument_as(cx: &mut FunctionContext) -> NeonResult<f64> {
+    let a: f64 = cx.argument_as(0)?;
+    let b: Option<f64> = cx.argument_as(1)?;
+
+    Ok(a + b.unwrap_or(0.0))
+}
+
+#[neon::export]
+fn extract_call_as(cx: &mut FunctionContext, f: Handle<JsFunction>) -> NeonResult<String> {
+    f.call_as(cx, (), (1, "two", vec![3.0]))
+}
+
+#[neon::export]
+fn extract_get_set_as<'cx>(
+    cx: &mut FunctionContext<'cx>,
+    obj: Handle<'cx, JsObject>,
+) -> NeonResult<Handle<'cx, JsObject>> {
+    let count: u32 = obj.get_as(cx, "count")?;
+    let out = cx.empty_object();
+
+    out.set_as(cx, "count", count + 1)?;
+    out.set_as(cx, "tags", vec!["a", "b"])?;
+
+    Ok(out)
+}
+
+#[derive(TryFromJs, TryIntoJs)]
+struct Options {
+    retry: Retry,
+    #[neon(rename = "URL")]
+    url: String,
+    #[neon(default)]
+    verbose: bool,
+    #[neon(default = "default_timeout")]
+    timeout_ms: u32,
+    #[neon(skip)]
+    cache: Vec<u8>,
+}
+
+#[derive(TryFromJs, TryIntoJs)]
+struct Retry {
+    max: u32,
+    backoff: Backoff,
+}
+
+fn default_timeout() -> u32 {
+    1000
+}
+
+#[derive(TryFromJs, TryIntoJs)]
+enum Backoff {
+    Constant,
+    Exponential,
+    #[neon(rename = "none")]
+    Disabled,
+}
+
+#[derive(TryFromJs, TryIntoJs)]
+enum Shape {
+    Circle { radius: f64 },
+    Rect { width: f64, height: f64 },
+    Point,
+}
+
+#[derive(TryFromJs, TryIntoJs)]
+#[neon(tag = "kind")]
+enum Tagged<T> {
+    Value { value: T },
+    Empty,
+}
+
+#[neon::export]
+fn extract_derive_options(mut options: Options) -> Options {
+    options.retry.max += 1;
+    options.cache.push(1);
+    options
+}
+
+#[neon::export]
+fn extract_derive_area(shape: Shape) -> f64 {
+    match shape {
+        Shape::Circle { radius } => std::f64::consts::PI * radius * radius,
+        Shape::Rect { width, height } => width * height,
+        Shape::Point => 0.0,
+    }
+}
+
+#[neon::export]
+fn extract_derive_scale(shape: Shape, n: f64) -> Shape {
+    match shape {
+        Shape::Circle { radius } => Shape::Circle { radius: radius * n },
+        Shape::Rect { width, height } => Shape::Rect {
+            width: width * n,
+            height: height * n,
+        },
+        Shape::Point => Shape::Point,
+    }
+}
+
+#[neon::export]
+fn extract_derive_generic(v: Tagged<String>) -> Tagged<String> {
+    match v {
+        Tagged::Value { value } => Tagged::Value {
+            value: value.to_uppercase(),
+        },
+        Tagged::Empty => Tagged::Empty,
+    }
+}
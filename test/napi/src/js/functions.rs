@@ -1,4 +1,4 @@
-use neon::prelude::*;
+use neon::{panic::PanicPolicy, prelude::*};
 
 fn add1(mut cx: FunctionContext) -> JsResult<JsNumber> {
     let x = cx.argument::<JsNumber>(0)?.value(&mut cx);
@@ -142,6 +142,21 @@ pub fn check_string_and_number(mut cx: FunctionContext) -> JsResult<JsUndefined>
     Ok(cx.undefined())
 }
 
+// Panic policy of the module, set in `main`
+pub fn panic_policy() -> PanicPolicy {
+    PanicPolicy::new().location(true).error(panic_error)
+}
+
+// Marks errors created for panics
+fn panic_error<'cx>(cx: &mut TaskContext<'cx>, msg: &str) -> JsResult<'cx, JsObject> {
+    let err = cx.error(msg)?;
+    let is_panic = cx.boolean(true);
+
+    err.set(cx, "isPanic", is_panic)?;
+
+    Ok(err.upcast())
+}
+
 pub fn panic(_: FunctionContext) -> JsResult<JsUndefined> {
     panic!("zomg")
 }
@@ -1,6 +1,14 @@
 use {
-    neon::{prelude::*, types::buffer::TypedArray},
+    neon::{
+        executor::{BoxFuture, Executor},
+        prelude::*,
+        types::buffer::TypedArray,
+    },
     once_cell::sync::OnceCell,
+    std::{
+        future::Future,
+        sync::atomic::{AtomicUsize, Ordering},
+    },
     tokio::runtime::Runtime,
 };
 
@@ -80,3 +88,126 @@ pub fn lazy_async_sum(mut cx: FunctionContext) -> JsResult<JsPromise> {
 
     Ok(promise)
 }
+
+// Accepts a function that returns a rejected `Promise`.
+// Resolves with the details of the rejection.
+// Purpose: Test that `JoinError` includes the exception of a `JsFuture`.
+#[neon::export]
+fn lazy_async_exception<'cx>(
+    cx: &mut FunctionContext<'cx>,
+    f: Handle<JsFunction>,
+) -> JsResult<'cx, JsPromise> {
+    let future = f
+        .call_with(cx)
+        .apply::<JsPromise, _>(cx)?
+        .to_future(cx, |mut cx, result| {
+            result.or_throw(&mut cx)?;
+
+            Ok(())
+        })?;
+
+    let (deferred, promise) = cx.promise();
+    let channel = cx.channel();
+    let runtime = runtime(cx)?;
+
+    runtime.spawn(async move {
+        let exception = future.await.err().and_then(|err| err.exception().cloned());
+
+        deferred.settle_with(&channel, move |mut cx| {
+            let exception = match exception {
+                Some(exception) => exception,
+                None => return cx.throw_error("Expected an exception"),
+            };
+
+            let obj = cx.empty_object();
+            let name = cx.string(exception.name().unwrap_or_default());
+            let message = cx.string(exception.message());
+            let code = cx.string(exception.code().unwrap_or_default());
+            let has_stack = cx.boolean(exception.stack().is_some());
+            let cause = match exception.cause() {
+                Some(cause) => cx.string(cause.to_string()).upcast(),
+                None => cx.undefined().upcast::<JsValue>(),
+            };
+            let display = cx.string(exception.to_string());
+
+            obj.set(&mut cx, "name", name)?;
+            obj.set(&mut cx, "message", message)?;
+            obj.set(&mut cx, "code", code)?;
+            obj.set(&mut cx, "hasStack", has_stack)?;
+            obj.set(&mut cx, "cause", cause)?;
+            obj.set(&mut cx, "display", display)?;
+
+            Ok(obj)
+        });
+    });
+
+    Ok(promise)
+}
+
+#[neon::export]
+async fn async_add(a: f64, b: f64) -> f64 {
+    a + b
+}
+
+#[neon::export]
+async fn async_reject(msg: String) -> Result<(), String> {
+    Err(msg)
+}
+
+#[neon::export]
+async fn async_panic() {
+    panic!("Oh, no!");
+}
+
+// Reads the array on the JavaScript thread before spawning the future
+#[neon::export(async)]
+fn async_sum<'cx>(
+    cx: &mut FunctionContext<'cx>,
+    nums: Handle<'cx, JsFloat64Array>,
+) -> impl Future<Output = f64> {
+    let nums = nums.as_slice(cx).to_vec();
+
+    async move { nums.into_iter().sum() }
+}
+
+pub fn async_double<'cx>(cx: &mut ModuleContext<'cx>) -> JsResult<'cx, JsFunction> {
+    JsFunction::new_async(cx, |cx| {
+        let n: f64 = cx.argument_as(0)?;
+
+        Ok(async move { n * 2.0 })
+    })
+}
+
+#[neon::export]
+fn spawn_add<'cx>(cx: &mut FunctionContext<'cx>, a: f64, b: f64) -> JsResult<'cx, JsPromise> {
+    cx.spawn(async move { a + b })
+}
+
+static SHUTDOWN_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+// Counts the number of times an instance executor is shut down
+struct CountingRuntime(Runtime);
+
+impl Executor for CountingRuntime {
+    fn spawn(&self, fut: BoxFuture) {
+        self.0.spawn(fut);
+    }
+
+    fn shutdown(self: Box<Self>) {
+        SHUTDOWN_COUNT.fetch_add(1, Ordering::SeqCst);
+        self.0.shutdown_background();
+    }
+}
+
+#[neon::export]
+fn set_counting_executor(cx: &mut FunctionContext) -> NeonResult<bool> {
+    let runtime = Runtime::new().or_else(|err| cx.throw_error(err.to_string()))?;
+    let res = neon::executor::set_instance_executor(cx, CountingRuntime(runtime));
+
+    Ok(res.is_ok())
+}
+
+#[neon::export]
+fn executor_shutdown_count() -> usize {
+    SHUTDOWN_COUNT.load(Ordering::SeqCst)
+}
@@ -1,6 +1,10 @@
-use std::borrow::Cow;
+use std::{borrow::Cow, cell::Cell, rc::Rc};
 
-use neon::{prelude::*, types::buffer::TypedArray};
+use neon::{
+    object::PropertyDescriptor,
+    prelude::*,
+    types::{buffer::TypedArray, TypeTag},
+};
 
 pub fn return_js_global_object(mut cx: FunctionContext) -> JsResult<JsObject> {
     Ok(cx.global_object())
@@ -95,3 +99,137 @@ pub fn call_symbol_method(mut cx: FunctionContext) -> JsResult<JsString> {
     let sym: Handle<JsValue> = cx.argument::<JsValue>(1)?;
     obj.call_method_with(&mut cx, sym)?.apply(&mut cx)
 }
+
+pub fn wrap_string(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let obj = cx.argument::<JsObject>(0)?;
+    let s = cx.argument::<JsString>(1)?.value(&mut cx);
+
+    obj.wrap(&mut cx, s)?;
+
+    Ok(cx.undefined())
+}
+
+pub fn unwrap_string(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let obj = cx.argument::<JsObject>(0)?;
+
+    match obj.unwrap::<String, _>(&mut cx) {
+        Some(s) => {
+            let s = s.clone();
+
+            Ok(cx.string(s).upcast())
+        }
+        None => Ok(cx.undefined().upcast()),
+    }
+}
+
+pub fn unwrap_number(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let obj = cx.argument::<JsObject>(0)?;
+
+    match obj.unwrap::<f64, _>(&mut cx) {
+        Some(&n) => Ok(cx.number(n).upcast()),
+        None => Ok(cx.undefined().upcast()),
+    }
+}
+
+pub fn remove_wrap_string(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let obj = cx.argument::<JsObject>(0)?;
+
+    // Safety: No references to the wrapped value are held
+    match unsafe { obj.remove_wrap::<String, _>(&mut cx) } {
+        Some(s) => Ok(cx.string(s).upcast()),
+        None => Ok(cx.undefined().upcast()),
+    }
+}
+
+pub fn wrap_with_finalizer(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    struct Finalizer(Root<JsFunction>);
+
+    impl Finalize for Finalizer {
+        fn finalize<'a, C: Context<'a>>(self, cx: &mut C) {
+            let _ = self.0.into_inner(cx).call_with(cx).exec(cx);
+        }
+    }
+
+    let obj = cx.argument::<JsObject>(0)?;
+    let callback = cx.argument::<JsFunction>(1)?.root(&mut cx);
+
+    obj.wrap(&mut cx, Finalizer(callback))?;
+
+    Ok(cx.undefined())
+}
+
+pub fn type_tag_object(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let obj = cx.argument::<JsObject>(0)?;
+    let name = cx.argument::<JsString>(1)?.value(&mut cx);
+
+    obj.type_tag(&mut cx, &TypeTag::from_name(&name))?;
+
+    Ok(cx.undefined())
+}
+
+pub fn check_object_type_tag(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let obj = cx.argument::<JsObject>(0)?;
+    let name = cx.argument::<JsString>(1)?.value(&mut cx);
+    let is_tagged = obj.check_type_tag(&mut cx, &TypeTag::from_name(&name));
+
+    Ok(cx.boolean(is_tagged))
+}
+
+pub fn type_tag_of_rust_type(mut cx: FunctionContext) -> JsResult<JsObject> {
+    struct Tagged;
+
+    let obj = cx.empty_object();
+
+    obj.type_tag(&mut cx, &TypeTag::of::<Tagged>())?;
+    assert!(obj.check_type_tag(&mut cx, &TypeTag::of::<Tagged>()));
+    assert!(!obj.check_type_tag(&mut cx, &TypeTag::of::<String>()));
+
+    Ok(obj)
+}
+
+pub fn add_finalizer(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let obj = cx.argument::<JsObject>(0)?;
+    let callback = cx.argument::<JsFunction>(1)?.root(&mut cx);
+
+    obj.add_finalizer(&mut cx, callback, |mut cx, callback| {
+        let _ = callback.into_inner(&mut cx).call_with(&cx).exec(&mut cx);
+    })?;
+
+    Ok(cx.undefined())
+}
+
+pub fn define_properties(mut cx: FunctionContext) -> JsResult<JsObject> {
+    let obj = cx.empty_object();
+    let version = cx.string("1.0.0");
+    let secret = cx.string("hunter2");
+    let count = Rc::new(Cell::new(0.0));
+    let setter_count = count.clone();
+
+    let descriptors = [
+        PropertyDescriptor::value(&mut cx, "version", version).enumerable(true),
+        PropertyDescriptor::value(&mut cx, "secret", secret),
+        PropertyDescriptor::accessor(&mut cx, "count", move |mut cx| Ok(cx.number(count.get())))
+            .setter(move |mut cx| {
+                setter_count.set(cx.argument::<JsNumber>(0)?.value(&mut cx));
+                Ok(cx.undefined())
+            })
+            .enumerable(true),
+    ];
+
+    obj.define_properties(&mut cx, descriptors)?;
+
+    Ok(obj)
+}
+
+pub fn define_property(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let obj = cx.argument::<JsObject>(0)?;
+    let key = cx.argument::<JsValue>(1)?;
+    let value = cx.argument::<JsValue>(2)?;
+    let descriptor = PropertyDescriptor::value(&mut cx, key, value)
+        .writable(true)
+        .configurable(true);
+
+    obj.define_property(&mut cx, descriptor)?;
+
+    Ok(cx.undefined())
+}
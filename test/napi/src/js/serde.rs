@@ -0,0 +1,80 @@
+use std::collections::BTreeMap;
+
+use neon::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct User {
+    name: String,
+    age: u8,
+    email: Option<String>,
+    tags: Vec<String>,
+    #[serde(with = "serde_bytes")]
+    avatar: Vec<u8>,
+    role: Role,
+    scores: BTreeMap<String, f64>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+enum Role {
+    Guest,
+    Member(u32),
+    Admin { level: u8 },
+}
+
+fn round_trip<'cx, T>(
+    cx: &mut FunctionContext<'cx>,
+    v: Handle<'cx, JsValue>,
+) -> JsResult<'cx, JsValue>
+where
+    T: Serialize + for<'de> Deserialize<'de>,
+{
+    let v: T = neon::serde::from_value(cx, v)?;
+
+    neon::serde::to_value(cx, &v)
+}
+
+#[neon::export]
+fn serde_round_trip_user<'cx>(
+    cx: &mut FunctionContext<'cx>,
+    v: Handle<'cx, JsValue>,
+) -> JsResult<'cx, JsValue> {
+    round_trip::<User>(cx, v)
+}
+
+#[neon::export]
+fn serde_round_trip_json<'cx>(
+    cx: &mut FunctionContext<'cx>,
+    v: Handle<'cx, JsValue>,
+) -> JsResult<'cx, JsValue> {
+    round_trip::<serde_json::Value>(cx, v)
+}
+
+#[neon::export]
+fn serde_round_trip_vec<'cx>(
+    cx: &mut FunctionContext<'cx>,
+    v: Handle<'cx, JsValue>,
+) -> JsResult<'cx, JsValue> {
+    round_trip::<Vec<u8>>(cx, v)
+}
+
+#[neon::export]
+fn serde_json_to_string<'cx>(
+    cx: &mut FunctionContext<'cx>,
+    v: Handle<'cx, JsValue>,
+) -> NeonResult<String> {
+    let v: serde_json::Value = neon::serde::from_value(cx, v)?;
+
+    Ok(v.to_string())
+}
+
+#[neon::export]
+fn serde_json_from_string<'cx>(cx: &mut FunctionContext<'cx>, s: String) -> JsResult<'cx, JsValue> {
+    let v: serde_json::Value = match serde_json::from_str(&s) {
+        Ok(v) => v,
+        Err(err) => return cx.throw_error(err.to_string()),
+    };
+
+    neon::serde::to_value(cx, &v)
+}
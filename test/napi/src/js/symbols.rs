@@ -0,0 +1,67 @@
+use neon::{
+    prelude::*,
+    types::{extract::TryIntoJs, JsSymbol},
+};
+
+#[neon::export]
+fn create_symbol<'cx>(
+    cx: &mut FunctionContext<'cx>,
+    description: Option<String>,
+) -> Handle<'cx, JsSymbol> {
+    JsSymbol::new(cx, description.as_deref())
+}
+
+#[cfg(feature = "napi-9")]
+#[neon::export]
+fn symbol_for<'cx>(cx: &mut FunctionContext<'cx>, key: String) -> Handle<'cx, JsSymbol> {
+    JsSymbol::for_key(cx, &key)
+}
+
+#[neon::export]
+fn symbol_description(
+    cx: &mut FunctionContext,
+    symbol: Handle<JsSymbol>,
+) -> NeonResult<Option<String>> {
+    symbol.description(cx)
+}
+
+#[neon::export]
+fn is_symbol(cx: &mut FunctionContext, value: Handle<JsValue>) -> bool {
+    value.is_a::<JsSymbol, _>(cx)
+}
+
+#[neon::export]
+fn get_symbol_property<'cx>(
+    cx: &mut FunctionContext<'cx>,
+    obj: Handle<'cx, JsObject>,
+    key: Handle<'cx, JsSymbol>,
+) -> JsResult<'cx, JsValue> {
+    obj.get_value(cx, key)
+}
+
+// Creates an iterable of `0..n` with a custom `toString` tag
+#[neon::export]
+fn range_iterable<'cx>(cx: &mut FunctionContext<'cx>, n: f64) -> JsResult<'cx, JsObject> {
+    let obj = cx.empty_object();
+    let iterator = JsSymbol::iterator(cx)?;
+    let tag = JsSymbol::to_string_tag(cx)?;
+    let name = cx.string("Range");
+    let f = JsFunction::new(cx, move |mut cx| {
+        let values = (0..n as u32).map(f64::from).collect::<Vec<_>>();
+        let values = values.try_into_js(&mut cx)?;
+        let iterator = JsSymbol::iterator(&mut cx)?;
+        let f: Handle<JsFunction> = values.get(&mut cx, iterator)?;
+
+        f.call_with(&cx).this(values).apply::<JsValue, _>(&mut cx)
+    })?;
+
+    obj.set(cx, iterator, f)?;
+    obj.set(cx, tag, name)?;
+
+    Ok(obj)
+}
+
+#[neon::export]
+fn async_iterator_symbol<'cx>(cx: &mut FunctionContext<'cx>) -> JsResult<'cx, JsSymbol> {
+    JsSymbol::async_iterator(cx)
+}
@@ -1,6 +1,6 @@
 use std::{cell::RefCell, sync::Arc, time::Duration};
 
-use neon::{prelude::*, types::buffer::TypedArray};
+use neon::{handle::WeakRoot, prelude::*, types::buffer::TypedArray};
 
 pub fn useless_root(mut cx: FunctionContext) -> JsResult<JsObject> {
     let object = cx.argument::<JsObject>(0)?;
@@ -10,6 +10,42 @@ pub fn useless_root(mut cx: FunctionContext) -> JsResult<JsObject> {
     Ok(object)
 }
 
+pub fn weak_root_new(mut cx: FunctionContext) -> JsResult<JsBox<WeakRoot<JsObject>>> {
+    let object = cx.argument::<JsObject>(0)?;
+    let weak = WeakRoot::new(&mut cx, &*object);
+
+    Ok(cx.boxed(weak))
+}
+
+pub fn weak_root_upgrade(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let weak = cx.argument::<JsBox<WeakRoot<JsObject>>>(0)?;
+
+    match weak.upgrade(&mut cx) {
+        Some(object) => Ok(object.upcast()),
+        None => Ok(cx.undefined().upcast()),
+    }
+}
+
+pub fn weak_root_into_root(mut cx: FunctionContext) -> JsResult<JsObject> {
+    let object = cx.argument::<JsObject>(0)?;
+    let weak = WeakRoot::new(&mut cx, &*object);
+    let root = weak.into_root(&mut cx).expect("object is still alive");
+
+    Ok(root.into_inner(&mut cx))
+}
+
+pub fn root_into_weak(mut cx: FunctionContext) -> JsResult<JsBox<WeakRoot<JsObject>>> {
+    let object = cx.argument::<JsObject>(0)?;
+    let root = object.root(&mut cx);
+    let clone = root.clone(&mut cx);
+    let weak = root.into_weak(&mut cx);
+
+    // The clone shares a reference with `root` and must remain valid
+    assert!(clone.into_inner(&mut cx).strict_equals(&mut cx, object));
+
+    Ok(cx.boxed(weak))
+}
+
 pub fn thread_callback(mut cx: FunctionContext) -> JsResult<JsUndefined> {
     let callback = cx.argument::<JsFunction>(0)?.root(&mut cx);
     let channel = cx.channel();
@@ -1,6 +1,6 @@
 use neon::{
     prelude::*,
-    types::buffer::{Binary, BorrowError, TypedArray},
+    types::buffer::{Binary, BorrowError, TypedArray, U8Clamped},
 };
 
 pub fn return_array_buffer(mut cx: FunctionContext) -> JsResult<JsArrayBuffer> {
@@ -379,3 +379,101 @@ pub fn write_buffer_with_borrow_mut(mut cx: FunctionContext) -> JsResult<JsUndef
 
     Ok(cx.undefined())
 }
+
+pub fn return_data_view_from_arraybuffer_region(mut cx: FunctionContext) -> JsResult<JsDataView> {
+    let buf = cx.argument::<JsArrayBuffer>(0)?;
+    let offset = cx.argument::<JsNumber>(1)?.value(&mut cx) as usize;
+    let len = cx.argument::<JsNumber>(2)?.value(&mut cx) as usize;
+
+    JsDataView::from_region(&mut cx, &buf.region(offset, len))
+}
+
+pub fn get_data_view_info(mut cx: FunctionContext) -> JsResult<JsObject> {
+    let view = cx.argument::<JsDataView>(0)?;
+    let region = view.region(&mut cx);
+    let obj = cx.empty_object();
+    let size = view.size(&mut cx);
+    let byte_offset = cx.number(region.offset() as f64);
+    let byte_length = cx.number(size as f64);
+    let buffer = view.buffer(&mut cx);
+
+    obj.set(&mut cx, "byteOffset", byte_offset)?;
+    obj.set(&mut cx, "byteLength", byte_length)?;
+    obj.set(&mut cx, "buffer", buffer)?;
+
+    Ok(obj)
+}
+
+// Reads `[u8, i16 (LE), u32 (BE), f64 (LE)]` from the start of a `DataView`
+pub fn read_data_view(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let view = cx.argument::<JsDataView>(0)?;
+    let values = [
+        view.get_u8(&mut cx, 0)? as f64,
+        view.get_i16_le(&mut cx, 1)? as f64,
+        view.get_u32_be(&mut cx, 3)? as f64,
+        view.get_f64_le(&mut cx, 7)?,
+    ];
+    let arr = cx.empty_array();
+
+    for (i, &n) in values.iter().enumerate() {
+        let n = cx.number(n);
+        arr.set(&mut cx, i as u32, n)?;
+    }
+
+    Ok(arr)
+}
+
+// Writes `[i8, u16 (BE), i32 (LE), f32 (BE)]` to the start of a `DataView`
+pub fn write_data_view(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let mut view = cx.argument::<JsDataView>(0)?;
+
+    view.set_i8(&mut cx, 0, -2)?;
+    view.set_u16_be(&mut cx, 1, 0x1234)?;
+    view.set_i32_le(&mut cx, 3, -100_000)?;
+    view.set_f32_be(&mut cx, 7, 1.5)?;
+
+    Ok(cx.undefined())
+}
+
+pub fn read_data_view_u64(mut cx: FunctionContext) -> JsResult<JsString> {
+    let view = cx.argument::<JsDataView>(0)?;
+    let offset = cx.argument::<JsNumber>(1)?.value(&mut cx) as usize;
+    let n = view.get_u64_le(&mut cx, offset)?;
+
+    Ok(cx.string(n.to_string()))
+}
+
+pub fn write_data_view_with_lock(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let mut view = cx.argument::<JsDataView>(0)?;
+    let i = cx.argument::<JsNumber>(1)?.value(&mut cx) as usize;
+    let x = cx.argument::<JsNumber>(2)?.value(&mut cx) as u8;
+    let lock = cx.lock();
+
+    view.try_borrow_mut(&lock)
+        .map(|mut slice| slice[i] = x)
+        .or_throw(&mut cx)?;
+
+    Ok(cx.undefined())
+}
+
+pub fn return_uint8_clamped_array_from_slice(
+    mut cx: FunctionContext,
+) -> JsResult<JsUint8ClampedArray> {
+    let values = [-1.0, 0.5, 1.5, 2.5, 254.5, 300.0, f64::NAN]
+        .iter()
+        .copied()
+        .map(U8Clamped::from_f64)
+        .collect::<Vec<_>>();
+
+    JsUint8ClampedArray::from_slice(&mut cx, &values)
+}
+
+pub fn double_uint8_clamped_array(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let mut arr = cx.argument::<JsUint8ClampedArray>(0)?;
+
+    for elem in arr.as_mut_slice(&mut cx).iter_mut() {
+        *elem = U8Clamped(elem.0.saturating_mul(2));
+    }
+
+    Ok(cx.undefined())
+}
@@ -36,6 +36,18 @@ pub fn is_buffer(mut cx: FunctionContext) -> JsResult<JsBoolean> {
     Ok(cx.boolean(result))
 }
 
+pub fn is_data_view(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let val: Handle<JsValue> = cx.argument(0)?;
+    let result = val.is_a::<JsDataView, _>(&mut cx);
+    Ok(cx.boolean(result))
+}
+
+pub fn is_uint8_clamped_array(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let val: Handle<JsValue> = cx.argument(0)?;
+    let result = val.is_a::<JsUint8ClampedArray, _>(&mut cx);
+    Ok(cx.boolean(result))
+}
+
 pub fn is_error(mut cx: FunctionContext) -> JsResult<JsBoolean> {
     let val: Handle<JsValue> = cx.argument(0)?;
     let result = val.is_a::<JsError, _>(&mut cx);
@@ -123,3 +123,62 @@ pub fn box_channels(mut cx: FunctionContext) -> JsResult<JsBox<Channels>> {
 
     Ok(cx.boxed(channels))
 }
+
+static CLEANUP_LOG: Lazy<Mutex<Vec<String>>> = Lazy::new(Default::default);
+
+fn log_cleanup(name: String) {
+    CLEANUP_LOG
+        .lock()
+        .unwrap_or_else(|err| err.into_inner())
+        .push(name);
+}
+
+pub fn on_cleanup_log(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let name = cx.argument::<JsString>(0)?.value(&mut cx);
+
+    cx.on_cleanup(move || log_cleanup(name));
+
+    Ok(cx.undefined())
+}
+
+pub fn on_cleanup_async_log(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let name = cx.argument::<JsString>(0)?.value(&mut cx);
+
+    cx.on_cleanup_async(move || {
+        thread::sleep(Duration::from_millis(50));
+        log_cleanup(name);
+    });
+
+    Ok(cx.undefined())
+}
+
+pub struct FinalizeLog {
+    name: String,
+    root: Root<JsObject>,
+}
+
+impl Finalize for FinalizeLog {
+    fn finalize<'a, C: Context<'a>>(self, cx: &mut C) {
+        // Runs after the instance has been torn down
+        let channel = cx.channel();
+
+        drop(self.root);
+
+        if channel.try_send(|_| Ok(())).is_err() {
+            log_cleanup(self.name);
+        }
+    }
+}
+
+pub fn box_finalize_log(mut cx: FunctionContext) -> JsResult<JsBox<FinalizeLog>> {
+    let name = cx.argument::<JsString>(0)?.value(&mut cx);
+    let root = cx.empty_object().root(&mut cx);
+
+    Ok(cx.boxed(FinalizeLog { name, root }))
+}
+
+pub fn take_cleanup_log(mut cx: FunctionContext) -> JsResult<JsString> {
+    let log = std::mem::take(&mut *CLEANUP_LOG.lock().unwrap_or_else(|err| err.into_inner()));
+
+    Ok(cx.string(log.join(",")))
+}
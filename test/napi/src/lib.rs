@@ -9,21 +9,27 @@ mod js {
     pub mod arrays;
     pub mod bigint;
     pub mod boxed;
+    pub mod class;
     pub mod coercions;
+    pub mod collections;
     pub mod date;
     pub mod errors;
+    pub mod export;
+    pub mod extract;
     pub mod functions;
     pub mod futures;
     pub mod numbers;
     pub mod objects;
+    pub mod serde;
     pub mod strings;
+    pub mod symbols;
     pub mod threads;
     pub mod typedarrays;
     pub mod types;
     pub mod workers;
 }
 
-#[neon::main]
+#[neon::main(panic_policy = "js::functions::panic_policy")]
 fn main(mut cx: ModuleContext) -> NeonResult<()> {
     let greeting = cx.string("Hello, World!");
     let greeting_copy = greeting.value(&mut cx);
@@ -279,10 +285,35 @@ fn main(mut cx: ModuleContext) -> NeonResult<()> {
     cx.export_function("read_buffer_with_borrow", read_buffer_with_borrow)?;
     cx.export_function("write_buffer_with_lock", write_buffer_with_lock)?;
     cx.export_function("write_buffer_with_borrow_mut", write_buffer_with_borrow_mut)?;
+    cx.export_function(
+        "return_data_view_from_arraybuffer_region",
+        return_data_view_from_arraybuffer_region,
+    )?;
+    cx.export_function("get_data_view_info", get_data_view_info)?;
+    cx.export_function("read_data_view", read_data_view)?;
+    cx.export_function("write_data_view", write_data_view)?;
+    cx.export_function("read_data_view_u64", read_data_view_u64)?;
+    cx.export_function("write_data_view_with_lock", write_data_view_with_lock)?;
+    cx.export_function(
+        "return_uint8_clamped_array_from_slice",
+        return_uint8_clamped_array_from_slice,
+    )?;
+    cx.export_function("double_uint8_clamped_array", double_uint8_clamped_array)?;
     cx.export_function("byte_length", byte_length)?;
     cx.export_function("call_nullary_method", call_nullary_method)?;
     cx.export_function("call_unary_method", call_unary_method)?;
     cx.export_function("call_symbol_method", call_symbol_method)?;
+    cx.export_function("wrap_string", wrap_string)?;
+    cx.export_function("unwrap_string", unwrap_string)?;
+    cx.export_function("unwrap_number", unwrap_number)?;
+    cx.export_function("remove_wrap_string", remove_wrap_string)?;
+    cx.export_function("wrap_with_finalizer", wrap_with_finalizer)?;
+    cx.export_function("type_tag_object", type_tag_object)?;
+    cx.export_function("check_object_type_tag", check_object_type_tag)?;
+    cx.export_function("type_tag_of_rust_type", type_tag_of_rust_type)?;
+    cx.export_function("add_finalizer", add_finalizer)?;
+    cx.export_function("define_properties", define_properties)?;
+    cx.export_function("define_property", define_property)?;
 
     cx.export_function("create_date", create_date)?;
     cx.export_function("get_date_value", get_date_value)?;
@@ -299,6 +330,8 @@ fn main(mut cx: ModuleContext) -> NeonResult<()> {
     cx.export_function("is_uint32_array", is_uint32_array)?;
     cx.export_function("is_boolean", is_boolean)?;
     cx.export_function("is_buffer", is_buffer)?;
+    cx.export_function("is_data_view", is_data_view)?;
+    cx.export_function("is_uint8_clamped_array", is_uint8_clamped_array)?;
     cx.export_function("is_error", is_error)?;
     cx.export_function("is_null", is_null)?;
     cx.export_function("is_number", is_number)?;
@@ -311,6 +344,9 @@ fn main(mut cx: ModuleContext) -> NeonResult<()> {
     cx.export_function("new_type_error", new_type_error)?;
     cx.export_function("new_range_error", new_range_error)?;
     cx.export_function("throw_error", throw_error)?;
+    cx.export_function("throw_error_with_code", throw_error_with_code)?;
+    cx.export_function("new_error_with_options", new_error_with_options)?;
+    cx.export_function("throw_error_with_rust_cause", throw_error_with_rust_cause)?;
     cx.export_function("downcast_error", downcast_error)?;
 
     cx.export_function("panic", panic)?;
@@ -341,14 +377,20 @@ fn main(mut cx: ModuleContext) -> NeonResult<()> {
     cx.export_function("get_own_property_names", call_get_own_property_names)?;
 
     cx.export_function("person_new", person_new)?;
+    cx.export_function("person_new_with_size", person_new_with_size)?;
     cx.export_function("person_greet", person_greet)?;
     cx.export_function("ref_person_new", ref_person_new)?;
     cx.export_function("ref_person_greet", ref_person_greet)?;
     cx.export_function("ref_person_set_name", ref_person_set_name)?;
     cx.export_function("ref_person_fail", ref_person_fail)?;
     cx.export_function("external_unit", external_unit)?;
+    cx.export_function("adjust_external_memory", adjust_external_memory)?;
 
     cx.export_function("useless_root", useless_root)?;
+    cx.export_function("weak_root_new", weak_root_new)?;
+    cx.export_function("weak_root_upgrade", weak_root_upgrade)?;
+    cx.export_function("weak_root_into_root", weak_root_into_root)?;
+    cx.export_function("root_into_weak", root_into_weak)?;
     cx.export_function("thread_callback", thread_callback)?;
     cx.export_function("multi_threaded_callback", multi_threaded_callback)?;
     cx.export_function("greeter_new", greeter_new)?;
@@ -390,11 +432,18 @@ fn main(mut cx: ModuleContext) -> NeonResult<()> {
     cx.export_function("unstash_global_object", js::workers::unstash_global_object)?;
     cx.export_function("reject_after", js::workers::reject_after)?;
     cx.export_function("box_channels", js::workers::box_channels)?;
+    cx.export_function("on_cleanup_log", js::workers::on_cleanup_log)?;
+    cx.export_function("on_cleanup_async_log", js::workers::on_cleanup_async_log)?;
+    cx.export_function("take_cleanup_log", js::workers::take_cleanup_log)?;
+    cx.export_function("box_finalize_log", js::workers::box_finalize_log)?;
 
     // Futures
     cx.export_function("lazy_async_add", js::futures::lazy_async_add)?;
     cx.export_function("lazy_async_sum", js::futures::lazy_async_sum)?;
 
+    let async_double = js::futures::async_double(&mut cx)?;
+    cx.export_value("async_double", async_double)?;
+
     // JsBigInt test suite
     cx.export_function("bigint_suite", js::bigint::bigint_suite)?;
 